@@ -1,6 +1,9 @@
 use crate::reply::on_component_instantiation;
-use crate::state::{add_app_component, create_cross_chain_message, ADO_ADDRESSES, APP_NAME};
-use andromeda_app::app::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{
+    add_app_component, create_cross_chain_message, ADO_ADDRESSES, ALLOW_DEPRECATED, APP_NAME,
+    COMPONENT_ADMIN, PENDING_DEPENDENCIES,
+};
+use andromeda_app::app::{order_by_dependencies, ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::ado_contract::ADOContract;
 use andromeda_std::amp::AndrAddr;
 use andromeda_std::common::context::ExecuteContext;
@@ -31,13 +34,16 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     APP_NAME.save(deps.storage, &msg.name)?;
-
-    ensure!(
-        msg.app_components.len() <= 50,
-        ContractError::TooManyAppComponents {}
-    );
+    let allow_deprecated = msg.allow_deprecated.unwrap_or(false);
+    ALLOW_DEPRECATED.save(deps.storage, &allow_deprecated)?;
 
     let sender = msg.owner.clone().unwrap_or(info.sender.to_string());
+    let component_admin = if msg.app_is_component_admin.unwrap_or(false) {
+        env.contract.address.clone()
+    } else {
+        deps.api.addr_validate(&sender)?
+    };
+    COMPONENT_ADMIN.save(deps.storage, &component_admin)?;
     let mut resp = ADOContract::default()
         .instantiate(
             deps.storage,
@@ -57,9 +63,28 @@ pub fn instantiate(
     let vfs_address = ADOContract::default().get_vfs_address(deps.storage, &deps.querier)?;
     let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
 
+    let mut app_components = msg.app_components.clone();
+    if let Some(template) = msg.template {
+        let template_components = template.resolve_components(
+            deps.api,
+            &adodb_addr,
+            &deps.querier,
+            env.contract.address.clone(),
+            &sender,
+        )?;
+        app_components.extend(template_components);
+        resp = resp.add_attribute("template", template.name);
+    }
+
+    ensure!(
+        app_components.len() <= 50,
+        ContractError::TooManyAppComponents {}
+    );
+    order_by_dependencies(&app_components)?;
+
     let mut vfs_msgs: Vec<SubMsg> = vec![];
 
-    for component in msg.app_components.clone() {
+    for component in app_components.clone() {
         ensure!(
             !ADO_ADDRESSES.has(deps.storage, &component.name),
             ContractError::NameAlreadyTaken {}
@@ -101,17 +126,25 @@ pub fn instantiate(
     let mut inst_msgs = vec![];
 
     // This is done in a separate loop to ensure ordering, VFS registration first then instantiation after
-    for component in msg.app_components.clone() {
+    for component in app_components.clone() {
         // Generate an ID for the component to help with tracking
         let idx = add_app_component(deps.storage, &component)?;
 
+        // Components with dependencies are instantiated once their dependencies have been instantiated
+        let dependencies = component.get_dependencies();
+        if !dependencies.is_empty() {
+            PENDING_DEPENDENCIES.save(deps.storage, &idx.to_string(), &dependencies)?;
+            continue;
+        }
+
         // Generate an instantiation message if required
         let inst_msg = component.generate_instantiation_message(
             &deps.querier,
             &adodb_addr,
             &env.contract.address,
-            &sender,
+            component_admin.as_str(),
             idx,
+            allow_deprecated,
         )?;
 
         if let Some(inst_msg) = inst_msg {
@@ -139,9 +172,10 @@ pub fn instantiate(
                 &deps,
                 app_name.clone(),
                 msg.owner.clone().unwrap_or(info.sender.to_string()),
-                msg.app_components.clone(),
+                app_components.clone(),
                 chain,
                 chain_info.clone(),
+                msg.app_is_component_admin,
             )?;
             resp = resp.add_submessage(sub_msg);
         }
@@ -150,7 +184,7 @@ pub fn instantiate(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.result.is_err() {
         return Err(ContractError::Std(StdError::generic_err(
             msg.result.unwrap_err(),
@@ -161,7 +195,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         Some(ReplyId::RegisterPath) => Ok(Response::default()),
         Some(ReplyId::ClaimOwnership) => Ok(Response::default()),
         Some(ReplyId::AssignApp) => Ok(Response::default()),
-        _ => on_component_instantiation(deps, msg),
+        _ => on_component_instantiation(deps, env, msg),
     }
 }
 
@@ -173,15 +207,11 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_not_paused(ctx.deps.storage, &ctx.env, msg.as_ref())?;
     match msg {
         ExecuteMsg::AddAppComponent { component } => {
             execute::handle_add_app_component(ctx, component)
@@ -190,8 +220,20 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
             execute::claim_ownership(ctx, name, new_owner)
         }
         ExecuteMsg::ProxyMessage { msg, name } => execute::message(ctx, name, msg),
+        ExecuteMsg::ProxyMessages { msgs } => execute::messages(ctx, msgs),
         ExecuteMsg::UpdateAddress { name, addr } => execute::update_address(ctx, name, addr),
+        ExecuteMsg::RemoveAppComponent { name } => execute::remove_app_component(ctx, name),
+        ExecuteMsg::ReplaceAppComponent { name, component } => {
+            execute::replace_app_component(ctx, name, component)
+        }
+        ExecuteMsg::MigrateComponents { targets } => execute::migrate_components(ctx, targets),
         ExecuteMsg::AssignAppToComponents {} => execute::assign_app_to_components(ctx),
+        ExecuteMsg::CrossChainCreateAck {
+            chain,
+            address,
+            components,
+            ..
+        } => execute::cross_chain_create_ack(ctx, chain, address, components),
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
@@ -1,17 +1,23 @@
 use crate::state::{
-    add_app_component, generate_assign_app_message, generate_ownership_message,
-    load_component_addresses, ADO_ADDRESSES, APP_NAME,
+    add_app_component, component_admin, generate_assign_app_message, generate_ownership_message,
+    load_component_addresses, load_component_descriptor, load_component_descriptors,
+    resolve_existing_dependencies, ADO_ADDRESSES, ADO_DESCRIPTORS, ALLOW_DEPRECATED, APP_NAME,
+    COMPONENT_REPLY_DATA, CROSS_CHAIN_ADDRESSES, PENDING_REPLACEMENTS,
 };
-use andromeda_app::app::{AppComponent, ComponentType};
+use andromeda_app::app::{
+    proxy_messages_action, AppComponent, ComponentMessage, ComponentType, CrossChainComponent,
+};
+use andromeda_std::ado_base::{AndromedaMsg, MigrateMsg};
 use andromeda_std::common::{context::ExecuteContext, reply::ReplyId};
 use andromeda_std::error::ContractError;
+use andromeda_std::os::adodb::ADOVersion;
 use andromeda_std::os::aos_querier::AOSQuerier;
-use andromeda_std::os::vfs::ExecuteMsg as VFSExecuteMsg;
+use andromeda_std::os::vfs::{convert_component_name, ExecuteMsg as VFSExecuteMsg};
 use andromeda_std::{ado_contract::ADOContract, amp::AndrAddr};
 
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, Binary, CosmosMsg, QuerierWrapper, ReplyOn, Response, Storage,
-    SubMsg, WasmMsg,
+    ensure, from_json, to_json_binary, Addr, Binary, Coin, CosmosMsg, Event, QuerierWrapper,
+    ReplyOn, Response, StdError, Storage, SubMsg, WasmMsg,
 };
 
 pub fn handle_add_app_component(
@@ -30,9 +36,12 @@ pub fn handle_add_app_component(
         }
     );
 
+    // Mirror apps are only created at instantiation, and are owned on their chains rather than by this app
     ensure!(
         !matches!(component.component_type, ComponentType::CrossChain(..)),
-        ContractError::CrossChainComponentsCurrentlyDisabled {}
+        ContractError::CrossChainComponentAfterInstantiation {
+            name: component.name.clone()
+        }
     );
     let contract = ADOContract::default();
     ensure!(
@@ -40,6 +49,7 @@ pub fn handle_add_app_component(
         ContractError::Unauthorized {}
     );
 
+    let component = resolve_existing_dependencies(ctx.deps.storage, component)?;
     let idx = add_app_component(ctx.deps.storage, &component)?;
     ensure!(idx < 50, ContractError::TooManyAppComponents {});
 
@@ -76,8 +86,11 @@ pub fn handle_add_app_component(
         querier,
         &adodb_addr,
         &env.contract.address,
-        sender,
+        component_admin(ctx.deps.storage, &env, &ctx.info.sender)?.as_str(),
         idx,
+        ALLOW_DEPRECATED
+            .may_load(ctx.deps.storage)?
+            .unwrap_or(false),
     )?;
 
     if let Some(inst_msg) = inst_msg {
@@ -107,6 +120,189 @@ pub fn handle_add_app_component(
     Ok(resp)
 }
 
+pub fn remove_app_component(ctx: ExecuteContext, name: String) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, info, .. } = ctx;
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+
+    let (idx, component) = load_component_descriptor(deps.storage, &name)?;
+    ADO_DESCRIPTORS.remove(deps.storage, &idx);
+    ADO_ADDRESSES.remove(deps.storage, &name);
+    COMPONENT_REPLY_DATA.remove(deps.storage, &name);
+
+    let mut resp = Response::default()
+        .add_attribute("method", "remove_app_component")
+        .add_attribute("name", name.clone())
+        .add_attribute("type", component.ado_type);
+
+    if !name.starts_with('.') {
+        let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
+        resp = resp.add_submessage(remove_component_path(kernel_address, &deps.querier, name)?);
+    }
+
+    Ok(resp)
+}
+
+pub fn replace_app_component(
+    ctx: ExecuteContext,
+    name: String,
+    component: AppComponent,
+) -> Result<Response, ContractError> {
+    let ExecuteContext {
+        deps, info, env, ..
+    } = ctx;
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure!(
+        component.name == name,
+        ContractError::InvalidComponent {
+            name: component.name.clone()
+        }
+    );
+    ensure!(
+        !matches!(component.component_type, ComponentType::CrossChain(..)),
+        ContractError::CrossChainComponentAfterInstantiation {
+            name: component.name.clone()
+        }
+    );
+    component.verify(&deps.as_ref())?;
+    let component = resolve_existing_dependencies(deps.storage, component)?;
+
+    // The replacement keeps the index of the previous component
+    let (idx, previous) = load_component_descriptor(deps.storage, &name)?;
+    ADO_DESCRIPTORS.save(deps.storage, &idx, &component)?;
+
+    let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let mut resp = Response::default()
+        .add_attribute("method", "replace_app_component")
+        .add_attribute("name", name.clone())
+        .add_attribute("previous_type", previous.ado_type)
+        .add_attribute("type", component.ado_type.clone());
+
+    if !name.starts_with('.') {
+        resp = resp.add_submessage(remove_component_path(
+            kernel_address,
+            &deps.querier,
+            name.clone(),
+        )?);
+    }
+
+    match component.component_type.clone() {
+        ComponentType::New(instantiate_msg) => {
+            // The previous instance holds the address predicted by `Instantiate2`,
+            // so the new instance's address is stored and registered with the VFS on reply
+            if let Some(previous_addr) = ADO_ADDRESSES.may_load(deps.storage, &name)? {
+                PENDING_REPLACEMENTS.save(deps.storage, &name, &previous_addr)?;
+            }
+            ADO_ADDRESSES.remove(deps.storage, &name);
+            let code_id = AOSQuerier::non_deprecated_code_id_getter(
+                &deps.querier,
+                &adodb_addr,
+                &component.ado_type,
+                ALLOW_DEPRECATED.may_load(deps.storage)?.unwrap_or(false),
+            )?;
+            let reply_id: u64 = idx
+                .parse()
+                .map_err(|_| StdError::generic_err("Invalid component index"))?;
+            let inst_msg = WasmMsg::Instantiate {
+                admin: Some(component_admin(deps.storage, &env, &info.sender)?.into_string()),
+                code_id,
+                msg: instantiate_msg,
+                funds: vec![],
+                label: format!("Instantiate: {}", component.ado_type),
+            };
+            resp = resp.add_submessage(SubMsg::reply_always(inst_msg, reply_id));
+        }
+        ComponentType::Symlink(symlink) => {
+            let component_address = symlink.get_raw_address(&deps.as_ref())?;
+            ADO_ADDRESSES.save(deps.storage, &name, &component_address)?;
+            let vfs_addr = ADOContract::default().get_vfs_address(deps.storage, &deps.querier)?;
+            let app_name = APP_NAME.load(deps.storage)?;
+            let registration_msg = component.generate_vfs_registration(
+                None,
+                &env.contract.address,
+                &app_name,
+                None,
+                &adodb_addr,
+                &vfs_addr,
+            )?;
+            if let Some(registration_msg) = registration_msg {
+                resp = resp.add_submessage(registration_msg);
+            }
+            resp = resp.add_event(component.generate_event(None));
+        }
+        ComponentType::CrossChain(..) => {
+            return Err(ContractError::CrossChainComponentAfterInstantiation { name })
+        }
+    }
+
+    Ok(resp)
+}
+
+/// Migrates components to the code of the given ADO versions, the app must be the admin of each component.
+///
+/// Components are instantiated with the app as their admin if it was created with `app_is_component_admin`,
+/// otherwise the app's creator must first update the admin of a component to the app.
+pub fn migrate_components(
+    ctx: ExecuteContext,
+    targets: Vec<(String, String)>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext {
+        deps, info, env, ..
+    } = ctx;
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let allow_deprecated = ALLOW_DEPRECATED.may_load(deps.storage)?.unwrap_or(false);
+    let mut resp = Response::default().add_attribute("method", "migrate_components");
+
+    for (name, version) in targets {
+        let (idx, mut component) = load_component_descriptor(deps.storage, &name)?;
+        ensure!(
+            matches!(component.component_type, ComponentType::New(..)),
+            ContractError::InvalidComponent { name }
+        );
+        let address = ADO_ADDRESSES.load(deps.storage, &name)?;
+        let admin = deps.querier.query_wasm_contract_info(&address)?.admin;
+        ensure!(
+            admin.as_deref() == Some(env.contract.address.as_str()),
+            ContractError::AppNotComponentAdmin { name }
+        );
+        let ado_version = ADOVersion::from_type(component.ado_type.clone()).with_version(version);
+        let code_id = AOSQuerier::non_deprecated_code_id_getter(
+            &deps.querier,
+            &adodb_addr,
+            ado_version.as_str(),
+            allow_deprecated,
+        )?;
+
+        component.ado_type = ado_version.clone().into_string();
+        ADO_DESCRIPTORS.save(deps.storage, &idx, &component)?;
+
+        let migrate_msg = WasmMsg::Migrate {
+            contract_addr: address.to_string(),
+            new_code_id: code_id,
+            msg: to_json_binary(&MigrateMsg {})?,
+        };
+        resp = resp.add_message(migrate_msg).add_event(
+            Event::new("migrate_app_component")
+                .add_attribute("name", name)
+                .add_attribute("ado_version", ado_version.into_string())
+                .add_attribute("code_id", code_id.to_string()),
+        );
+    }
+
+    Ok(resp)
+}
+
 pub fn claim_ownership(
     ctx: ExecuteContext,
     name_opt: Option<String>,
@@ -173,6 +369,79 @@ pub fn message(ctx: ExecuteContext, name: String, msg: Binary) -> Result<Respons
         .add_attribute("recipient", name))
 }
 
+pub fn messages(
+    ctx: ExecuteContext,
+    msgs: Vec<ComponentMessage>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext {
+        mut deps,
+        info,
+        env,
+        amp_ctx,
+        ..
+    } = ctx;
+    // Messages received through the kernel are sent on behalf of the packet's origin
+    let sender = match amp_ctx {
+        Some(pkt) => pkt.ctx.get_origin(),
+        None => info.sender.to_string(),
+    };
+
+    // The funds sent to the app must be fully distributed between the messages
+    let mut required_funds: Vec<Coin> = vec![];
+    for coin in msgs
+        .iter()
+        .flat_map(|msg| msg.funds.clone().unwrap_or_default())
+    {
+        match required_funds.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(required) => required.amount = required.amount.checked_add(coin.amount)?,
+            None => required_funds.push(coin),
+        }
+    }
+    required_funds.retain(|coin| !coin.amount.is_zero());
+    ensure!(
+        required_funds.len() == info.funds.len()
+            && required_funds
+                .iter()
+                .all(|coin| info.funds.iter().any(|sent| sent == coin)),
+        ContractError::InvalidFunds {
+            msg: "Sent funds must match the funds of the messages".to_string()
+        }
+    );
+
+    let mut resp = Response::default().add_attribute("method", "app_messages");
+    for component_msg in msgs {
+        let action = proxy_messages_action(&component_msg.name);
+        ADOContract::default().ensure_not_paused(deps.storage, &env, &action)?;
+        ADOContract::default().is_permissioned_strict(
+            deps.branch(),
+            env.clone(),
+            action,
+            sender.as_str(),
+        )?;
+        ensure!(
+            from_json::<AndromedaMsg>(&component_msg.msg).is_err(),
+            ContractError::ProxiedBaseMessage {
+                name: component_msg.name
+            }
+        );
+        let addr = ADO_ADDRESSES
+            .may_load(deps.storage, &component_msg.name)?
+            .filter(|addr| !addr.as_str().is_empty())
+            .ok_or(ContractError::InvalidComponent {
+                name: component_msg.name.clone(),
+            })?;
+        resp = resp
+            .add_message(WasmMsg::Execute {
+                contract_addr: addr.to_string(),
+                msg: component_msg.msg,
+                funds: component_msg.funds.unwrap_or_default(),
+            })
+            .add_attribute("recipient", component_msg.name);
+    }
+
+    Ok(resp)
+}
+
 pub fn has_update_address_privilege(
     storage: &dyn Storage,
     sender: &str,
@@ -242,6 +511,80 @@ pub fn register_component_path(
     ))
 }
 
+/// Records the address of each of the app's components on another chain, as reported by the mirror app created there.
+///
+/// Components the mirror app did not report are left without an address.
+pub fn cross_chain_create_ack(
+    ctx: ExecuteContext,
+    chain: String,
+    address: Option<String>,
+    components: Vec<(String, String)>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, info, .. } = ctx;
+    let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
+    ensure!(
+        info.sender == kernel_address,
+        ContractError::Unauthorized {}
+    );
+
+    let mut resp = Response::default()
+        .add_attribute("method", "cross_chain_create_ack")
+        .add_attribute("chain", chain.clone());
+    let address = match address {
+        Some(address) => address,
+        // The creation failed on the receiving chain
+        None => return Ok(resp.add_attribute("success", "false")),
+    };
+
+    for component in load_component_descriptors(deps.storage)? {
+        if let ComponentType::CrossChain(CrossChainComponent {
+            chain: component_chain,
+            ..
+        }) = component.component_type
+        {
+            if component_chain != chain {
+                continue;
+            }
+            let component_address = components
+                .iter()
+                .find(|(name, _)| name == &component.name)
+                .map(|(_, address)| address);
+            if let Some(component_address) = component_address {
+                CROSS_CHAIN_ADDRESSES.save(deps.storage, &component.name, component_address)?;
+                resp = resp.add_attribute("component", component.name);
+            }
+        }
+    }
+
+    Ok(resp
+        .add_attribute("success", "true")
+        .add_attribute("address", address))
+}
+
+pub fn remove_component_path(
+    kernel_address: Addr,
+    querier: &QuerierWrapper,
+    name: impl Into<String>,
+) -> Result<SubMsg, ContractError> {
+    let vfs_address: Addr = AOSQuerier::vfs_address_getter(querier, &kernel_address)?;
+    let name: String = name.into();
+
+    let remove_path_msg = VFSExecuteMsg::RemovePath {
+        name: convert_component_name(&name),
+        parent_address: None,
+    };
+    let cosmos_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: vfs_address.to_string(),
+        msg: to_json_binary(&remove_path_msg)?,
+        funds: vec![],
+    });
+
+    Ok(SubMsg::reply_on_error(
+        cosmos_msg,
+        ReplyId::RegisterPath.repr(),
+    ))
+}
+
 pub fn assign_app_to_components(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let ExecuteContext {
         deps, env, info, ..
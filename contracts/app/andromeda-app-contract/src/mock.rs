@@ -88,6 +88,9 @@ pub fn mock_app_instantiate_msg(
         kernel_address: kernel_address.into(),
         owner,
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     }
 }
 
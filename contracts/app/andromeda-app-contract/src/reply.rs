@@ -1,16 +1,54 @@
-use andromeda_std::{common::response::get_reply_address, error::ContractError};
-use cosmwasm_std::{ensure_eq, Addr, DepsMut, Reply, Response};
+use andromeda_std::{
+    ado_contract::ADOContract, common::response::get_reply_instantiate_data, error::ContractError,
+    os::vfs::convert_component_name,
+};
+use cosmwasm_std::{ensure_eq, Addr, DepsMut, Env, Reply, Response};
 
-use crate::state::{ADO_ADDRESSES, ADO_DESCRIPTORS};
+use crate::{
+    execute::register_component_path,
+    state::{
+        instantiate_ready_dependents, ADO_ADDRESSES, ADO_DESCRIPTORS, COMPONENT_REPLY_DATA,
+        PENDING_REPLACEMENTS,
+    },
+};
 
-pub fn on_component_instantiation(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+pub fn on_component_instantiation(
+    deps: DepsMut,
+    env: Env,
+    msg: Reply,
+) -> Result<Response, ContractError> {
     let id = msg.id.to_string();
 
     let descriptor = ADO_DESCRIPTORS.load(deps.storage, &id)?;
 
-    let addr_str = get_reply_address(msg)?;
+    let (addr_str, data) = get_reply_instantiate_data(msg)?;
     let addr = &deps.api.addr_validate(&addr_str)?;
+
+    // The data returned by the component is made available to the components depending on it
+    match data {
+        Some(data) => COMPONENT_REPLY_DATA.save(deps.storage, &descriptor.name, &data)?,
+        None => COMPONENT_REPLY_DATA.remove(deps.storage, &descriptor.name),
+    }
+
+    // Replaced components are instantiated without a predicted address
+    if PENDING_REPLACEMENTS.has(deps.storage, &descriptor.name) {
+        PENDING_REPLACEMENTS.remove(deps.storage, &descriptor.name);
+        ADO_ADDRESSES.save(deps.storage, &descriptor.name, addr)?;
+        let mut resp = Response::default().add_event(descriptor.generate_event(Some(addr.clone())));
+        if !descriptor.name.starts_with('.') {
+            let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
+            resp = resp.add_submessage(register_component_path(
+                kernel_address,
+                &deps.querier,
+                convert_component_name(&descriptor.name),
+                addr.clone(),
+            )?);
+        }
+        return Ok(resp);
+    }
+
     let saved_addr = ADO_ADDRESSES.load(deps.storage, &descriptor.name)?;
+
     ensure_eq!(
         addr,
         saved_addr,
@@ -20,7 +58,9 @@ pub fn on_component_instantiation(deps: DepsMut, msg: Reply) -> Result<Response,
         }
     );
 
-    let resp = Response::default();
+    // Components waiting on this one can now be instantiated
+    let inst_msgs = instantiate_ready_dependents(deps, &env, &descriptor.name)?;
+    let resp = Response::default().add_submessages(inst_msgs);
 
     Ok(resp)
 }
@@ -11,8 +11,8 @@ use andromeda_std::{
     os::kernel::ExecuteMsg as KernelExecuteMsg,
 };
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, Coin, CosmosMsg, DepsMut, Order, ReplyOn, Storage, SubMsg,
-    WasmMsg,
+    ensure, from_json, to_json_binary, Addr, Binary, Coin, CosmosMsg, DepsMut, Env, Order, ReplyOn,
+    StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw_storage_plus::{Bound, Item, Map};
 
@@ -22,9 +22,35 @@ pub const ADO_ADDRESSES: Map<&str, Addr> = Map::new("ado_addresses");
 pub const ADO_DESCRIPTORS: Map<&str, AppComponent> = Map::new("ado_descriptors");
 pub const ADO_IDX: Item<u64> = Item::new("ado_idx");
 pub const APP_NAME: Item<String> = Item::new("app_name");
+/// Whether components may be instantiated from deprecated ADO versions
+pub const ALLOW_DEPRECATED: Item<bool> = Item::new("allow_deprecated");
+/// The address of each cross-chain component on its chain, reported by the kernel once the mirror app is created
+pub const CROSS_CHAIN_ADDRESSES: Map<&str, String> = Map::new("cross_chain_addresses");
+/// The admin of the components instantiated by the app, either the app itself or the account that created the app
+pub const COMPONENT_ADMIN: Item<Addr> = Item::new("component_admin");
+/// The previous address of each component being replaced by a new instance, cleared once the instance is created
+pub const PENDING_REPLACEMENTS: Map<&str, Addr> = Map::new("pending_replacements");
+/// The dependencies each component is waiting on before it is instantiated, keyed by component index
+pub const PENDING_DEPENDENCIES: Map<&str, Vec<String>> = Map::new("pending_dependencies");
+/// The data returned by the instantiation of each component, keyed by component name
+pub const COMPONENT_REPLY_DATA: Map<&str, Binary> = Map::new("component_reply_data");
 // Used to keep track of which component indices have had the app assigned
 pub const ASSIGNED_IDX: Item<u64> = Item::new("assigned_idx");
 
+/// The admin to instantiate a component added by the given sender with, the app if it administers its components
+pub fn component_admin(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<Addr, ContractError> {
+    let admin = COMPONENT_ADMIN.load(storage)?;
+    if admin == env.contract.address {
+        Ok(admin)
+    } else {
+        Ok(sender.clone())
+    }
+}
+
 // DEV NOTE: Very similar to CW721 module instantiation, possibly merge both implementations?
 pub fn add_app_component(
     storage: &mut dyn Storage,
@@ -37,15 +63,122 @@ pub fn add_app_component(
     Ok(idx)
 }
 
+/// Marks the given component as instantiated and generates the instantiation messages for the components that were only waiting on it.
+///
+/// The placeholders of their dependencies in their instantiate messages are substituted, see [`dependency_substitutions`].
+pub fn instantiate_ready_dependents(
+    deps: DepsMut,
+    env: &Env,
+    name: &str,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let pending: Vec<(String, Vec<String>)> = PENDING_DEPENDENCIES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut ready: Vec<u64> = vec![];
+    for (idx, mut dependencies) in pending {
+        if !dependencies.iter().any(|dependency| dependency == name) {
+            continue;
+        }
+        dependencies.retain(|dependency| dependency != name);
+        if dependencies.is_empty() {
+            PENDING_DEPENDENCIES.remove(deps.storage, &idx);
+            ready.push(idx.parse().map_err(|_| StdError::parse_err("u64", idx))?);
+        } else {
+            PENDING_DEPENDENCIES.save(deps.storage, &idx, &dependencies)?;
+        }
+    }
+    if ready.is_empty() {
+        return Ok(vec![]);
+    }
+    ready.sort();
+
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let allow_deprecated = ALLOW_DEPRECATED.may_load(deps.storage)?.unwrap_or(false);
+
+    let admin = COMPONENT_ADMIN.load(deps.storage)?;
+    let mut inst_msgs = vec![];
+    for idx in ready {
+        let component = ADO_DESCRIPTORS.load(deps.storage, &idx.to_string())?;
+        let component = resolve_existing_dependencies(deps.storage, component)?;
+        ADO_DESCRIPTORS.save(deps.storage, &idx.to_string(), &component)?;
+        let inst_msg = component.generate_instantiation_message(
+            &deps.querier,
+            &adodb_addr,
+            &env.contract.address,
+            admin.as_str(),
+            idx,
+            allow_deprecated,
+        )?;
+        if let Some(inst_msg) = inst_msg {
+            inst_msgs.push(inst_msg);
+        }
+    }
+    Ok(inst_msgs)
+}
+
+/// Substitutes the placeholders of the components the given component depends on, which must already exist
+pub fn resolve_existing_dependencies(
+    storage: &dyn Storage,
+    component: AppComponent,
+) -> Result<AppComponent, ContractError> {
+    let substitutions = dependency_substitutions(storage, &component)?;
+    component.with_substitutions(&substitutions)
+}
+
+/// Gets the placeholder substitutions made available by the dependencies of the given component.
+///
+/// `${dependency}` is substituted with the address of the dependency and `${dependency.data}` with the data returned by its instantiation.
+/// Data holding a JSON string is substituted with the string, other data with its UTF-8 contents or, failing that, its base64 encoding.
+pub fn dependency_substitutions(
+    storage: &dyn Storage,
+    component: &AppComponent,
+) -> Result<Vec<(String, String)>, ContractError> {
+    let mut substitutions = vec![];
+    for dependency in component.get_dependencies() {
+        let addr = ADO_ADDRESSES
+            .may_load(storage, &dependency)?
+            .filter(|addr| !addr.as_str().is_empty())
+            .ok_or(ContractError::InvalidComponentDependency {
+                name: component.name.clone(),
+                dependency: dependency.clone(),
+            })?;
+        if let Some(data) = COMPONENT_REPLY_DATA.may_load(storage, &dependency)? {
+            let data = match from_json::<String>(&data) {
+                Ok(value) => value,
+                Err(_) => String::from_utf8(data.to_vec()).unwrap_or_else(|_| data.to_base64()),
+            };
+            substitutions.push((format!("{dependency}.data"), data));
+        }
+        substitutions.push((dependency, addr.to_string()));
+    }
+    Ok(substitutions)
+}
+
+/// Loads the descriptor key and descriptor of the component with the given name
+pub fn load_component_descriptor(
+    storage: &dyn Storage,
+    name: &str,
+) -> Result<(String, AppComponent), ContractError> {
+    ADO_DESCRIPTORS
+        .range(storage, None, None, Order::Ascending)
+        .flatten()
+        .find(|(_, component)| component.name == name)
+        .ok_or(ContractError::InvalidComponent {
+            name: name.to_string(),
+        })
+}
+
 pub fn load_component_addresses(
     storage: &dyn Storage,
     min: Option<&str>,
 ) -> Result<Vec<Addr>, ContractError> {
     let min = Some(Bound::inclusive(min.unwrap_or("0")));
+    // Components without a local address, such as cross-chain components, are skipped
     let addresses: Vec<Addr> = ADO_ADDRESSES
         .range(storage, min, None, Order::Ascending)
         .flatten()
         .map(|(_vec, addr)| addr)
+        .filter(|addr| !addr.as_str().is_empty())
         .collect();
 
     Ok(addresses)
@@ -58,9 +191,12 @@ pub fn load_component_addresses_with_name(
     let addresses: Vec<ComponentAddress> = ADO_ADDRESSES
         .range(storage, min, None, Order::Ascending)
         .flatten()
-        .map(|(name, addr)| ComponentAddress {
-            name,
-            address: addr.to_string(),
+        .map(|(name, addr)| {
+            let address = match CROSS_CHAIN_ADDRESSES.may_load(storage, &name) {
+                Ok(Some(remote_addr)) if addr.as_str().is_empty() => remote_addr,
+                _ => addr.to_string(),
+            };
+            ComponentAddress { name, address }
         })
         .collect();
 
@@ -141,6 +277,7 @@ pub fn create_cross_chain_message(
     components: Vec<AppComponent>,
     target_chain_info: ChainInfo,
     all_chain_info: Vec<ChainInfo>,
+    app_is_component_admin: Option<bool>,
 ) -> Result<SubMsg, ContractError> {
     let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
     let curr_chain = AOSQuerier::get_current_chain(&deps.querier, &kernel_address)?;
@@ -163,6 +300,7 @@ pub fn create_cross_chain_message(
                         name,
                         ado_type: component.ado_type,
                         component_type: ComponentType::New(instantiate_msg),
+                        depends_on: None,
                     }
                 // Otherwise use a symlink to the component
                 } else {
@@ -180,6 +318,7 @@ pub fn create_cross_chain_message(
                         component_type: ComponentType::Symlink(AndrAddr::from_string(format!(
                             "ibc://{chain}/home/{owner}/{app_name}/{name}"
                         ))),
+                        depends_on: None,
                     }
                 }
             }
@@ -190,6 +329,7 @@ pub fn create_cross_chain_message(
                 component_type: ComponentType::Symlink(AndrAddr::from_string(format!(
                     "ibc://{curr_chain}/home/{owner}/{app_name}/{name}"
                 ))),
+                depends_on: None,
             },
         };
         new_components.push(new_component);
@@ -199,6 +339,9 @@ pub fn create_cross_chain_message(
         app_components: new_components,
         name: app_name,
         chain_info: None,
+        allow_deprecated: ALLOW_DEPRECATED.may_load(deps.storage)?,
+        template: None,
+        app_is_component_admin,
         kernel_address: channel_info.kernel_address,
     };
 
@@ -207,6 +350,7 @@ pub fn create_cross_chain_message(
         msg: to_json_binary(&msg)?,
         owner: Some(AndrAddr::from_string(target_chain_info.owner)),
         chain: Some(target_chain_info.chain_name),
+        force: None,
     };
 
     let cosmos_msg = CosmosMsg::Wasm(WasmMsg::Execute {
@@ -254,6 +398,7 @@ mod test {
                     chain: target_chain.clone(),
                     instantiate_msg: to_json_binary(&"test_instantiate").unwrap(),
                 }),
+                depends_on: None,
             },
             AppComponent {
                 name: "test_component".to_string(),
@@ -262,11 +407,13 @@ mod test {
                     chain: second_chain_info.chain_name.clone(),
                     instantiate_msg: to_json_binary(&"test_instantiate").unwrap(),
                 }),
+                depends_on: None,
             },
             AppComponent {
                 name: "test_component".to_string(),
                 ado_type: "test_ado".to_string(),
                 component_type: ComponentType::New(to_json_binary(&"test_instantiate").unwrap()),
+                depends_on: None,
             },
         ];
         let expected_components = vec![
@@ -274,6 +421,7 @@ mod test {
                 name: "test_component".to_string(),
                 ado_type: "test_ado".to_string(),
                 component_type: ComponentType::New(to_json_binary(&"test_instantiate").unwrap()),
+                depends_on: None,
             },
             AppComponent {
                 name: "test_component".to_string(),
@@ -282,6 +430,7 @@ mod test {
                     "ibc://{}/home/{}/test_app/test_component",
                     second_chain_info.chain_name, second_chain_info.owner
                 ))),
+                depends_on: None,
             },
             AppComponent {
                 name: "test_component".to_string(),
@@ -290,6 +439,7 @@ mod test {
                     "ibc://andromeda/home/{}/test_app/test_component",
                     target_owner
                 ))),
+                depends_on: None,
             },
         ];
 
@@ -300,6 +450,7 @@ mod test {
             components,
             target_chain_info,
             all_chain_info,
+            None,
         )
         .unwrap();
 
@@ -315,6 +466,7 @@ mod test {
                 msg,
                 owner,
                 chain,
+                ..
             } => {
                 assert_eq!(ado_type, "app-contract");
                 assert_eq!(owner, Some(AndrAddr::from_string(target_owner.clone())));
@@ -1,21 +1,30 @@
-use crate::state::{ADO_DESCRIPTORS, ADO_IDX};
+use crate::state::{
+    add_app_component, resolve_existing_dependencies, ADO_DESCRIPTORS, ADO_IDX,
+    PENDING_DEPENDENCIES, PENDING_REPLACEMENTS,
+};
 
 use super::{contract::*, state::ADO_ADDRESSES};
-use andromeda_app::app::{AppComponent, ComponentType, ExecuteMsg, InstantiateMsg};
+use andromeda_app::app::{
+    order_by_dependencies, proxy_messages_action, AppComponent, ComponentAddress, ComponentMessage,
+    ComponentType, CrossChainComponent, ExecuteMsg, InstantiateMsg,
+};
 use andromeda_std::ado_base::ownership::OwnershipMessage;
-// use andromeda_std::amp::AndrAddr;
-// use andromeda_std::common::reply::ReplyId;
-// use andromeda_std::os::vfs::{convert_component_name, ExecuteMsg as VFSExecuteMsg};
+use andromeda_std::ado_base::permissioning::{LocalPermission, Permission, PermissioningMessage};
+use andromeda_std::amp::AndrAddr;
+use andromeda_std::common::reply::ReplyId;
+use andromeda_std::os::vfs::ExecuteMsg as VFSExecuteMsg;
 use andromeda_std::testing::mock_querier::{
     mock_dependencies_custom, MOCK_ANCHOR_CONTRACT, MOCK_CW20_CONTRACT, MOCK_KERNEL_CONTRACT,
+    MOCK_VFS_CONTRACT,
 };
 
 use andromeda_std::{ado_base::AndromedaMsg, error::ContractError};
 
 use cosmwasm_std::{
-    attr,
+    attr, coins, from_json,
     testing::{mock_env, mock_info},
-    to_json_binary, Addr, CosmosMsg, Empty, ReplyOn, Response, StdError, SubMsg, WasmMsg,
+    to_json_binary, Addr, CosmosMsg, DepsMut, Empty, Env, ReplyOn, Response, StdError, SubMsg,
+    WasmMsg,
 };
 use cosmwasm_std::{Binary, Event, Reply, SubMsgResponse, SubMsgResult};
 
@@ -29,6 +38,9 @@ fn test_empty_instantiation() {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
     let info = mock_info("creator", &[]);
 
@@ -153,6 +165,9 @@ fn test_add_app_component_unauthorized() {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info, inst_msg).unwrap();
@@ -163,6 +178,7 @@ fn test_add_app_component_unauthorized() {
             name: "token".to_string(),
             ado_type: "cw721".to_string(),
             component_type: ComponentType::New(to_json_binary(&true).unwrap()),
+            depends_on: None,
         },
     };
 
@@ -272,6 +288,9 @@ fn test_claim_ownership_unauth() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info, inst_msg).unwrap();
@@ -297,6 +316,9 @@ fn test_claim_ownership_not_found() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -321,6 +343,9 @@ fn test_claim_ownership_empty() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -345,6 +370,9 @@ fn test_claim_ownership_all() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -383,6 +411,9 @@ fn test_claim_ownership() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -441,6 +472,9 @@ fn test_proxy_message_unauth() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info, inst_msg).unwrap();
@@ -466,6 +500,9 @@ fn test_proxy_message_not_found() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -491,6 +528,9 @@ fn test_proxy_message() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
     ADO_ADDRESSES
         .save(
@@ -528,6 +568,122 @@ fn test_proxy_message() {
     assert_eq!(expected, res)
 }
 
+#[test]
+fn test_proxy_messages() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+    for (name, addr) in [("token", "tokenaddress"), ("staking", "stakingaddress")] {
+        ADO_ADDRESSES
+            .save(deps.as_mut().storage, name, &Addr::unchecked(addr))
+            .unwrap();
+    }
+
+    let msg = ExecuteMsg::ProxyMessages {
+        msgs: vec![
+            ComponentMessage {
+                name: "token".to_string(),
+                msg: to_json_binary(&true).unwrap(),
+                funds: Some(coins(100, "uandr")),
+            },
+            ComponentMessage {
+                name: "staking".to_string(),
+                msg: to_json_binary(&false).unwrap(),
+                funds: None,
+            },
+        ],
+    };
+
+    let operator_info = mock_info("operator", &coins(100, "uandr"));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        operator_info.clone(),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    // Permissions are scoped to each component
+    for name in ["token", "staking"] {
+        let permission_msg = ExecuteMsg::Permissioning(PermissioningMessage::SetPermission {
+            actors: vec![AndrAddr::from_string("operator")],
+            action: proxy_messages_action(name),
+            permission: Permission::Local(LocalPermission::whitelisted(None)),
+        });
+        execute(deps.as_mut(), env.clone(), info.clone(), permission_msg).unwrap();
+
+        if name == "token" {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                msg.clone(),
+            )
+            .unwrap_err();
+            assert_eq!(ContractError::Unauthorized {}, err);
+        }
+    }
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("operator", &coins(50, "uandr")),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidFunds {
+            msg: "Sent funds must match the funds of the messages".to_string()
+        },
+        err
+    );
+
+    let res = execute(deps.as_mut(), env.clone(), operator_info, msg).unwrap();
+    let expected = Response::new()
+        .add_attribute("method", "app_messages")
+        .add_message(WasmMsg::Execute {
+            contract_addr: "tokenaddress".to_string(),
+            msg: to_json_binary(&true).unwrap(),
+            funds: coins(100, "uandr"),
+        })
+        .add_attribute("recipient", "token")
+        .add_message(WasmMsg::Execute {
+            contract_addr: "stakingaddress".to_string(),
+            msg: to_json_binary(&false).unwrap(),
+            funds: vec![],
+        })
+        .add_attribute("recipient", "staking");
+    assert_eq!(expected, res);
+
+    // Base ADO messages cannot be proxied, even by the owner
+    let msg = ExecuteMsg::ProxyMessages {
+        msgs: vec![ComponentMessage {
+            name: "token".to_string(),
+            msg: to_json_binary(&AndromedaMsg::Ownership(OwnershipMessage::Disown)).unwrap(),
+            funds: None,
+        }],
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(
+        ContractError::ProxiedBaseMessage {
+            name: "token".to_string()
+        },
+        err
+    );
+}
+
 #[test]
 fn test_update_address_unauth() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -539,6 +695,9 @@ fn test_update_address_unauth() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     ADO_ADDRESSES
@@ -571,6 +730,9 @@ fn test_update_address_not_found() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -598,6 +760,9 @@ fn test_update_address() {
         owner: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     ADO_ADDRESSES
@@ -632,6 +797,9 @@ fn test_add_app_component_limit() {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     // we can just call .unwrap() to assert this was a success
@@ -651,6 +819,7 @@ fn test_add_app_component_limit() {
             name: "token".to_string(),
             ado_type: "cw721".to_string(),
             component_type: ComponentType::New(to_json_binary(&true).unwrap()),
+            depends_on: None,
         },
     };
 
@@ -666,6 +835,7 @@ fn test_reply_assign_app() {
         ado_type: "cw721".to_string(),
         name: "token".to_string(),
         component_type: ComponentType::New(to_json_binary(&true).unwrap()),
+        depends_on: None,
     };
     let component_idx = 1;
     ADO_DESCRIPTORS
@@ -700,3 +870,511 @@ fn test_reply_assign_app() {
     let res = reply(deps.as_mut(), env, mock_reply).unwrap();
     assert!(res.messages.is_empty());
 }
+
+#[test]
+fn test_instantiation_cyclic_dependencies() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        app_components: vec![
+            AppComponent::new("token", "cw20", to_json_binary(&true).unwrap())
+                .with_depends_on(vec!["staking".to_string()]),
+            AppComponent::new("staking", "cw20-staking", to_json_binary(&true).unwrap())
+                .with_depends_on(vec!["token".to_string()]),
+        ],
+        name: String::from("Some App"),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+
+    let err = instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap_err();
+    assert_eq!(
+        ContractError::CyclicComponentDependencies {
+            components: "token, staking".to_string()
+        },
+        err
+    );
+}
+
+#[test]
+fn test_literal_placeholder_is_not_a_dependency() {
+    let deps = mock_dependencies_custom(&[]);
+    // Placeholders are only substituted for the components listed in `depends_on`
+    let component = AppComponent::new(
+        "token",
+        "cw20",
+        Binary::from(r#"{"name":"${not_a_component}"}"#.as_bytes()),
+    );
+    assert!(component.get_dependencies().is_empty());
+    assert_eq!(
+        order_by_dependencies(std::slice::from_ref(&component)).unwrap(),
+        vec!["token".to_string()]
+    );
+
+    let resolved = resolve_existing_dependencies(deps.as_ref().storage, component.clone()).unwrap();
+    assert_eq!(resolved, component);
+}
+
+/// Mocks the reply to the instantiation of the component with the given index, returning the given data
+fn mock_instantiate_reply(id: u64, addr: &str, data: &[u8]) -> Reply {
+    // Protobuf encoded `MsgInstantiateContractResponse`
+    let mut resp = vec![0x0a, addr.len() as u8];
+    resp.extend(addr.as_bytes());
+    resp.extend([0x12, data.len() as u8]);
+    resp.extend(data);
+    Reply {
+        id,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            data: Some(Binary::from(resp)),
+            events: vec![Event::new("instantiate").add_attribute("contract_address", addr)],
+        }),
+    }
+}
+
+/// Instantiates an app with a `token` component and a `staking` component waiting on it
+fn instantiate_with_dependent(mut deps: DepsMut, env: Env, staking_msg: &str) {
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.branch(), env, mock_info("creator", &[]), inst_msg).unwrap();
+
+    let token = AppComponent::new("token", "cw20", to_json_binary(&true).unwrap());
+    let staking = AppComponent::new(
+        "staking",
+        "cw20-staking",
+        Binary::from(staking_msg.as_bytes()),
+    )
+    .with_depends_on(vec!["token".to_string()]);
+    add_app_component(deps.storage, &token).unwrap();
+    add_app_component(deps.storage, &staking).unwrap();
+    ADO_ADDRESSES
+        .save(deps.storage, "token", &Addr::unchecked("cosmos2contract"))
+        .unwrap();
+    ADO_ADDRESSES
+        .save(deps.storage, "staking", &Addr::unchecked("stakingaddress"))
+        .unwrap();
+    PENDING_DEPENDENCIES
+        .save(deps.storage, "2", &vec!["token".to_string()])
+        .unwrap();
+}
+
+#[test]
+fn test_reply_instantiates_dependents() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate_with_dependent(
+        deps.as_mut(),
+        env.clone(),
+        r#"{"staking_token":"${token}","denom":"${token.data}"}"#,
+    );
+
+    let res = reply(
+        deps.as_mut(),
+        env.clone(),
+        mock_instantiate_reply(1, "cosmos2contract", br#""factory/cosmos2contract/utoken""#),
+    )
+    .unwrap();
+    let expected_msg = SubMsg::reply_always(
+        WasmMsg::Instantiate2 {
+            admin: Some("creator".to_string()),
+            code_id: 1,
+            label: "Instantiate: cw20-staking".to_string(),
+            msg: Binary::from(
+                r#"{"staking_token":"cosmos2contract","denom":"factory/cosmos2contract/utoken"}"#
+                    .as_bytes(),
+            ),
+            funds: vec![],
+            salt: Binary::from("staking".as_bytes()),
+        },
+        2,
+    );
+    assert_eq!(res.messages, vec![expected_msg]);
+    assert!(!PENDING_DEPENDENCIES.has(deps.as_ref().storage, "2"));
+}
+
+#[test]
+fn test_reply_dependent_missing_data() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate_with_dependent(deps.as_mut(), env.clone(), r#"{"denom":"${token.data}"}"#);
+
+    // The token returns no data to substitute
+    let err = reply(
+        deps.as_mut(),
+        env,
+        mock_instantiate_reply(1, "cosmos2contract", &[]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidComponentDependency {
+            name: "staking".to_string(),
+            dependency: "token".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_remove_app_component() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+
+    let component = AppComponent::new("token", "cw20", to_json_binary(&true).unwrap());
+    add_app_component(deps.as_mut().storage, &component).unwrap();
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+
+    let msg = ExecuteMsg::RemoveAppComponent {
+        name: "token".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    let expected_msg = SubMsg::reply_on_error(
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MOCK_VFS_CONTRACT.to_string(),
+            msg: to_json_binary(&VFSExecuteMsg::RemovePath {
+                name: "token".to_string(),
+                parent_address: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }),
+        ReplyId::RegisterPath.repr(),
+    );
+    assert_eq!(res.messages, vec![expected_msg]);
+    assert!(!ADO_ADDRESSES.has(deps.as_ref().storage, "token"));
+    assert!(ADO_DESCRIPTORS
+        .may_load(deps.as_ref().storage, "1")
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_migrate_components_symlink() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+
+    let component = AppComponent::symlink("token", "cw20", "/home/user/token");
+    add_app_component(deps.as_mut().storage, &component).unwrap();
+
+    // Only components instantiated by the app can be migrated
+    let msg = ExecuteMsg::MigrateComponents {
+        targets: vec![("token".to_string(), "1.0.0".to_string())],
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidComponent {
+            name: "token".to_string()
+        },
+        err
+    );
+}
+
+#[test]
+fn test_migrate_components_requires_app_admin() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+
+    let component = AppComponent::new("token", "cw20", to_json_binary(&true).unwrap());
+    add_app_component(deps.as_mut().storage, &component).unwrap();
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+
+    // The mock component has no admin, so the app cannot migrate it
+    let msg = ExecuteMsg::MigrateComponents {
+        targets: vec![("token".to_string(), "1.0.0".to_string())],
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(
+        ContractError::AppNotComponentAdmin {
+            name: "token".to_string()
+        },
+        err
+    );
+}
+
+#[test]
+fn test_replace_app_component() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+
+    let component = AppComponent::new("token", "cw20", to_json_binary(&true).unwrap());
+    add_app_component(deps.as_mut().storage, &component).unwrap();
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+
+    let msg = ExecuteMsg::ReplaceAppComponent {
+        name: "token".to_string(),
+        component: AppComponent::new("token", "cw20", to_json_binary(&false).unwrap()),
+    };
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let expected_msg = SubMsg::reply_always(
+        WasmMsg::Instantiate {
+            admin: Some("creator".to_string()),
+            code_id: 1,
+            msg: to_json_binary(&false).unwrap(),
+            funds: vec![],
+            label: "Instantiate: cw20".to_string(),
+        },
+        1,
+    );
+    assert_eq!(res.messages.last(), Some(&expected_msg));
+    // The component has no address until the new instance is created
+    assert!(!ADO_ADDRESSES.has(deps.as_ref().storage, "token"));
+    assert_eq!(
+        PENDING_REPLACEMENTS
+            .load(deps.as_ref().storage, "token")
+            .unwrap(),
+        Addr::unchecked("tokenaddress")
+    );
+
+    let mock_reply_event = Event::new("instantiate").add_attribute(
+        "contract_address".to_string(),
+        "cosmos2contract".to_string(),
+    );
+    let mock_reply = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            data: Some(Binary::from_base64("Cg9jb3Ntb3MyY29udHJhY3QSAA==").unwrap()),
+            events: vec![mock_reply_event],
+        }),
+    };
+    reply(deps.as_mut(), env, mock_reply).unwrap();
+    assert_eq!(
+        ADO_ADDRESSES.load(deps.as_ref().storage, "token").unwrap(),
+        Addr::unchecked("cosmos2contract")
+    );
+    assert!(!PENDING_REPLACEMENTS.has(deps.as_ref().storage, "token"));
+}
+
+#[test]
+fn test_cross_chain_component_after_instantiation() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+
+    let component = AppComponent::new("token", "cw20", to_json_binary(&true).unwrap());
+    add_app_component(deps.as_mut().storage, &component).unwrap();
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+
+    let cross_chain_component = |name: &str| AppComponent {
+        name: name.to_string(),
+        ado_type: "cw20".to_string(),
+        component_type: ComponentType::CrossChain(CrossChainComponent {
+            chain: "chain2".to_string(),
+            instantiate_msg: to_json_binary(&true).unwrap(),
+        }),
+        depends_on: None,
+    };
+
+    // Cross-chain components can only be declared at instantiation
+    let msg = ExecuteMsg::AddAppComponent {
+        component: cross_chain_component("remote"),
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CrossChainComponentAfterInstantiation {
+            name: "remote".to_string()
+        }
+    );
+    assert!(!ADO_ADDRESSES.has(deps.as_ref().storage, "remote"));
+
+    let msg = ExecuteMsg::ReplaceAppComponent {
+        name: "token".to_string(),
+        component: cross_chain_component("token"),
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CrossChainComponentAfterInstantiation {
+            name: "token".to_string()
+        }
+    );
+    assert_eq!(
+        ADO_ADDRESSES.load(deps.as_ref().storage, "token").unwrap(),
+        Addr::unchecked("tokenaddress")
+    );
+}
+
+#[test]
+fn test_cross_chain_create_ack() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+
+    let component = AppComponent {
+        name: "token".to_string(),
+        ado_type: "cw20".to_string(),
+        component_type: ComponentType::CrossChain(CrossChainComponent {
+            instantiate_msg: to_json_binary(&true).unwrap(),
+            chain: "chain".to_string(),
+        }),
+        depends_on: None,
+    };
+    add_app_component(deps.as_mut().storage, &component).unwrap();
+    let staking = AppComponent {
+        name: "staking".to_string(),
+        ..component
+    };
+    add_app_component(deps.as_mut().storage, &staking).unwrap();
+    for name in ["token", "staking"] {
+        ADO_ADDRESSES
+            .save(deps.as_mut().storage, name, &Addr::unchecked(""))
+            .unwrap();
+    }
+
+    let msg = ExecuteMsg::CrossChainCreateAck {
+        chain: "chain".to_string(),
+        ado_type: "app-contract".to_string(),
+        address: Some("remote_app".to_string()),
+        components: vec![
+            ("token".to_string(), "remote_token".to_string()),
+            ("staking".to_string(), "remote_staking".to_string()),
+        ],
+        error: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_KERNEL_CONTRACT, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let addresses: Vec<ComponentAddress> = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            andromeda_app::app::QueryMsg::GetAddressesWithNames {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    // Each component reports its own address on the receiving chain
+    assert_eq!(
+        addresses,
+        vec![
+            ComponentAddress {
+                name: "staking".to_string(),
+                address: "remote_staking".to_string(),
+            },
+            ComponentAddress {
+                name: "token".to_string(),
+                address: "remote_token".to_string(),
+            },
+        ]
+    );
+}
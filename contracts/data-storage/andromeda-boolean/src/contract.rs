@@ -71,12 +71,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -30,14 +30,16 @@ pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Respon
         ExecuteMsg::SetValue { value } => set_value(ctx, value, action),
         ExecuteMsg::DeleteValue {} => delete_value(ctx),
         ExecuteMsg::Rates(rates_message) => match rates_message {
-            RatesMessage::SetRate { rate, .. } => match rate {
-                Rate::Local(local_rate) => {
-                    // Percent rates aren't applicable in this case, so we enforce Flat rates
-                    ensure!(local_rate.value.is_flat(), ContractError::InvalidRate {});
-                    ADOContract::default().execute(ctx, msg)
+            RatesMessage::SetRate { rate, .. } | RatesMessage::AddRate { rate, .. } => {
+                match rate {
+                    Rate::Local(local_rate) => {
+                        // Percent rates aren't applicable in this case, so we enforce Flat rates
+                        ensure!(local_rate.value.is_flat(), ContractError::InvalidRate {});
+                        ADOContract::default().execute(ctx, msg)
+                    }
+                    Rate::Contract(_) => ADOContract::default().execute(ctx, msg),
                 }
-                Rate::Contract(_) => ADOContract::default().execute(ctx, msg),
-            },
+            }
             RatesMessage::RemoveRate { .. } => ADOContract::default().execute(ctx, msg),
         },
         _ => ADOContract::default().execute(ctx, msg),
@@ -56,6 +56,7 @@ fn test_set_value_with_tax() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weights: None,
         }),
     });
 
@@ -78,6 +79,7 @@ fn test_set_value_with_tax() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -5,8 +5,8 @@ use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
 use andromeda_data_storage::primitive::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
-    ado_contract::ADOContract,
-    common::{context::ExecuteContext, encode_binary},
+    ado_contract::{rates::migrate_rates_to_lists, ADOContract},
+    common::{context::ExecuteContext, encode_binary, migration::MigrationRegistry},
     error::ContractError,
 };
 
@@ -20,6 +20,11 @@ use crate::{
 const CONTRACT_NAME: &str = "crates.io:andromeda-primitive";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// State migrations run when upgrading the contract
+fn migrations() -> MigrationRegistry {
+    MigrationRegistry::new().add_step("2.0.3", "2.0.4", migrate_rates_to_lists)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -52,17 +57,18 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    ADOContract::default().migrate(deps, CONTRACT_NAME, CONTRACT_VERSION)
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    ADOContract::default().migrate_with_registry(
+        deps,
+        &env,
+        CONTRACT_NAME,
+        CONTRACT_VERSION,
+        &migrations(),
+    )
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -72,6 +78,14 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         QueryMsg::GetType { key } => encode_binary(&get_type(deps.storage, key)?),
         QueryMsg::AllKeys {} => encode_binary(&all_keys(deps.storage)?),
         QueryMsg::OwnerKeys { owner } => encode_binary(&owner_keys(&deps, owner)?),
+        QueryMsg::MigrationPlan { from_version } => {
+            encode_binary(&ADOContract::default().query_migration_plan(
+                deps,
+                &migrations(),
+                from_version,
+                CONTRACT_VERSION,
+            )?)
+        }
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -1,6 +1,6 @@
 #![cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{execute, instantiate, migrate, query};
 use andromeda_data_storage::primitive::{
     ExecuteMsg, GetTypeResponse, GetValueResponse, InstantiateMsg, Primitive, PrimitiveRestriction,
     QueryMsg,
@@ -80,7 +80,8 @@ impl MockPrimitive {
 }
 
 pub fn mock_andromeda_primitive() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new_with_empty(execute, instantiate, query);
+    let contract =
+        ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate_empty(migrate);
     Box::new(contract)
 }
 
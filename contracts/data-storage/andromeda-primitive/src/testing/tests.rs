@@ -77,6 +77,7 @@ fn test_set_value_with_tax() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weights: None,
         }),
     });
 
@@ -96,6 +97,7 @@ fn test_set_value_with_tax() {
         recipients: vec![],
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weights: None,
     });
 
     let msg = ExecuteMsg::Rates(RatesMessage::SetRate {
@@ -109,12 +111,13 @@ fn test_set_value_with_tax() {
         .unwrap();
     assert_eq!(
         queried_rates.unwrap(),
-        Rate::Local(LocalRate {
+        vec![Rate::Local(LocalRate {
             rate_type: LocalRateType::Additive,
             recipients: vec![Recipient::new(AndrAddr::from_string("creator"), None)],
             value: LocalRateValue::Flat(coin(20_u128, "uandr")),
             description: None,
-        })
+            weights: None,
+        })]
     );
 
     let rate: Rate = Rate::Local(LocalRate {
@@ -126,6 +129,7 @@ fn test_set_value_with_tax() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weights: None,
     });
 
     // Set rates
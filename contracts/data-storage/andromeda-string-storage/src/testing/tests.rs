@@ -67,6 +67,7 @@ fn test_set_value_with_tax() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weights: None,
         }),
     });
 
@@ -89,6 +90,7 @@ fn test_set_value_with_tax() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -9,15 +9,15 @@ use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     amp::messages::AMPPkt,
     common::{
-        actions::call_action, encode_binary, expiration::Expiry, Milliseconds,
+        actions::call_action, encode_binary, expiration::Expiry, response::AndrEvent, Milliseconds,
         MillisecondsExpiration,
     },
     error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
-    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdError, SubMsg, Uint128,
+    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Event,
+    MessageInfo, Reply, Response, StdError, SubMsg, Uint128,
 };
 use cw_utils::nonpayable;
 
@@ -102,13 +102,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -156,6 +150,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let conditional_splitter = CONDITIONAL_SPLITTER.load(deps.storage)?;
 
     let mut msgs: Vec<SubMsg> = Vec::new();
+    let mut events: Vec<Event> = Vec::new();
     let mut amp_funds: Vec<Coin> = Vec::new();
 
     let mut remainder_funds = info.funds.clone();
@@ -180,6 +175,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
                     remainder_funds[i].amount.checked_sub(recip_coin.amount)?;
                 vec_coin.push(recip_coin.clone());
                 amp_funds.push(recip_coin);
+                events.push(
+                    AndrEvent::FundsSent {
+                        recipient: address_percent.recipient.address.to_string(),
+                        funds: vec_coin.clone(),
+                    }
+                    .into(),
+                );
 
                 let amp_msg = address_percent
                     .recipient
@@ -192,6 +194,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     remainder_funds.retain(|x| x.amount > Uint128::zero());
 
     if !remainder_funds.is_empty() {
+        events.push(
+            AndrEvent::FundsSent {
+                recipient: info.sender.to_string(),
+                funds: remainder_funds.clone(),
+            }
+            .into(),
+        );
         msgs.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
             to_address: info.sender.to_string(),
             amount: remainder_funds,
@@ -205,6 +214,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
 
     Ok(Response::new()
         .add_submessages(msgs)
+        .add_events(events)
         .add_attribute("action", "send")
         .add_attribute("sender", info.sender.to_string()))
 }
@@ -3,14 +3,16 @@ use andromeda_std::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
     },
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, response::AndrEvent, Funds, Milliseconds},
     error::ContractError,
 };
-use andromeda_testing::economics_msg::generate_economics_message;
+use andromeda_testing::economics_msg::{
+    generate_economics_message, generate_economics_message_with_funds,
+};
 use cosmwasm_std::{
     attr, from_json,
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
-    to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Response, SubMsg, Timestamp,
+    to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Event, Response, SubMsg, Timestamp,
     Uint128,
 };
 pub const OWNER: &str = "creator";
@@ -30,6 +32,14 @@ use andromeda_finance::{
     splitter::AddressPercent,
 };
 
+fn funds_sent(recipient: &str, amount: u128) -> Event {
+    AndrEvent::FundsSent {
+        recipient: recipient.to_string(),
+        funds: vec![Coin::new(amount, "uandr")],
+    }
+    .into()
+}
+
 fn init(deps: DepsMut) -> Response {
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -438,7 +448,16 @@ fn test_execute_send() {
             amp_msg,
         ])
         .add_attributes(vec![attr("action", "send"), attr("sender", "creator")])
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![Funds::Native(Coin::new(first_batch, "uandr"))],
+        ))
+        .add_events(vec![
+            funds_sent("address1", 4),
+            funds_sent("address2", 1),
+            funds_sent(OWNER, 3),
+        ]);
 
     assert_eq!(res, expected_res);
 
@@ -480,7 +499,16 @@ fn test_execute_send() {
             amp_msg,
         ])
         .add_attributes(vec![attr("action", "send"), attr("sender", "creator")])
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![Funds::Native(Coin::new(second_batch, "uandr"))],
+        ))
+        .add_events(vec![
+            funds_sent("address1", 2),
+            funds_sent("address2", 1),
+            funds_sent(OWNER, 7),
+        ]);
 
     assert_eq!(res, expected_res);
 
@@ -514,7 +542,12 @@ fn test_execute_send() {
         // No refund for the sender since the percentages add up to 100
         .add_submessage(amp_msg)
         .add_attributes(vec![attr("action", "send"), attr("sender", "creator")])
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![Funds::Native(Coin::new(third_batch, "uandr"))],
+        ))
+        .add_events(vec![funds_sent("address1", 50), funds_sent("address2", 50)]);
 
     assert_eq!(res, expected_res);
 }
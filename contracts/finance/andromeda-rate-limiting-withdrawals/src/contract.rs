@@ -7,6 +7,7 @@ use andromeda_std::ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg};
 use andromeda_std::ado_contract::ADOContract;
 use andromeda_std::common::actions::call_action;
 use andromeda_std::common::context::ExecuteContext;
+use andromeda_std::common::response::AndrEvent;
 use andromeda_std::common::Milliseconds;
 use andromeda_std::{common::encode_binary, error::ContractError};
 
@@ -78,13 +79,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -224,6 +219,13 @@ fn execute_withdraw(ctx: ExecuteContext, amount: Uint128) -> Result<Response, Co
             to_address: info.sender.to_string(),
             amount: vec![coin.clone()],
         }))
+        .add_event(
+            AndrEvent::FundsSent {
+                recipient: info.sender.to_string(),
+                funds: vec![coin.clone()],
+            }
+            .into(),
+        )
         .add_attribute("action", "withdrew funds")
         .add_attribute("coin", coin.to_string());
     Ok(res)
@@ -11,12 +11,14 @@ use andromeda_finance::{
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     amp::messages::AMPPkt,
-    common::{actions::call_action, encode_binary, expiration::Expiry, Milliseconds},
+    common::{
+        actions::call_action, encode_binary, expiration::Expiry, response::AndrEvent, Milliseconds,
+    },
     error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
-    attr, coins, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    attr, coins, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Event,
     MessageInfo, Reply, Response, StdError, SubMsg,
 };
 use cw_utils::nonpayable;
@@ -104,13 +106,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -162,6 +158,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let splitter = SPLITTER.load(deps.storage)?;
 
     let mut msgs: Vec<SubMsg> = Vec::new();
+    let mut events: Vec<Event> = Vec::new();
     let mut amp_funds: Vec<Coin> = Vec::new();
 
     let mut pkt = AMPPkt::from_ctx(ctx.amp_ctx, ctx.env.contract.address.to_string());
@@ -188,6 +185,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
                 let recipient_funds =
                     cosmwasm_std::coin(recipient_coin.amount.u128(), recipient_coin.denom);
 
+                events.push(
+                    AndrEvent::FundsSent {
+                        recipient: recipient.recipient.address.to_string(),
+                        funds: vec![recipient_funds.clone()],
+                    }
+                    .into(),
+                );
                 let amp_msg = recipient
                     .recipient
                     .generate_amp_msg(&deps.as_ref(), Some(vec![recipient_funds.clone()]))?;
@@ -200,9 +204,17 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
 
         // Refund message for sender
         if !remainder_funds.is_zero() {
+            let refund = coins(remainder_funds.u128(), denom);
+            events.push(
+                AndrEvent::FundsSent {
+                    recipient: info.sender.to_string(),
+                    funds: refund.clone(),
+                }
+                .into(),
+            );
             let msg = SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
                 to_address: info.sender.clone().into_string(),
-                amount: coins(remainder_funds.u128(), denom),
+                amount: refund,
             }));
             msgs.push(msg);
         }
@@ -217,6 +229,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
 
     Ok(Response::new()
         .add_submessages(msgs)
+        .add_events(events)
         .add_attribute("action", "send")
         .add_attribute("sender", info.sender.to_string()))
 }
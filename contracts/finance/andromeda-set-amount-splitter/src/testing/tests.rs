@@ -3,14 +3,16 @@ use andromeda_std::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
     },
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, response::AndrEvent, Funds, Milliseconds},
     error::ContractError,
 };
-use andromeda_testing::economics_msg::generate_economics_message;
+use andromeda_testing::economics_msg::{
+    generate_economics_message, generate_economics_message_with_funds,
+};
 use cosmwasm_std::{
     attr, coin, coins, from_json,
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
-    to_json_binary, BankMsg, Coin, CosmosMsg, DepsMut, Response, SubMsg,
+    to_json_binary, BankMsg, Coin, CosmosMsg, DepsMut, Event, Response, SubMsg,
 };
 pub const OWNER: &str = "creator";
 
@@ -25,6 +27,14 @@ use andromeda_finance::set_amount_splitter::{
     AddressAmount, ExecuteMsg, GetSplitterConfigResponse, InstantiateMsg, QueryMsg, Splitter,
 };
 
+fn funds_sent(recipient: &str, funds: Coin) -> Event {
+    AndrEvent::FundsSent {
+        recipient: recipient.to_string(),
+        funds: vec![funds],
+    }
+    .into()
+}
+
 fn init(deps: DepsMut) -> Response {
     let mock_recipient: Vec<AddressAmount> = vec![AddressAmount {
         recipient: Recipient::from_string(String::from("some_address")),
@@ -244,7 +254,21 @@ fn test_execute_send() {
             amp_msg,
         ])
         .add_attributes(vec![attr("action", "send"), attr("sender", "creator")])
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![
+                Funds::Native(coin(sender_funds_amount, "uandr")),
+                Funds::Native(coin(50, "usdc")),
+            ],
+        ))
+        .add_events(vec![
+            funds_sent("address1", coin(1, "uandr")),
+            funds_sent("address2", coin(1, "uandr")),
+            funds_sent(OWNER, coin(9998, "uandr")),
+            funds_sent("address1", coin(30, "usdc")),
+            funds_sent("address2", coin(20, "usdc")),
+        ]);
 
     assert_eq!(res, expected_res);
 }
@@ -318,7 +342,16 @@ fn test_execute_send_ado_recipient() {
         ])
         .add_attribute("action", "send")
         .add_attribute("sender", "creator")
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![Funds::Native(coin(sender_funds_amount, "uandr"))],
+        ))
+        .add_events(vec![
+            funds_sent("address1", coin(1, "uandr")),
+            funds_sent("address2", coin(1, "uandr")),
+            funds_sent(OWNER, coin(9_998, "uandr")),
+        ]);
 
     assert_eq!(res, expected_res);
 }
@@ -6,13 +6,15 @@ use andromeda_finance::splitter::{
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     amp::messages::AMPPkt,
-    common::{actions::call_action, encode_binary, expiration::Expiry, Milliseconds},
+    common::{
+        actions::call_action, encode_binary, expiration::Expiry, response::AndrEvent, Milliseconds,
+    },
     error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
-    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdError, SubMsg, Uint128,
+    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Event,
+    MessageInfo, Reply, Response, StdError, SubMsg, Uint128,
 };
 use cw_utils::nonpayable;
 
@@ -85,13 +87,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -135,6 +131,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let splitter = SPLITTER.load(deps.storage)?;
 
     let mut msgs: Vec<SubMsg> = Vec::new();
+    let mut events: Vec<Event> = Vec::new();
     let mut amp_funds: Vec<Coin> = Vec::new();
 
     let mut remainder_funds = info.funds.clone();
@@ -164,6 +161,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
             }
         }
         if !vec_coin.is_empty() {
+            events.push(
+                AndrEvent::FundsSent {
+                    recipient: recipient_addr.recipient.address.to_string(),
+                    funds: vec_coin.clone(),
+                }
+                .into(),
+            );
             let amp_msg = recipient_addr
                 .recipient
                 .generate_amp_msg(&deps.as_ref(), Some(vec_coin))?;
@@ -178,6 +182,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     // From tests, it looks like owner of smart contract (Andromeda) will recieve the rest of funds.
     // If so, should be documented
     if !remainder_funds.is_empty() {
+        events.push(
+            AndrEvent::FundsSent {
+                recipient: info.sender.to_string(),
+                funds: remainder_funds.clone(),
+            }
+            .into(),
+        );
         msgs.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
             to_address: info.sender.to_string(),
             amount: remainder_funds,
@@ -192,6 +203,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
 
     Ok(Response::new()
         .add_submessages(msgs)
+        .add_events(events)
         .add_attribute("action", "send")
         .add_attribute("sender", info.sender.to_string()))
 }
@@ -3,14 +3,16 @@ use andromeda_std::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
     },
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, response::AndrEvent, Funds, Milliseconds},
     error::ContractError,
 };
-use andromeda_testing::economics_msg::generate_economics_message;
+use andromeda_testing::economics_msg::{
+    generate_economics_message, generate_economics_message_with_funds,
+};
 use cosmwasm_std::{
     attr, from_json,
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
-    to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Response, SubMsg, Timestamp,
+    to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Event, Response, SubMsg, Timestamp,
 };
 pub const OWNER: &str = "creator";
 
@@ -25,6 +27,14 @@ use andromeda_finance::splitter::{
     AddressPercent, ExecuteMsg, GetSplitterConfigResponse, InstantiateMsg, QueryMsg, Splitter,
 };
 
+fn funds_sent(recipient: &str, amount: u128) -> Event {
+    AndrEvent::FundsSent {
+        recipient: recipient.to_string(),
+        funds: vec![Coin::new(amount, "uluna")],
+    }
+    .into()
+}
+
 fn init(deps: DepsMut) -> Response {
     let mock_recipient: Vec<AddressPercent> = vec![AddressPercent {
         recipient: Recipient::from_string(String::from("some_address")),
@@ -326,7 +336,16 @@ fn test_execute_send() {
             amp_msg,
         ])
         .add_attributes(vec![attr("action", "send"), attr("sender", "creator")])
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![Funds::Native(Coin::new(sender_funds_amount, "uluna"))],
+        ))
+        .add_events(vec![
+            funds_sent("address1", 1000),
+            funds_sent("address2", 2000),
+            funds_sent(OWNER, 7000),
+        ]);
 
     assert_eq!(res, expected_res);
 }
@@ -402,7 +421,16 @@ fn test_execute_send_ado_recipient() {
         ])
         .add_attribute("action", "send")
         .add_attribute("sender", "creator")
-        .add_submessage(generate_economics_message(OWNER, "Send"));
+        .add_submessage(generate_economics_message_with_funds(
+            OWNER,
+            "Send",
+            vec![Funds::Native(Coin::new(sender_funds_amount, "uluna"))],
+        ))
+        .add_events(vec![
+            funds_sent("address1", 1000),
+            funds_sent("address2", 2000),
+            funds_sent(OWNER, 7000),
+        ]);
 
     assert_eq!(res, expected_res);
 }
@@ -5,12 +5,12 @@ use andromeda_finance::timelock::{
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     amp::Recipient,
-    common::{actions::call_action, encode_binary},
+    common::{actions::call_action, encode_binary, response::AndrEvent},
     error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
-    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, SubMsg,
+    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, SubMsg,
 };
 
 use crate::state::{escrows, get_key, get_keys_for_recipient};
@@ -52,13 +52,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -151,9 +145,17 @@ fn execute_release_funds(
     ensure!(!keys.is_empty(), ContractError::NoLockedFunds {});
 
     let mut msgs: Vec<SubMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
     for key in keys.iter() {
         let funds: Escrow = escrows().load(deps.storage, key.clone())?;
         if !funds.is_locked(&env.block)? {
+            events.push(
+                AndrEvent::FundsSent {
+                    recipient: funds.recipient.address.to_string(),
+                    funds: funds.coins.clone(),
+                }
+                .into(),
+            );
             let msg = funds
                 .recipient
                 .generate_direct_msg(&deps.as_ref(), funds.coins)?;
@@ -164,10 +166,13 @@ fn execute_release_funds(
 
     ensure!(!msgs.is_empty(), ContractError::FundsAreLocked {});
 
-    Ok(Response::new().add_submessages(msgs).add_attributes(vec![
-        attr("action", "release_funds"),
-        attr("recipient_addr", recipient_addr),
-    ]))
+    Ok(Response::new()
+        .add_submessages(msgs)
+        .add_events(events)
+        .add_attributes(vec![
+            attr("action", "release_funds"),
+            attr("recipient_addr", recipient_addr),
+        ]))
 }
 
 fn execute_release_specific_funds(
@@ -189,13 +194,20 @@ fn execute_release_specific_funds(
                 ContractError::FundsAreLocked {}
             );
             escrows().remove(deps.storage, key)?;
+            let event = AndrEvent::FundsSent {
+                recipient: escrow.recipient.address.to_string(),
+                funds: escrow.coins.clone(),
+            };
             let msg = escrow
                 .recipient
                 .generate_direct_msg(&deps.as_ref(), escrow.coins)?;
-            Ok(Response::new().add_submessage(msg).add_attributes(vec![
-                attr("action", "release_funds"),
-                attr("recipient_addr", recipient),
-            ]))
+            Ok(Response::new()
+                .add_submessage(msg)
+                .add_event(event.into())
+                .add_attributes(vec![
+                    attr("action", "release_funds"),
+                    attr("recipient_addr", recipient),
+                ]))
         }
     }
 }
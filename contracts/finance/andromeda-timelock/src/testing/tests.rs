@@ -7,16 +7,27 @@ use andromeda_finance::timelock::{
 };
 use andromeda_std::{
     amp::Recipient,
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, response::AndrEvent, Funds, Milliseconds},
     error::ContractError,
 };
-use andromeda_testing::economics_msg::generate_economics_message;
+use andromeda_testing::economics_msg::generate_economics_message_with_funds;
 use cosmwasm_std::{
     attr, coin, coins, from_json,
     testing::{mock_env, mock_info},
-    BankMsg, Coin, Response, Timestamp,
+    BankMsg, Coin, Event, Response, Timestamp,
 };
 
+fn funds_sent(msg: &BankMsg) -> Event {
+    let BankMsg::Send { to_address, amount } = msg else {
+        unreachable!()
+    };
+    AndrEvent::FundsSent {
+        recipient: to_address.clone(),
+        funds: amount.clone(),
+    }
+    .into()
+}
+
 #[test]
 fn test_execute_hold_funds() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -44,7 +55,11 @@ fn test_execute_hold_funds() {
             ),
             attr("condition", format!("{:?}", Some(condition.clone()))),
         ])
-        .add_submessage(generate_economics_message("owner", "HoldFunds"));
+        .add_submessage(generate_economics_message_with_funds(
+            "owner",
+            "HoldFunds",
+            vec![Funds::Native(coin(1000, "uusd"))],
+        ));
     assert_eq!(expected, res);
 
     let query_msg = QueryMsg::GetLockedFunds {
@@ -141,12 +156,17 @@ fn test_execute_release_funds_no_condition() {
     };
     assert_eq!(
         Response::new()
+            .add_event(funds_sent(&bank_msg))
             .add_message(bank_msg)
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "owner"),
             ])
-            .add_submessage(generate_economics_message(owner, "ReleaseFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                owner,
+                "ReleaseFunds",
+                vec![Funds::Native(coin(100, "uusd"))]
+            )),
         res
     );
 }
@@ -185,12 +205,17 @@ fn test_execute_release_multiple_escrows() {
     };
     assert_eq!(
         Response::new()
+            .add_events(vec![funds_sent(&bank_msg1), funds_sent(&bank_msg2)])
             .add_messages(vec![bank_msg1, bank_msg2])
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "recipient"),
             ])
-            .add_submessage(generate_economics_message("sender2", "ReleaseFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                "sender2",
+                "ReleaseFunds",
+                vec![Funds::Native(coin(200, "uusd"))]
+            )),
         res
     );
 }
@@ -225,12 +250,17 @@ fn test_execute_release_funds_time_condition() {
     };
     assert_eq!(
         Response::new()
+            .add_event(funds_sent(&bank_msg))
             .add_message(bank_msg)
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "owner"),
             ])
-            .add_submessage(generate_economics_message(owner, "ReleaseFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                owner,
+                "ReleaseFunds",
+                vec![Funds::Native(coin(100, "uusd"))]
+            )),
         res
     );
 }
@@ -309,12 +339,20 @@ fn test_execute_release_funds_min_funds_condition() {
     };
     assert_eq!(
         Response::new()
+            .add_event(funds_sent(&bank_msg))
             .add_message(bank_msg)
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "owner"),
             ])
-            .add_submessage(generate_economics_message(owner, "ReleaseFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                owner,
+                "ReleaseFunds",
+                vec![
+                    Funds::Native(coin(110, "uusd")),
+                    Funds::Native(coin(120, "uluna"))
+                ]
+            )),
         res
     );
 }
@@ -358,12 +396,17 @@ fn test_execute_release_specific_funds_no_condition() {
     };
     assert_eq!(
         Response::new()
+            .add_event(funds_sent(&bank_msg))
             .add_message(bank_msg)
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "owner"),
             ])
-            .add_submessage(generate_economics_message(owner, "ReleaseSpecificFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                owner,
+                "ReleaseSpecificFunds",
+                vec![Funds::Native(coin(100, "uusd"))]
+            )),
         res
     );
 }
@@ -397,12 +440,17 @@ fn test_execute_release_specific_funds_time_condition() {
     };
     assert_eq!(
         Response::new()
+            .add_event(funds_sent(&bank_msg))
             .add_message(bank_msg)
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "owner"),
             ])
-            .add_submessage(generate_economics_message(owner, "ReleaseSpecificFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                owner,
+                "ReleaseSpecificFunds",
+                vec![Funds::Native(coin(100, "uusd"))]
+            )),
         res
     );
 }
@@ -453,12 +501,20 @@ fn test_execute_release_specific_funds_min_funds_condition() {
     };
     assert_eq!(
         Response::new()
+            .add_event(funds_sent(&bank_msg))
             .add_message(bank_msg)
             .add_attributes(vec![
                 attr("action", "release_funds"),
                 attr("recipient_addr", "owner"),
             ])
-            .add_submessage(generate_economics_message(owner, "ReleaseSpecificFunds")),
+            .add_submessage(generate_economics_message_with_funds(
+                owner,
+                "ReleaseSpecificFunds",
+                vec![
+                    Funds::Native(coin(110, "uusd")),
+                    Funds::Native(coin(120, "uluna"))
+                ]
+            )),
         res
     );
 }
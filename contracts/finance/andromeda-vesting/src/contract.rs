@@ -1,13 +1,16 @@
 use andromeda_std::{
     ado_contract::ADOContract,
-    common::{actions::call_action, context::ExecuteContext, withdraw::WithdrawalType},
+    common::{
+        actions::call_action, context::ExecuteContext, response::AndrEvent,
+        withdraw::WithdrawalType,
+    },
     error::ContractError,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response,
-    Uint128,
+    ensure, Binary, Coin, Decimal, Deps, DepsMut, Env, Event, MessageInfo, QuerierWrapper,
+    Response, Uint128,
 };
 use cw_asset::AssetInfo;
 use cw_utils::nonpayable;
@@ -66,13 +69,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -222,13 +219,20 @@ fn execute_claim(
     key.save(deps.storage, &batch)?;
 
     let config = CONFIG.load(deps.storage)?;
-    let withdraw_msg = config.recipient.generate_direct_msg(
-        &deps.as_ref(),
-        vec![Coin::new(amount_to_send.u128(), config.denom)],
-    )?;
+    let funds = vec![Coin::new(amount_to_send.u128(), config.denom)];
+    let withdraw_msg = config
+        .recipient
+        .generate_direct_msg(&deps.as_ref(), funds.clone())?;
 
     Ok(Response::new()
         .add_submessage(withdraw_msg)
+        .add_event(
+            AndrEvent::FundsSent {
+                recipient: config.recipient.address.to_string(),
+                funds,
+            }
+            .into(),
+        )
         .add_attribute("action", "claim")
         .add_attribute("amount", amount_to_send)
         .add_attribute("batch_id", batch_id.to_string())
@@ -283,18 +287,26 @@ fn execute_claim_all(
         key.save(deps.storage, &batch)?;
     }
     let mut msgs = vec![];
+    let mut events = vec![];
 
     // Don't want to error here since there will generally be other batches that will have
     // claimable amounts. Erroring for one would make the whole transaction fai.
     if !total_amount_to_send.is_zero() {
         let config = CONFIG.load(deps.storage)?;
-        msgs.push(config.recipient.generate_direct_msg(
-            &deps.as_ref(),
-            vec![Coin::new(total_amount_to_send.u128(), config.denom)],
-        )?)
+        let funds = vec![Coin::new(total_amount_to_send.u128(), config.denom)];
+        events.push(Event::from(AndrEvent::FundsSent {
+            recipient: config.recipient.address.to_string(),
+            funds: funds.clone(),
+        }));
+        msgs.push(
+            config
+                .recipient
+                .generate_direct_msg(&deps.as_ref(), funds)?,
+        )
     }
     Ok(Response::new()
         .add_submessages(msgs)
+        .add_events(events)
         .add_attribute("action", "claim_all")
         .add_attribute("last_batch_id_processed", last_batch_id))
 }
@@ -1,11 +1,13 @@
 use andromeda_std::{
-    amp::Recipient, common::withdraw::WithdrawalType, error::ContractError,
+    amp::Recipient,
+    common::{response::AndrEvent, withdraw::WithdrawalType},
+    error::ContractError,
     testing::mock_querier::MOCK_KERNEL_CONTRACT,
 };
 use cosmwasm_std::{
     coins, from_json,
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
-    BankMsg, Decimal, DepsMut, Response, Uint128,
+    BankMsg, Coin, Decimal, DepsMut, Event, Response, Uint128,
 };
 use cw_utils::Duration;
 
@@ -19,6 +21,14 @@ use andromeda_finance::vesting::{BatchResponse, Config, ExecuteMsg, InstantiateM
 
 const UNBONDING_BLOCK_DURATION: u64 = 5;
 
+fn funds_sent(funds: Vec<Coin>) -> Event {
+    AndrEvent::FundsSent {
+        recipient: "recipient".to_string(),
+        funds,
+    }
+    .into()
+}
+
 fn init(deps: DepsMut) -> Response {
     let msg = InstantiateMsg {
         recipient: Recipient::from_string("recipient"),
@@ -460,6 +470,7 @@ fn test_claim_batch_single_claim() {
                 to_address: "recipient".to_string(),
                 amount: coins(10, "uusd")
             })
+            .add_event(funds_sent(coins(10, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "10")
             .add_attribute("batch_id", "1")
@@ -521,6 +532,7 @@ fn test_claim_batch_not_nice_numbers_single_release() {
                 to_address: "recipient".to_string(),
                 amount: coins(7, "uusd")
             })
+            .add_event(funds_sent(coins(7, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "7")
             .add_attribute("batch_id", "1")
@@ -585,6 +597,7 @@ fn test_claim_batch_not_nice_numbers_multiple_releases() {
                 to_address: "recipient".to_string(),
                 amount: coins(12683916792, "uusd")
             })
+            .add_event(funds_sent(coins(12683916792, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "12683916792")
             .add_attribute("batch_id", "1")
@@ -614,6 +627,7 @@ fn test_claim_batch_not_nice_numbers_multiple_releases() {
                 to_address: "recipient".to_string(),
                 amount: coins(vesting_amount - 12683916792, "uusd")
             })
+            .add_event(funds_sent(coins(vesting_amount - 12683916792, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", (vesting_amount - 12683916792).to_string())
             .add_attribute("batch_id", "1")
@@ -679,6 +693,7 @@ fn test_claim_batch_middle_of_interval() {
                 to_address: "recipient".to_string(),
                 amount: coins(10, "uusd")
             })
+            .add_event(funds_sent(coins(10, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "10")
             .add_attribute("batch_id", "1")
@@ -739,6 +754,7 @@ fn test_claim_batch_multiple_claims() {
                 to_address: "recipient".to_string(),
                 amount: coins(10, "uusd")
             })
+            .add_event(funds_sent(coins(10, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "10")
             .add_attribute("batch_id", "1")
@@ -772,6 +788,7 @@ fn test_claim_batch_multiple_claims() {
                 to_address: "recipient".to_string(),
                 amount: coins(30, "uusd")
             })
+            .add_event(funds_sent(coins(30, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "30")
             .add_attribute("batch_id", "1")
@@ -833,6 +850,7 @@ fn test_claim_batch_all_releases() {
                 to_address: "recipient".to_string(),
                 amount: coins(100, "uusd")
             })
+            .add_event(funds_sent(coins(100, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "100")
             .add_attribute("batch_id", "1")
@@ -899,6 +917,7 @@ fn test_claim_batch_too_high_of_claim() {
                 // Only one gets claim
                 amount: coins(10, "uusd")
             })
+            .add_event(funds_sent(coins(10, "uusd")))
             .add_attribute("action", "claim")
             .add_attribute("amount", "10")
             .add_attribute("batch_id", "1")
@@ -1050,6 +1069,7 @@ fn test_claim_all() {
                 // 20 from the first, 40 from the second, 10 from the third.
                 amount: coins(20 + 40 + 10, "uusd")
             })
+            .add_event(funds_sent(coins(20 + 40 + 10, "uusd")))
             .add_attribute("action", "claim_all")
             .add_attribute("last_batch_id_processed", "3"),
         res
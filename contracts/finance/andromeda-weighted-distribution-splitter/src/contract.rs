@@ -12,13 +12,13 @@ use andromeda_std::{
     amp::Recipient,
     common::{
         actions::call_action, context::ExecuteContext, encode_binary, expiration::Expiry,
-        Milliseconds,
+        response::AndrEvent, Milliseconds,
     },
     error::ContractError,
 };
 use cosmwasm_std::{
-    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, SubMsg, Uint128,
+    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Event,
+    MessageInfo, Response, SubMsg, Uint128,
 };
 use cw_utils::nonpayable;
 
@@ -84,13 +84,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -238,6 +232,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
 
     let splitter = SPLITTER.load(deps.storage)?;
     let mut msgs: Vec<SubMsg> = Vec::new();
+    let mut events: Vec<Event> = Vec::new();
     let mut remainder_funds = info.funds.clone();
     let mut total_weight = Uint128::zero();
 
@@ -260,6 +255,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
         }
         // ADO receivers must use AndromedaMsg::Receive to execute their functionality
         // Others may just receive the funds
+        events.push(
+            AndrEvent::FundsSent {
+                recipient: recipient_addr.recipient.address.to_string(),
+                funds: vec_coin.clone(),
+            }
+            .into(),
+        );
         let direct_message = recipient_addr
             .recipient
             .generate_direct_msg(&deps.as_ref(), vec_coin)?;
@@ -268,6 +270,13 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     remainder_funds.retain(|x| x.amount > Uint128::zero());
 
     if !remainder_funds.is_empty() {
+        events.push(
+            AndrEvent::FundsSent {
+                recipient: info.sender.to_string(),
+                funds: remainder_funds.clone(),
+            }
+            .into(),
+        );
         msgs.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
             to_address: info.sender.to_string(),
             amount: remainder_funds,
@@ -303,6 +312,7 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
 
     Ok(Response::new()
         .add_submessages(msgs)
+        .add_events(events)
         .add_attributes(vec![attr("action", "send"), attr("sender", info.sender)]))
 }
 
@@ -2,7 +2,7 @@ use andromeda_std::{
     amp::addresses::AndrAddr,
     common::{
         expiration::{Expiry, MILLISECONDS_TO_NANOSECONDS_RATIO},
-        Milliseconds,
+        Funds, Milliseconds,
     },
     error::ContractError,
     testing::mock_querier::MOCK_KERNEL_CONTRACT,
@@ -13,7 +13,7 @@ use cosmwasm_std::{
     to_json_binary, Addr, BankMsg, Decimal, Decimal256, DepsMut, Response, Uint128, Uint256,
     WasmMsg,
 };
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
 
 use crate::{
     contract::{execute, instantiate, query},
@@ -27,7 +27,9 @@ use andromeda_fungible_tokens::cw20_staking::{
     AllocationConfig, AllocationState, Config, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg,
     RewardToken, RewardTokenUnchecked, RewardType, StakerResponse, State,
 };
-use andromeda_testing::economics_msg::generate_economics_message;
+use andromeda_testing::economics_msg::{
+    generate_economics_message, generate_economics_message_with_funds,
+};
 use cw_asset::{AssetInfo, AssetInfoUnchecked};
 
 const MOCK_STAKING_TOKEN: &str = "staking_token";
@@ -354,7 +356,14 @@ fn test_stake_unstake_tokens() {
             .add_attribute("sender", "sender")
             .add_attribute("share", "100")
             .add_attribute("amount", "100")
-            .add_submessage(generate_economics_message(MOCK_STAKING_TOKEN, "Receive")),
+            .add_submessage(generate_economics_message_with_funds(
+                MOCK_STAKING_TOKEN,
+                "Receive",
+                vec![Funds::Cw20(Cw20Coin {
+                    address: MOCK_STAKING_TOKEN.to_string(),
+                    amount: Uint128::new(100)
+                })]
+            )),
         res
     );
 
@@ -393,7 +402,14 @@ fn test_stake_unstake_tokens() {
             .add_attribute("sender", "other_sender")
             .add_attribute("share", "50")
             .add_attribute("amount", "100")
-            .add_submessage(generate_economics_message(MOCK_STAKING_TOKEN, "Receive")),
+            .add_submessage(generate_economics_message_with_funds(
+                MOCK_STAKING_TOKEN,
+                "Receive",
+                vec![Funds::Cw20(Cw20Coin {
+                    address: MOCK_STAKING_TOKEN.to_string(),
+                    amount: Uint128::new(100)
+                })]
+            )),
         res
     );
 
@@ -855,7 +871,14 @@ fn test_update_global_indexes_cw20_deposit() {
         Response::new()
             .add_attribute("action", "update_global_indexes")
             .add_attribute("cw20:incentive_token", "0.2")
-            .add_submessage(generate_economics_message(MOCK_INCENTIVE_TOKEN, "Receive")),
+            .add_submessage(generate_economics_message_with_funds(
+                MOCK_INCENTIVE_TOKEN,
+                "Receive",
+                vec![Funds::Cw20(Cw20Coin {
+                    address: MOCK_INCENTIVE_TOKEN.to_string(),
+                    amount: Uint128::new(20)
+                })]
+            )),
         res
     );
 
@@ -5,7 +5,7 @@ use andromeda_std::ado_base::permissioning::{LocalPermission, Permission};
 use andromeda_std::ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate};
 use andromeda_std::ado_contract::ADOContract;
 use andromeda_std::amp::{AndrAddr, Recipient};
-use andromeda_std::common::context::ExecuteContext;
+use andromeda_std::common::{context::ExecuteContext, response::AndrEvent};
 
 use andromeda_std::{error::ContractError, testing::mock_querier::MOCK_KERNEL_CONTRACT};
 use andromeda_testing::economics_msg::generate_economics_message;
@@ -98,6 +98,7 @@ fn test_transfer() {
             percent: Decimal::percent(10),
         }),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -110,6 +111,14 @@ fn test_transfer() {
         attr("deducted", "10cosmos2contract"),
         attr("payment", "royalty_recipient<10cosmos2contract"),
     ]);
+    let expected_andr_event: Event = AndrEvent::RateApplied {
+        rate_type: "deductive".to_string(),
+        recipient: "royalty_recipient".to_string(),
+        asset: "cw20:cosmos2contract".to_string(),
+        amount: Uint128::new(10),
+        deducted: true,
+    }
+    .into();
 
     // Blacklist the sender who otherwise would have been able to call the function successfully
     let permission = Permission::Local(LocalPermission::blacklisted(None));
@@ -137,6 +146,7 @@ fn test_transfer() {
     assert_eq!(
         Response::new()
             .add_event(expected_event)
+            .add_event(expected_andr_event)
             .add_attribute("action", "transfer")
             .add_attribute("from", "sender")
             .add_attribute("to", "other")
@@ -204,6 +214,7 @@ fn test_send() {
             percent: Decimal::percent(10),
         }),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -220,6 +231,14 @@ fn test_send() {
     let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
     let expected_event = Event::new("tax")
         .add_attributes(vec![attr("payment", "rates_recipient<10cosmos2contract")]);
+    let expected_andr_event: Event = AndrEvent::RateApplied {
+        rate_type: "additive".to_string(),
+        recipient: "rates_recipient".to_string(),
+        asset: "cw20:cosmos2contract".to_string(),
+        amount: Uint128::new(10),
+        deducted: false,
+    }
+    .into();
 
     assert_eq!(
         Response::new()
@@ -237,6 +256,7 @@ fn test_send() {
                 .unwrap(),
             )
             .add_event(expected_event)
+            .add_event(expected_andr_event)
             .add_submessage(generate_economics_message("sender", "Send")),
         res
     );
@@ -11,18 +11,20 @@ use andromeda_fungible_tokens::lockdrop::{
 use andromeda_std::amp::AndrAddr;
 use andromeda_std::common::expiration::Expiry;
 use andromeda_std::{
-    common::{expiration::MILLISECONDS_TO_NANOSECONDS_RATIO, Milliseconds},
+    common::{expiration::MILLISECONDS_TO_NANOSECONDS_RATIO, Funds, Milliseconds},
     error::ContractError,
     testing::mock_querier::MOCK_KERNEL_CONTRACT,
 };
-use andromeda_testing::economics_msg::generate_economics_message;
+use andromeda_testing::economics_msg::{
+    generate_economics_message, generate_economics_message_with_funds,
+};
 use cosmwasm_std::{
     coin, coins, from_json,
     testing::{mock_env, mock_info},
     to_json_binary, Addr, BankMsg, Decimal, DepsMut, Response, Uint128, WasmMsg,
 };
 
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
 
 const MOCK_INCENTIVE_TOKEN: &str = "mock_incentive_token";
 const DEPOSIT_WINDOW: u64 = 5;
@@ -204,7 +206,14 @@ fn test_increase_incentives() {
         Response::new()
             .add_attribute("action", "incentives_increased")
             .add_attribute("amount", "100")
-            .add_submessage(generate_economics_message(MOCK_INCENTIVE_TOKEN, "Receive")),
+            .add_submessage(generate_economics_message_with_funds(
+                MOCK_INCENTIVE_TOKEN,
+                "Receive",
+                vec![Funds::Cw20(Cw20Coin {
+                    address: MOCK_INCENTIVE_TOKEN.to_string(),
+                    amount: Uint128::new(100),
+                })]
+            )),
         res
     );
 
@@ -302,7 +311,11 @@ fn test_deposit_native() {
             .add_attribute("action", "lock_native")
             .add_attribute("user", "sender")
             .add_attribute("ust_deposited", "100")
-            .add_submessage(generate_economics_message("sender", "DepositNative")),
+            .add_submessage(generate_economics_message_with_funds(
+                "sender",
+                "DepositNative",
+                vec![Funds::Native(coin(100, "uusd"))]
+            )),
         res
     );
 
@@ -420,7 +433,11 @@ fn test_withdraw_native() {
             .add_attribute("action", "withdraw_native")
             .add_attribute("user", "sender")
             .add_attribute("amount", "100")
-            .add_submessage(generate_economics_message("sender", "WithdrawNative")),
+            .add_submessage(generate_economics_message_with_funds(
+                "sender",
+                "WithdrawNative",
+                vec![Funds::Native(coin(100, "uusd"))]
+            )),
         res
     );
 
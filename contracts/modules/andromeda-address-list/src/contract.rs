@@ -2,7 +2,10 @@ use andromeda_modules::address_list::{ActorPermissionResponse, IncludesActorResp
 #[cfg(not(feature = "library"))]
 use andromeda_modules::address_list::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::{
-    ado_base::{permissioning::LocalPermission, InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
+    ado_base::{
+        hooks::AndromedaHook, permissioning::LocalPermission, InstantiateMsg as BaseInstantiateMsg,
+        MigrateMsg,
+    },
     ado_contract::ADOContract,
     amp::AndrAddr,
     common::{context::ExecuteContext, encode_binary},
@@ -70,16 +73,11 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     let _contract = ADOContract::default();
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_not_paused(ctx.deps.storage, &ctx.env, msg.as_ref())?;
     match msg {
         ExecuteMsg::PermissionActors { actors, permission } => {
             execute_permission_actors(ctx, actors, permission)
@@ -165,10 +163,26 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
     match msg {
         QueryMsg::IncludesActor { actor } => encode_binary(&query_actor(deps, actor)?),
         QueryMsg::ActorPermission { actor } => encode_binary(&query_actor_permission(deps, actor)?),
+        QueryMsg::AndrHook(msg) => handle_andr_hook(deps, env, msg),
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
 
+fn handle_andr_hook(deps: Deps, env: Env, msg: AndromedaHook) -> Result<Binary, ContractError> {
+    match msg {
+        AndromedaHook::OnExecute { sender, .. } => {
+            let actor = deps.api.addr_validate(&sender)?;
+            let permission = PERMISSIONS.may_load(deps.storage, &actor)?;
+            ensure!(
+                permission.is_some_and(|permission| permission.is_permissioned(&env, true)),
+                ContractError::Unauthorized {}
+            );
+            encode_binary(&Some::<Response>(Response::default()))
+        }
+        _ => encode_binary(&None::<Response>),
+    }
+}
+
 fn query_actor(deps: Deps, actor: Addr) -> Result<IncludesActorResponse, ContractError> {
     Ok(IncludesActorResponse {
         included: includes_actor(deps.storage, &actor)?,
@@ -5,12 +5,13 @@ use andromeda_modules::address_list::{
     ActorPermission, ActorPermissionResponse, ExecuteMsg, IncludesActorResponse, InstantiateMsg,
     QueryMsg,
 };
+use andromeda_std::ado_base::hooks::AndromedaHook;
 use andromeda_std::ado_base::permissioning::LocalPermission;
 
 use andromeda_std::amp::AndrAddr;
 use andromeda_std::error::ContractError;
 
-use cosmwasm_std::{attr, from_json, Addr, DepsMut, MessageInfo};
+use cosmwasm_std::{attr, from_json, to_json_binary, Addr, DepsMut, MessageInfo};
 use cosmwasm_std::{
     testing::{mock_env, mock_info},
     Response,
@@ -300,3 +301,32 @@ fn test_actor_permission_query() {
     let err = query(deps.as_ref(), mock_env(), msg).unwrap_err();
     assert_eq!(err, ContractError::ActorNotFound {});
 }
+
+#[test]
+fn test_andr_hook_on_execute() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut(), mock_info("creator", &[]));
+
+    let on_execute = |sender: &str| {
+        QueryMsg::AndrHook(AndromedaHook::OnExecute {
+            sender: sender.to_string(),
+            payload: to_json_binary(&"action").unwrap(),
+        })
+    };
+
+    let res: Option<Response> =
+        from_json(query(deps.as_ref(), mock_env(), on_execute("actor")).unwrap()).unwrap();
+    assert_eq!(res, Some(Response::default()));
+
+    let err = query(deps.as_ref(), mock_env(), on_execute("random_actor")).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Unsupported hooks are ignored
+    let msg = QueryMsg::AndrHook(AndromedaHook::OnTokenTransfer {
+        token_id: "1".to_string(),
+        sender: "actor".to_string(),
+        recipient: "random_actor".to_string(),
+    });
+    let res: Option<Response> = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    assert_eq!(res, None);
+}
@@ -3,20 +3,18 @@ use crate::state::RATES;
 use andromeda_modules::rates::{ExecuteMsg, InstantiateMsg, QueryMsg, RateResponse};
 use andromeda_std::{
     ado_base::{
-        rates::{calculate_fee, LocalRate, PaymentAttribute, RatesResponse},
+        hooks::{AndromedaHook, OnFundsTransferResponse},
+        rates::{LocalRate, RatesResponse},
         InstantiateMsg as BaseInstantiateMsg, MigrateMsg,
     },
     ado_contract::ADOContract,
     amp::Recipient,
-    common::{context::ExecuteContext, deduct_funds, encode_binary, Funds},
+    common::{context::ExecuteContext, denom::AssetAmount, encode_binary, Funds},
     error::ContractError,
 };
 
-use cosmwasm_std::{
-    attr, coin, ensure, Binary, Coin, Deps, DepsMut, Env, Event, MessageInfo, Response, SubMsg,
-};
+use cosmwasm_std::{attr, ensure, Binary, Deps, DepsMut, Env, MessageInfo, Response};
 use cosmwasm_std::{entry_point, from_json};
-use cw20::Cw20Coin;
 use cw_utils::nonpayable;
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-rates";
@@ -63,16 +61,11 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_not_paused(ctx.deps.storage, &ctx.env, msg.as_ref())?;
     match msg {
         ExecuteMsg::SetRate { action, rate } => execute_set_rate(ctx, action, rate),
         ExecuteMsg::RemoveRate { action } => execute_remove_rate(ctx, action),
@@ -92,8 +85,8 @@ fn execute_set_rate(
         ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
-    // Validate the local rate's value
-    rate.value.validate()?;
+    // Validate the local rate
+    rate.validate()?;
 
     // Set the sender as the recipient in case no recipients were provided
     if rate.recipients.is_empty() {
@@ -130,10 +123,36 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::Rate { action } => encode_binary(&query_rate(deps, action)?),
+        QueryMsg::AndrHook(msg) => handle_andr_hook(deps, msg),
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
 
+fn handle_andr_hook(deps: Deps, msg: AndromedaHook) -> Result<Binary, ContractError> {
+    match msg {
+        AndromedaHook::OnFundsTransfer {
+            payload, amount, ..
+        } => {
+            // Actions without a rate are left untouched
+            let action: String = from_json(&payload)?;
+            if !RATES.has(deps.storage, &action) {
+                return encode_binary(&None::<OnFundsTransferResponse>);
+            }
+            let RatesResponse {
+                msgs,
+                events,
+                leftover_funds,
+            } = query_deducted_funds(deps, payload, amount)?;
+            encode_binary(&Some(OnFundsTransferResponse {
+                msgs,
+                events,
+                leftover_funds,
+            }))
+        }
+        _ => encode_binary(&None::<OnFundsTransferResponse>),
+    }
+}
+
 fn query_rate(deps: Deps, action: String) -> Result<RateResponse, ContractError> {
     let rate = RATES.may_load(deps.storage, &action)?;
     match rate {
@@ -150,63 +169,13 @@ pub fn query_deducted_funds(
 ) -> Result<RatesResponse, ContractError> {
     let action: String = from_json(payload)?;
     let local_rate = RATES.load(deps.storage, &action)?;
-    let mut msgs: Vec<SubMsg> = vec![];
-    let mut events: Vec<Event> = vec![];
-    let (coin, is_native): (Coin, bool) = match funds {
-        Funds::Native(coin) => (coin, true),
-        Funds::Cw20(cw20_coin) => (coin(cw20_coin.amount.u128(), cw20_coin.address), false),
-    };
-    let mut leftover_funds = vec![coin.clone()];
-
-    let event_name = if local_rate.rate_type.is_additive() {
-        "tax"
-    } else {
-        "royalty"
-    };
-    let mut event = Event::new(event_name);
-    if let Some(desc) = &local_rate.description {
-        event = event.add_attribute("description", desc);
-    }
-    local_rate.value.validate()?;
-    let fee = calculate_fee(local_rate.value, &coin)?;
-    for receiver in local_rate.recipients.iter() {
-        if !local_rate.rate_type.is_additive() {
-            deduct_funds(&mut leftover_funds, &fee)?;
-            event = event.add_attribute("deducted", fee.to_string());
-        }
-        event = event.add_attribute(
-            "payment",
-            PaymentAttribute {
-                receiver: receiver.get_addr(),
-                amount: fee.clone(),
-            }
-            .to_string(),
-        );
-        let msg = if is_native {
-            receiver.generate_direct_msg(&deps, vec![fee.clone()])?
-        } else {
-            receiver.generate_msg_cw20(
-                &deps,
-                Cw20Coin {
-                    amount: fee.amount,
-                    address: fee.denom.to_string(),
-                },
-            )?
-        };
-        msgs.push(msg);
-    }
-    events.push(event);
+    local_rate.validate()?;
+    let (msgs, events, leftover_funds) =
+        local_rate.generate_asset_response(deps, vec![AssetAmount::from(funds)])?;
 
     Ok(RatesResponse {
         msgs,
-        leftover_funds: if is_native {
-            Funds::Native(leftover_funds[0].clone())
-        } else {
-            Funds::Cw20(Cw20Coin {
-                amount: leftover_funds[0].amount,
-                address: coin.denom,
-            })
-        },
+        leftover_funds: leftover_funds[0].clone().into(),
         events,
     })
 }
@@ -1,9 +1,13 @@
 use andromeda_std::{
     ado_base::rates::{calculate_fee, LocalRateValue, PercentRate},
+    amp::AndrAddr,
+    common::denom::{Asset, AssetAmount},
     error::ContractError,
 };
 use cosmwasm_std::{coin, Coin, Decimal};
 
+use crate::testing::mock_querier::mock_dependencies_custom;
+
 struct TestHandleLocalCase {
     name: &'static str,
     fee_rate: LocalRateValue,
@@ -36,6 +40,28 @@ fn test_handle_local() {
             expected_result: coin(100, "uandr"),
             expected_error: None,
         },
+        TestHandleLocalCase {
+            name: "Flat rate in a different asset than the payment",
+            fee_rate: LocalRateValue::FlatAsset(AssetAmount::new(
+                Asset::Cw20Token(AndrAddr::from_string("cw20_contract")),
+                10u128,
+            )),
+            payment: coin(100, "uandr"),
+            expected_result: coin(10, "cw20_contract"),
+            expected_error: Some(ContractError::RateAssetMismatch {
+                rate_asset: "cw20:cw20_contract".to_string(),
+            }),
+        },
+        TestHandleLocalCase {
+            name: "Flat rate in a CW20 asset",
+            fee_rate: LocalRateValue::FlatAsset(AssetAmount::new(
+                Asset::Cw20Token(AndrAddr::from_string("cw20_contract")),
+                10u128,
+            )),
+            payment: coin(100, "cw20_contract"),
+            expected_result: coin(10, "cw20_contract"),
+            expected_error: None,
+        },
         TestHandleLocalCase {
             name: "Percent rate without remainder",
             fee_rate: LocalRateValue::Percent(PercentRate {
@@ -95,8 +121,9 @@ fn test_handle_local() {
         },
     ];
 
+    let deps = mock_dependencies_custom(&[]);
     for test in test_cases {
-        let res = calculate_fee(test.fee_rate, &test.payment);
+        let res = calculate_fee(&deps.as_ref(), test.fee_rate, &test.payment);
         if let Some(err) = test.expected_error {
             assert_eq!(res.unwrap_err(), err, "{}", test.name);
             continue;
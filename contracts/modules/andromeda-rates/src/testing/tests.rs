@@ -4,16 +4,19 @@ use crate::testing::mock_querier::{
 };
 use andromeda_modules::rates::{ExecuteMsg, InstantiateMsg, QueryMsg, RateResponse};
 
-use andromeda_std::ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, RatesResponse};
+use andromeda_std::ado_base::hooks::{AndromedaHook, OnFundsTransferResponse};
+use andromeda_std::ado_base::rates::{
+    LocalRate, LocalRateType, LocalRateValue, PercentRate, RatesResponse,
+};
 use andromeda_std::amp::AndrAddr;
-use andromeda_std::common::Funds;
+use andromeda_std::common::{response::AndrEvent, Funds};
 use andromeda_std::{amp::recipient::Recipient, common::encode_binary};
 
-use cosmwasm_std::{attr, Event};
+use cosmwasm_std::{attr, from_json, Event};
 use cosmwasm_std::{
     coin, coins,
     testing::{mock_env, mock_info},
-    BankMsg, CosmosMsg, Response, SubMsg, WasmMsg,
+    BankMsg, CosmosMsg, Decimal, Response, SubMsg, Uint128, WasmMsg,
 };
 use cw20::{Cw20Coin, Cw20ExecuteMsg};
 
@@ -33,6 +36,7 @@ fn test_instantiate_query() {
         }],
         value: LocalRateValue::Flat(coin(100_u128, "uandr")),
         description: None,
+        weights: None,
     };
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -69,6 +73,7 @@ fn test_andr_receive() {
         }],
         value: LocalRateValue::Flat(coin(100_u128, "uandr")),
         description: None,
+        weights: None,
     };
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -103,6 +108,7 @@ fn test_query_deducted_funds_native() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weights: None,
     };
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -131,6 +137,14 @@ fn test_query_deducted_funds_native() {
             leftover_funds: Funds::Native(coin(100, "uandr")),
             events: vec![
                 Event::new("tax").add_attribute("payment", "recipient1<20uandr"),
+                AndrEvent::RateApplied {
+                    rate_type: "additive".to_string(),
+                    recipient: MOCK_RECIPIENT1.to_string(),
+                    asset: "native:uandr".to_string(),
+                    amount: Uint128::new(20),
+                    deducted: false,
+                }
+                .into(),
                 // Event::new("royalty")
                 //     .add_attribute("description", "desc1")
                 //     .add_attribute("deducted", "10uusd")
@@ -141,6 +155,52 @@ fn test_query_deducted_funds_native() {
     );
 }
 
+#[test]
+fn test_query_deducted_funds_weighted_recipients() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_OWNER, &[]);
+    let action: String = "deposit".to_string();
+    let payload = encode_binary(&action).unwrap();
+    let rate = LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipients: vec![
+            Recipient::from_string(MOCK_RECIPIENT1),
+            Recipient::from_string("recipient2"),
+        ],
+        value: LocalRateValue::Percent(PercentRate {
+            percent: Decimal::percent(10),
+        }),
+        description: None,
+        weights: Some(vec![Uint128::new(3), Uint128::new(1)]),
+    };
+    let msg = InstantiateMsg {
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        action,
+        rate,
+    };
+    let _res = instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+    // A single 10% fee is split 3:1 between the recipients
+    let res =
+        query_deducted_funds(deps.as_ref(), payload, Funds::Native(coin(200, "uandr"))).unwrap();
+    assert_eq!(
+        res.msgs,
+        vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_RECIPIENT1.into(),
+                amount: coins(15, "uandr"),
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "recipient2".into(),
+                amount: coins(5, "uandr"),
+            })),
+        ]
+    );
+    assert_eq!(res.leftover_funds, Funds::Native(coin(180, "uandr")));
+}
+
 #[test]
 fn test_query_deducted_funds_cw20() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -160,6 +220,7 @@ fn test_query_deducted_funds_cw20() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, cw20_address)),
         description: None,
+        weights: None,
     };
 
     // let rates = vec![
@@ -228,6 +289,14 @@ fn test_query_deducted_funds_cw20() {
                 Event::new("tax")
                     // .add_attribute("description", "desc2")
                     .add_attribute("payment", "recipient1<20address"),
+                AndrEvent::RateApplied {
+                    rate_type: "additive".to_string(),
+                    recipient: MOCK_RECIPIENT1.to_string(),
+                    asset: format!("cw20:{cw20_address}"),
+                    amount: Uint128::new(20),
+                    deducted: false,
+                }
+                .into(),
                 // Event::new("royalty")
                 //     .add_attribute("description", "desc1")
                 //     .add_attribute("deducted", "10address")
@@ -237,3 +306,62 @@ fn test_query_deducted_funds_cw20() {
         res
     );
 }
+
+#[test]
+fn test_andr_hook_on_funds_transfer() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_OWNER, &[]);
+    let rate = LocalRate {
+        rate_type: LocalRateType::Additive,
+        recipients: vec![Recipient {
+            address: AndrAddr::from_string("recipient1".to_string()),
+            msg: None,
+            ibc_recovery_address: None,
+        }],
+        value: LocalRateValue::Flat(coin(20_u128, "uandr")),
+        description: None,
+        weights: None,
+    };
+    let msg = InstantiateMsg {
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        action: "deposit".to_string(),
+        rate,
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let hook = |action: &str| -> Option<OnFundsTransferResponse> {
+        let msg = QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
+            sender: "sender".to_string(),
+            payload: encode_binary(&action).unwrap(),
+            amount: Funds::Native(coin(100, "uandr")),
+        });
+        from_json(query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        hook("deposit"),
+        Some(OnFundsTransferResponse {
+            msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_RECIPIENT1.into(),
+                amount: coins(20, "uandr"),
+            }))],
+            events: vec![
+                Event::new("tax").add_attribute("payment", "recipient1<20uandr"),
+                AndrEvent::RateApplied {
+                    rate_type: "additive".to_string(),
+                    recipient: MOCK_RECIPIENT1.to_string(),
+                    asset: "native:uandr".to_string(),
+                    amount: Uint128::new(20),
+                    deducted: false,
+                }
+                .into(),
+            ],
+            leftover_funds: Funds::Native(coin(100, "uandr")),
+        })
+    );
+
+    // Actions without a rate are not handled
+    assert_eq!(hook("withdraw"), None);
+}
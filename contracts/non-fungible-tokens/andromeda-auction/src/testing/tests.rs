@@ -25,7 +25,7 @@ use andromeda_std::{
         encode_binary,
         expiration::{Expiry, MILLISECONDS_TO_NANOSECONDS_RATIO},
         reply::ReplyId,
-        Milliseconds,
+        Funds, Milliseconds,
     },
     error::ContractError,
     os::economics::ExecuteMsg as EconomicsExecuteMsg,
@@ -592,7 +592,8 @@ fn execute_place_bid_multiple_bids() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("sender"),
-                        action: "PlaceBid".to_string()
+                        action: "PlaceBid".to_string(),
+                        funds: Some(vec![Funds::Native(coin(100, "uusd"))]),
                     })
                     .unwrap(),
                     funds: vec![],
@@ -641,7 +642,8 @@ fn execute_place_bid_multiple_bids() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("other"),
-                        action: "PlaceBid".to_string()
+                        action: "PlaceBid".to_string(),
+                        funds: Some(vec![Funds::Native(coin(200, "uusd"))]),
                     })
                     .unwrap(),
                     funds: vec![],
@@ -678,7 +680,8 @@ fn execute_place_bid_multiple_bids() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("sender"),
-                        action: "PlaceBid".to_string()
+                        action: "PlaceBid".to_string(),
+                        funds: Some(vec![Funds::Native(coin(250, "uusd"))]),
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1169,7 +1172,8 @@ fn execute_start_auction_after_previous_finished() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked(MOCK_TOKEN_ADDR),
-                        action: "ReceiveNft".to_string()
+                        action: "ReceiveNft".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1221,7 +1225,8 @@ fn execute_claim_no_bids() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("any_user"),
-                        action: "Claim".to_string()
+                        action: "Claim".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1273,7 +1278,8 @@ fn execute_claim_no_bids_cw20() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("any_user"),
-                        action: "Claim".to_string()
+                        action: "Claim".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1300,6 +1306,7 @@ fn execute_claim_with_tax() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, "uusd")),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -1360,7 +1367,8 @@ fn execute_claim_with_tax() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("any_user"),
-                        action: "Claim".to_string()
+                        action: "Claim".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1436,7 +1444,8 @@ fn execute_buy_now() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("sender_2"),
-                        action: "BuyNow".to_string()
+                        action: "BuyNow".to_string(),
+                        funds: Some(vec![Funds::Native(coin(500, "uusd"))]),
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1477,6 +1486,7 @@ fn execute_claim_with_royalty() {
         }],
         value: LocalRateValue::Flat(coin(20_u128, "uusd")),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -1537,7 +1547,8 @@ fn execute_claim_with_royalty() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("any_user"),
-                        action: "Claim".to_string()
+                        action: "Claim".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1613,7 +1624,8 @@ fn execute_claim_cw20() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("any_user"),
-                        action: "Claim".to_string()
+                        action: "Claim".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1641,6 +1653,7 @@ fn execute_claim_cw20_with_tax() {
             percent: Decimal::percent(20),
         }),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -1716,7 +1729,8 @@ fn execute_claim_cw20_with_tax() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("any_user"),
-                        action: "Claim".to_string()
+                        action: "Claim".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1828,7 +1842,8 @@ fn execute_cancel_no_bids() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("owner"),
-                        action: "CancelAuction".to_string()
+                        action: "CancelAuction".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1879,7 +1894,8 @@ fn execute_cancel_no_bids_cw20() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("owner"),
-                        action: "CancelAuction".to_string()
+                        action: "CancelAuction".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1944,7 +1960,8 @@ fn execute_cancel_with_bids() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("owner"),
-                        action: "CancelAuction".to_string()
+                        action: "CancelAuction".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -2024,7 +2041,8 @@ fn execute_cancel_with_bids_cw20() {
                     contract_addr: "economics_contract".to_string(),
                     msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                         payee: Addr::unchecked("owner"),
-                        action: "CancelAuction".to_string()
+                        action: "CancelAuction".to_string(),
+                        funds: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -8,7 +8,7 @@ use andromeda_non_fungible_tokens::cw721::ExecuteMsg as Cw721ExecuteMsg;
 use andromeda_std::ado_base::permissioning::{LocalPermission, Permission};
 use andromeda_std::amp::messages::AMPPkt;
 use andromeda_std::amp::{AndrAddr, Recipient};
-use andromeda_std::common::actions::call_action;
+use andromeda_std::common::actions::call_msg_action;
 use andromeda_std::common::denom::{Asset, SEND_CW20_ACTION};
 use andromeda_std::common::migration::ensure_compatibility;
 use andromeda_std::common::{Milliseconds, MillisecondsExpiration, OrderBy};
@@ -107,23 +107,11 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
-    let action_response = call_action(
-        &mut ctx.deps,
-        &ctx.info,
-        &ctx.env,
-        &ctx.amp_ctx,
-        msg.as_ref(),
-    )?;
+    let action_response = call_msg_action(&mut ctx, &msg)?;
 
     let res = match msg {
         ExecuteMsg::AddTier { tier } => execute_add_tier(ctx, tier),
@@ -99,11 +99,11 @@ mod test {
     };
     use andromeda_std::{
         amp::{messages::AMPPkt, AndrAddr, Recipient},
-        common::{denom::Asset, encode_binary},
+        common::{denom::Asset, encode_binary, Funds},
         testing::mock_querier::MOCK_CW20_CONTRACT,
     };
     use cosmwasm_std::{coin, coins, testing::MOCK_CONTRACT_ADDR, wasm_execute, BankMsg, Coin};
-    use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
 
     use crate::{
         state::{get_current_capital, set_current_stage, set_tier_orders, TIER_SALES},
@@ -258,6 +258,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER),
                                 action: "AddTier".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -364,6 +365,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER),
                                 action: "UpdateTier".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -466,6 +468,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER),
                                 action: "RemoveTier".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -574,6 +577,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER),
                                 action: "StartCampaign".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -732,6 +736,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(buyer),
                                 action: "PurchaseTiers".to_string(),
+                                funds: Some(vec![Funds::Native(coin(1000, MOCK_NATIVE_DENOM))]),
                             })
                             .unwrap(),
                             funds: vec![],
@@ -938,6 +943,10 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_CW20_CONTRACT),
                                 action: "Receive".to_string(),
+                                funds: Some(vec![Funds::Cw20(Cw20Coin {
+                                    address: MOCK_CW20_CONTRACT.to_string(),
+                                    amount: Uint128::new(1000u128),
+                                })]),
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1161,6 +1170,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER.to_string()),
                                 action: "EndCampaign".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1198,6 +1208,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER.to_string()),
                                 action: "EndCampaign".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1224,6 +1235,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER.to_string()),
                                 action: "EndCampaign".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1250,6 +1262,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER.to_string()),
                                 action: "DiscardCampaign".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1276,6 +1289,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: Addr::unchecked(MOCK_DEFAULT_OWNER.to_string()),
                                 action: "EndCampaign".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1427,6 +1441,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: orderer.clone(),
                                 action: "Claim".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1464,6 +1479,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: orderer.clone(),
                                 action: "Claim".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -1508,6 +1524,7 @@ mod test {
                             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                                 payee: orderer.clone(),
                                 action: "Claim".to_string(),
+                                funds: None,
                             })
                             .unwrap(),
                             funds: vec![],
@@ -11,7 +11,7 @@ use andromeda_non_fungible_tokens::cw721::{
 };
 use andromeda_std::common::rates::get_tax_amount;
 use andromeda_std::{
-    ado_base::{AndromedaMsg, AndromedaQuery},
+    ado_base::{hooks::AndromedaHook, AndromedaMsg, AndromedaQuery},
     ado_contract::{permissioning::is_context_permissioned_strict, ADOContract},
     amp::AndrAddr,
     common::{actions::call_action, context::ExecuteContext},
@@ -74,16 +74,8 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    if let ExecuteMsg::AMPReceive(pkt) = msg {
-        ADOContract::default().execute_amp_receive(
-            ExecuteContext::new(deps, info, env),
-            pkt,
-            handle_execute,
-        )
-    } else {
-        let ctx = ExecuteContext::new(deps, info, env);
-        handle_execute(ctx, msg)
-    }
+    let ctx = ExecuteContext::new(deps, info, env);
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -311,6 +303,17 @@ fn execute_transfer(
         Uint128::zero()
     };
 
+    let hook_resp = token_transfer_hook(
+        deps.as_ref(),
+        &token_id,
+        info.sender.as_str(),
+        &recipient_address,
+    )?;
+    resp = resp
+        .add_submessages(hook_resp.messages)
+        .add_attributes(hook_resp.attributes)
+        .add_events(hook_resp.events);
+
     check_can_send(deps.as_ref(), env, info, &token_id, &token, tax_amount)?;
     token.owner = deps.api.addr_validate(&recipient_address)?;
     token.approvals.clear();
@@ -468,8 +471,43 @@ fn execute_send_nft(
     let contract = AndrCW721Contract::default();
     TRANSFER_AGREEMENTS.remove(deps.storage, &token_id);
     let contract_addr = contract_addr.get_raw_address(&deps.as_ref())?.into_string();
+    let hook_resp = token_transfer_hook(
+        deps.as_ref(),
+        &token_id,
+        info.sender.as_str(),
+        &contract_addr,
+    )?;
 
-    Ok(contract.send_nft(deps, env, info, contract_addr, token_id, msg)?)
+    let resp = contract.send_nft(deps, env, info, contract_addr, token_id, msg)?;
+    Ok(resp
+        .add_submessages(hook_resp.messages)
+        .add_attributes(hook_resp.attributes)
+        .add_events(hook_resp.events))
+}
+
+/// Sends an `OnTokenTransfer` hook to all registered modules, merging their responses.
+/// Modules may reject the transfer by erroring.
+fn token_transfer_hook(
+    deps: Deps,
+    token_id: &str,
+    sender: &str,
+    recipient: &str,
+) -> Result<Response, ContractError> {
+    let responses = ADOContract::default().module_hook::<Response>(
+        &deps,
+        AndromedaHook::OnTokenTransfer {
+            token_id: token_id.to_string(),
+            sender: sender.to_string(),
+            recipient: recipient.to_string(),
+        },
+    )?;
+    Ok(responses
+        .into_iter()
+        .fold(Response::default(), |acc, resp| {
+            acc.add_submessages(resp.messages)
+                .add_attributes(resp.attributes)
+                .add_events(resp.events)
+        }))
 }
 
 #[cfg_attr(not(feature = "imported"), entry_point)]
@@ -295,6 +295,7 @@ fn test_burn() {
             &deps.as_ref().querier,
             "Burn".to_string(),
             Addr::unchecked("creator".to_string()),
+            &[],
         )
         .unwrap();
 
@@ -432,6 +433,7 @@ fn test_transfer_agreement() {
 //         }],
 //         value: LocalRateValue::Flat(coin(10_u128, "uusd")),
 //         description: None,
+//         weights: None,
 //     });
 
 //     // Set rates
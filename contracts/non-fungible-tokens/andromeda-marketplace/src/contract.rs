@@ -15,7 +15,7 @@ use andromeda_std::{
     ado_contract::ADOContract,
     amp::Recipient,
     common::{
-        actions::call_action,
+        actions::call_msg_action,
         context::ExecuteContext,
         denom::{Asset, SEND_CW20_ACTION, SEND_NFT_ACTION},
         encode_binary,
@@ -101,25 +101,13 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let ctx = ExecuteContext::new(deps, info, env);
-
-    match msg {
-        ExecuteMsg::AMPReceive(pkt) => {
-            ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
-        }
-        _ => handle_execute(ctx, msg),
-    }
+    ADOContract::default().execute_entry(ctx, msg, handle_execute)
 }
 
 pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
     let action = msg.as_ref().to_string();
 
-    let action_response = call_action(
-        &mut ctx.deps,
-        &ctx.info,
-        &ctx.env,
-        &ctx.amp_ctx,
-        msg.as_ref(),
-    )?;
+    let action_response = call_msg_action(&mut ctx, &msg)?;
     let res = match msg {
         ExecuteMsg::ReceiveNft(msg) => handle_receive_cw721(ctx, msg),
         ExecuteMsg::Receive(msg) => handle_receive_cw20(ctx, msg),
@@ -436,10 +424,20 @@ fn execute_buy(
         token_sale_state.clone(),
         action.clone(),
     )?;
+    // Pass the seller's proceeds through any registered modules
+    let (module_msgs, module_events, after_tax_payment) = ADOContract::default()
+        .on_funds_transfer(
+            &deps.as_ref(),
+            info.sender.to_string(),
+            after_tax_payment,
+            encode_binary(&action)?,
+        )?;
 
     let mut resp = Response::new()
         // Send tax/royalty messages
         .add_submessages(tax_messages)
+        .add_submessages(module_msgs)
+        .add_events(module_events)
         // Send NFT to buyer.
         .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: token_sale_state.token_address.clone(),
@@ -572,12 +570,22 @@ fn execute_buy_cw20(
         &info,
         Some(amount_sent),
         token_sale_state.clone(),
-        action,
+        action.clone(),
     )?;
+    // Pass the seller's proceeds through any registered modules
+    let (module_msgs, module_events, after_tax_payment) = ADOContract::default()
+        .on_funds_transfer(
+            &deps.as_ref(),
+            sender.to_string(),
+            after_tax_payment,
+            encode_binary(&action)?,
+        )?;
 
     let mut resp: Response = Response::new()
         // Send tax/royalty messages
         .add_submessages(tax_messages)
+        .add_submessages(module_msgs)
+        .add_events(module_events)
         // Send NFT to buyer.
         .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: token_sale_state.token_address.clone(),
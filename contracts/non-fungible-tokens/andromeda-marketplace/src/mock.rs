@@ -100,7 +100,7 @@ impl MockMarketplace {
         )
     }
 
-    pub fn query_rates(&self, app: &mut MockApp, action: String) -> Option<Rate> {
+    pub fn query_rates(&self, app: &mut MockApp, action: String) -> Option<Vec<Rate>> {
         let msg = mock_get_rates(action);
         self.query(app, msg)
     }
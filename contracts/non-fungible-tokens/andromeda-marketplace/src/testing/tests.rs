@@ -12,7 +12,7 @@ use andromeda_std::{
         encode_binary,
         expiration::{expiration_from_milliseconds, Expiry, MILLISECONDS_TO_NANOSECONDS_RATIO},
         reply::ReplyId,
-        Milliseconds,
+        Funds, Milliseconds,
     },
     error::ContractError,
     os::economics::ExecuteMsg as EconomicsExecuteMsg,
@@ -745,6 +745,7 @@ fn test_execute_buy_with_tax_and_royalty_insufficient_funds() {
             percent: Decimal::percent(50),
         }),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -801,6 +802,7 @@ fn test_execute_buy_with_tax_and_royalty_insufficient_funds_cw20() {
             percent: Decimal::percent(50),
         }),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -878,6 +880,7 @@ fn test_execute_buy_with_tax_and_royalty_works() {
             percent: Decimal::percent(50),
         }),
         description: None,
+        weights: None,
     });
 
     // Set rates
@@ -924,6 +927,7 @@ fn test_execute_buy_with_tax_and_royalty_works() {
                 msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                     payee: Addr::unchecked("someone"),
                     action: "Buy".to_string(),
+                    funds: Some(vec![Funds::Native(coin(150, "uusd"))]),
                 })
                 .unwrap(),
                 funds: vec![],
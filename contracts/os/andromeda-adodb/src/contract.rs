@@ -1,6 +1,7 @@
 use crate::{execute, query};
 use andromeda_std::ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg};
 use andromeda_std::ado_contract::ADOContract;
+use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::common::encode_binary;
 use andromeda_std::error::ContractError;
 use andromeda_std::os::adodb::{ADOVersion, ExecuteMsg, InstantiateMsg, QueryMsg};
@@ -52,6 +53,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_not_paused(deps.storage, &env, msg.as_ref())?;
     match msg {
         ExecuteMsg::Publish {
             code_id,
@@ -85,10 +87,29 @@ pub fn execute(
             ado_type,
             publisher,
         } => execute::update_publisher(deps, info, &ADOVersion::from_string(ado_type), publisher),
+        ExecuteMsg::Deprecate {
+            ado_type,
+            version,
+            reason,
+        } => execute::deprecate(deps, info, ado_type, version, reason),
+        ExecuteMsg::PublishTemplate {
+            name,
+            version,
+            components,
+            params_schema,
+        } => execute::publish_template(deps, info, name, version, components, params_schema),
         // Base message
         ExecuteMsg::Ownership(ownership_message) => {
             ADOContract::default().execute_ownership(deps, env, info, ownership_message)
         }
+        ExecuteMsg::Pause { actions, until } => ADOContract::default().execute_pause(
+            ExecuteContext::new(deps, info, env),
+            actions,
+            until,
+        ),
+        ExecuteMsg::Unpause {} => {
+            ADOContract::default().execute_unpause(ExecuteContext::new(deps, info, env))
+        }
     }
 }
 
@@ -98,7 +119,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::CodeId { key } => encode_binary(&query::code_id(deps, key)?),
         // QueryMsg::UnpublishedCodeIds {} => encode_binary(&query::unpublished_code_ids(deps)?),
@@ -130,9 +151,15 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             encode_binary(&query::action_fee_by_code_id(deps, code_id, action)?)
         }
         // Base queries
+        QueryMsg::Template { name, version } => {
+            encode_binary(&query::template(deps, name, version)?)
+        }
         QueryMsg::Version {} => encode_binary(&ADOContract::default().query_version(deps)?),
         QueryMsg::Type {} => encode_binary(&ADOContract::default().query_type(deps)?),
         QueryMsg::Owner {} => encode_binary(&ADOContract::default().query_contract_owner(deps)?),
+        QueryMsg::PauseStatus {} => {
+            encode_binary(&ADOContract::default().query_pause_status(deps, env)?)
+        }
         QueryMsg::KernelAddress {} => {
             encode_binary(&ADOContract::default().query_kernel_address(deps)?)
         }
@@ -1,12 +1,15 @@
 use crate::state::{
     read_code_id, remove_code_id, save_action_fees, store_code_id, ACTION_FEES, ADO_TYPE,
-    LATEST_VERSION, PUBLISHER, UNPUBLISHED_CODE_IDS, UNPUBLISHED_VERSIONS,
+    DEPRECATED_VERSIONS, LATEST_TEMPLATE_VERSION, LATEST_VERSION, PUBLISHER, TEMPLATES,
+    UNPUBLISHED_CODE_IDS, UNPUBLISHED_VERSIONS,
 };
 
 use andromeda_std::ado_contract::ADOContract;
 
 use andromeda_std::error::ContractError;
-use andromeda_std::os::adodb::{ADOVersion, ActionFee};
+use andromeda_std::os::adodb::{
+    ADOVersion, ActionFee, AppTemplate, DeprecationInfo, TemplateComponent, TemplateParam,
+};
 use cosmwasm_std::{attr, ensure, DepsMut, Env, MessageInfo, Response};
 
 #[allow(clippy::too_many_arguments)]
@@ -153,6 +156,8 @@ pub fn unpublish(
     // Remove publisher for this version
     PUBLISHER.remove(deps.storage, ado_version.as_str());
 
+    DEPRECATED_VERSIONS.remove(deps.storage, ado_version.as_str());
+
     // Add the unpublished code id to the list
     UNPUBLISHED_CODE_IDS.save(deps.storage, code_id, &true)?;
 
@@ -168,6 +173,123 @@ pub fn unpublish(
     ]))
 }
 
+pub fn deprecate(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    version: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure!(
+        semver::Version::parse(&version).is_ok(),
+        ContractError::InvalidADOVersion {
+            msg: Some("Provided version is not valid semver".to_string())
+        }
+    );
+
+    let ado_version = ADOVersion::from_type(ado_type).with_version(version);
+    let code_id =
+        read_code_id(deps.storage, &ado_version)
+            .ok()
+            .ok_or(ContractError::InvalidADOVersion {
+                msg: Some("Version not already published".to_string()),
+            })?;
+    ensure!(
+        !DEPRECATED_VERSIONS.has(deps.storage, ado_version.as_str()),
+        ContractError::InvalidADOVersion {
+            msg: Some("Version already deprecated".to_string()),
+        }
+    );
+
+    DEPRECATED_VERSIONS.save(
+        deps.storage,
+        ado_version.as_str(),
+        &DeprecationInfo {
+            reason: reason.clone(),
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "deprecate_ado"),
+        attr("ado_type", ado_version.into_string()),
+        attr("code_id", code_id.to_string()),
+        attr("reason", reason.unwrap_or_default()),
+    ]))
+}
+
+pub fn publish_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    version: String,
+    components: Vec<TemplateComponent>,
+    params_schema: Vec<TemplateParam>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure!(
+        !name.is_empty() && !name.contains('@'),
+        ContractError::InvalidTemplate {
+            msg: "Invalid template name".to_string()
+        }
+    );
+    let parsed_version =
+        semver::Version::parse(&version)
+            .ok()
+            .ok_or(ContractError::InvalidADOVersion {
+                msg: Some("Provided version is not valid semver".to_string()),
+            })?;
+    ensure!(
+        !TEMPLATES.has(deps.storage, (&name, &version)),
+        ContractError::InvalidTemplate {
+            msg: format!("Template {name}@{version} already published")
+        }
+    );
+
+    let template = AppTemplate {
+        name: name.clone(),
+        version: version.clone(),
+        components,
+        params_schema,
+        publisher: info.sender.to_string(),
+    };
+    template.validate()?;
+    for component in template.components.iter() {
+        let ado_version = ADOVersion::from_string(&component.ado_type);
+        ensure!(
+            read_code_id(deps.storage, &ado_version).is_ok(),
+            ContractError::InvalidTemplate {
+                msg: format!("ADO type {} is not published", component.ado_type)
+            }
+        );
+    }
+    TEMPLATES.save(deps.storage, (&name, &version), &template)?;
+
+    let latest_version = LATEST_TEMPLATE_VERSION.may_load(deps.storage, &name)?;
+    let is_latest = match latest_version {
+        Some(latest_version) => semver::Version::parse(&latest_version)
+            .map(|latest_version| latest_version < parsed_version)
+            .unwrap_or(true),
+        None => true,
+    };
+    if is_latest && parsed_version.pre.is_empty() {
+        LATEST_TEMPLATE_VERSION.save(deps.storage, &name, &version)?;
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "publish_template"),
+        attr("name", name),
+        attr("version", version),
+        attr("publisher", info.sender),
+    ]))
+}
+
 pub fn update_action_fees(
     deps: DepsMut,
     info: MessageInfo,
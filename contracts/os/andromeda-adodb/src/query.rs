@@ -1,10 +1,13 @@
 use crate::state::{
-    read_code_id, read_latest_code_id, ACTION_FEES, ADO_TYPE, CODE_ID, PUBLISHER,
-    UNPUBLISHED_CODE_IDS,
+    read_code_id, read_latest_code_id, ACTION_FEES, ADO_TYPE, CODE_ID, DEPRECATED_VERSIONS,
+    LATEST_TEMPLATE_VERSION, PUBLISHER, TEMPLATES, UNPUBLISHED_CODE_IDS,
 };
 
 use andromeda_std::error::ContractError;
-use andromeda_std::os::adodb::{ADOMetadata, ADOVersion, ActionFee, IsUnpublishedCodeIdResponse};
+use andromeda_std::os::adodb::{
+    ADOMetadata, ADOVersion, ADOVersionResponse, ActionFee, AppTemplate,
+    IsUnpublishedCodeIdResponse,
+};
 use cosmwasm_std::{Deps, Order, StdResult, Storage};
 
 use cw_storage_plus::Bound;
@@ -63,7 +66,26 @@ pub fn ado_versions(
     ado_type: &str,
     start_after: Option<String>,
     limit: Option<u32>,
-) -> Result<Vec<String>, ContractError> {
+) -> Result<Vec<ADOVersionResponse>, ContractError> {
+    read_ado_versions(storage, ado_type, start_after, limit)
+        .into_iter()
+        .map(|(version, code_id)| {
+            Ok(ADOVersionResponse {
+                deprecated: DEPRECATED_VERSIONS.may_load(storage, &version)?,
+                version,
+                code_id,
+            })
+        })
+        .collect()
+}
+
+/// Reads the published `(ado_type@version, code_id)` pairs for an ADO type, sorted by version descending
+fn read_ado_versions(
+    storage: &dyn Storage,
+    ado_type: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Vec<(String, u64)> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start_after = start_after.unwrap_or(ado_type.to_string());
     let start = Some(Bound::exclusive(start_after.as_str()));
@@ -72,17 +94,17 @@ pub fn ado_versions(
     let end_ado_type = format!("{ado_type}A");
     let end = Some(Bound::exclusive(end_ado_type.as_str()));
 
-    let mut versions: Vec<String> = CODE_ID
-        .keys(storage, start, end, Order::Ascending)
+    let mut versions: Vec<(String, u64)> = CODE_ID
+        .range(storage, start, end, Order::Ascending)
         .take(limit)
         .map(|item| item.unwrap())
         .collect();
-    versions.sort_by(|a, b| {
+    versions.sort_by(|(a, _), (b, _)| {
         let version_a: Version = ADOVersion::from_string(a).get_version().parse().unwrap();
         let version_b: Version = ADOVersion::from_string(b).get_version().parse().unwrap();
         version_b.cmp(&version_a)
     });
-    Ok(versions)
+    versions
 }
 
 // pub fn unpublished_ado_versions(
@@ -127,3 +149,15 @@ pub fn action_fee_by_code_id(
     let ado_version = ADO_TYPE.load(deps.storage, &code_id.to_string())?;
     Ok(ACTION_FEES.may_load(deps.storage, &(ado_version.get_type(), action))?)
 }
+
+pub fn template(
+    deps: Deps,
+    name: String,
+    version: Option<String>,
+) -> Result<AppTemplate, ContractError> {
+    let version = match version {
+        Some(version) => version,
+        None => LATEST_TEMPLATE_VERSION.load(deps.storage, &name)?,
+    };
+    Ok(TEMPLATES.load(deps.storage, (&name, &version))?)
+}
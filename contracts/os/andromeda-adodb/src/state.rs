@@ -1,9 +1,10 @@
 use andromeda_std::{
     error::ContractError,
-    os::adodb::{ADOVersion, ActionFee},
+    os::adodb::{ADOVersion, ActionFee, AppTemplate, DeprecationInfo},
 };
-use cosmwasm_std::{ensure, Api, StdResult, Storage};
-use cw_storage_plus::Map;
+use cosmwasm_std::{ensure, Api, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use semver::{Version, VersionReq};
 
 /// Stores a mapping from an ADO type/version to its code ID
 pub const CODE_ID: Map<&str, u64> = Map::new("code_id");
@@ -19,6 +20,12 @@ pub const ADO_TYPE: Map<&str, ADOVersion> = Map::new("ado_type");
 pub const PUBLISHER: Map<&str, String> = Map::new("publisher");
 /// Stores a mapping from an (ADO,Action) to its action fees
 pub const ACTION_FEES: Map<&(String, String), ActionFee> = Map::new("action_fees");
+/// Stores deprecated `ado_type@version` keys, deprecated versions remain resolvable but are flagged
+pub const DEPRECATED_VERSIONS: Map<&str, DeprecationInfo> = Map::new("deprecated_versions");
+/// Stores app templates by (name, version)
+pub const TEMPLATES: Map<(&str, &str), AppTemplate> = Map::new("templates");
+/// Stores the latest published version of each app template
+pub const LATEST_TEMPLATE_VERSION: Map<&str, String> = Map::new("latest_template_version");
 
 pub fn store_code_id(
     storage: &mut dyn Storage,
@@ -113,12 +120,59 @@ pub fn remove_code_id(
 }
 
 pub fn read_code_id(storage: &dyn Storage, ado_version: &ADOVersion) -> StdResult<u64> {
-    if ado_version.get_version() == "latest" {
+    let version = ado_version.get_version();
+    if version == "latest" {
         let (_version, code_id) = read_latest_code_id(storage, ado_version.get_type())?;
-        Ok(code_id)
-    } else {
-        CODE_ID.load(storage, ado_version.as_str())
+        return Ok(code_id);
     }
+    // Exact versions are also valid requirements (`1.0.0` is `^1.0.0`) so they are checked first
+    if Version::parse(&version).is_err() {
+        if let Ok(req) = VersionReq::parse(&version) {
+            return read_matching_code_id(storage, &ado_version.get_type(), &req);
+        }
+    }
+    CODE_ID.load(storage, ado_version.as_str())
+}
+
+/// Gets the published versions for a given ADO type along with their code IDs
+pub fn read_versions(storage: &dyn Storage, ado_type: &str) -> StdResult<Vec<(Version, u64)>> {
+    let prefix = format!("{ado_type}@");
+    let start = Bound::exclusive(prefix.as_str());
+    // '@' is followed by 'A' in ASCII, bounding the range to keys for this ADO type
+    let end_ado_type = format!("{ado_type}A");
+    let end = Bound::exclusive(end_ado_type.as_str());
+    let mut versions = vec![];
+    for item in CODE_ID.range(storage, Some(start), Some(end), Order::Ascending) {
+        let (key, code_id) = item?;
+        if let Some(version) = key
+            .strip_prefix(&prefix)
+            .and_then(|v| Version::parse(v).ok())
+        {
+            versions.push((version, code_id));
+        }
+    }
+    Ok(versions)
+}
+
+/// Resolves the highest published version matching the given requirement.
+///
+/// Deprecated versions are only resolved if no other version matches.
+pub fn read_matching_code_id(
+    storage: &dyn Storage,
+    ado_type: &str,
+    req: &VersionReq,
+) -> StdResult<u64> {
+    let mut matching: Vec<(Version, u64)> = read_versions(storage, ado_type)?
+        .into_iter()
+        .filter(|(version, _)| req.matches(version))
+        .collect();
+    matching.sort_by(|a, b| b.0.cmp(&a.0));
+    let code_id = matching
+        .iter()
+        .find(|(version, _)| !DEPRECATED_VERSIONS.has(storage, &format!("{ado_type}@{version}")))
+        .or(matching.first())
+        .map(|(_, code_id)| *code_id);
+    code_id.ok_or_else(|| StdError::not_found(format!("{ado_type}@{req}")))
 }
 
 pub fn read_latest_code_id(storage: &dyn Storage, ado_type: String) -> StdResult<(String, u64)> {
@@ -1,12 +1,17 @@
 #[cfg(test)]
 use andromeda_std::testing::mock_querier::{mock_dependencies_custom, MOCK_KERNEL_CONTRACT};
-use cosmwasm_std::{from_json, Uint128};
+use cosmwasm_std::{from_json, Decimal, Deps, Uint128};
 
 use crate::contract::{execute, instantiate, query};
-use crate::state::{ACTION_FEES, CODE_ID, LATEST_VERSION, PUBLISHER, UNPUBLISHED_CODE_IDS};
+use crate::state::{
+    ACTION_FEES, CODE_ID, DEPRECATED_VERSIONS, LATEST_VERSION, PUBLISHER, UNPUBLISHED_CODE_IDS,
+};
 
 use andromeda_std::error::ContractError;
-use andromeda_std::os::adodb::{ADOVersion, ActionFee, ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_std::os::adodb::{
+    ADOVersion, ADOVersionResponse, ActionFee, AppTemplate, DeprecationInfo, ExecuteMsg, FeeAsset,
+    InstantiateMsg, QueryMsg, TemplateComponent, TemplateParam,
+};
 
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
@@ -48,12 +53,16 @@ fn test_publish() {
             amount: Uint128::from(1u128),
             asset: "cw20:somecw20token".to_string(),
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         },
         ActionFee {
             action: "action2".to_string(),
             amount: Uint128::from(2u128),
             asset: "native:uusd".to_string(),
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         },
     ];
 
@@ -210,12 +219,16 @@ fn test_unpublish() {
             amount: Uint128::from(1u128),
             asset: "cw20:somecw20token".to_string(),
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         },
         ActionFee {
             action: "action2".to_string(),
             amount: Uint128::from(2u128),
             asset: "native:uusd".to_string(),
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         },
     ];
     let ado_version = ADOVersion::from_type("ado_type").with_version("0.1.0");
@@ -489,12 +502,16 @@ fn test_update_action_fees() {
             amount: Uint128::from(1u128),
             asset: "cw20:somecw20token".to_string(),
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         },
         ActionFee {
             action: "action2".to_string(),
             amount: Uint128::from(2u128),
             asset: "native:uusd".to_string(),
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         },
     ];
 
@@ -515,7 +532,7 @@ fn test_update_action_fees() {
         .save(deps.as_mut().storage, ado_version.as_str(), &code_id)
         .unwrap();
 
-    let res = execute(deps.as_mut(), env.clone(), info, msg.clone());
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
     assert!(res.is_ok());
 
     // TEST ACTION FEE
@@ -529,6 +546,57 @@ fn test_update_action_fees() {
         assert_eq!(fee, action_fee);
     }
 
+    // Alternative assets and percentages are validated
+    let action_fee = ActionFee::new(
+        "action3".to_string(),
+        "native:uusd".to_string(),
+        Uint128::from(2u128),
+    );
+    let invalid_alternative = ExecuteMsg::UpdateActionFees {
+        action_fees: vec![action_fee
+            .with_alternative_assets(vec![FeeAsset::new("invalid", Uint128::from(1u128))])],
+        ado_type: ado_version.clone().into_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        invalid_alternative,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidAsset {
+            asset: "invalid".to_string()
+        }
+    );
+
+    let invalid_percentage = ExecuteMsg::UpdateActionFees {
+        action_fees: vec![action_fee.with_percentage(Decimal::percent(101))],
+        ado_type: ado_version.clone().into_string(),
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), invalid_percentage).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+
+    let multi_asset_fee = action_fee
+        .with_alternative_assets(vec![FeeAsset::new(
+            "cw20:somecw20token",
+            Uint128::from(3u128),
+        )])
+        .with_percentage(Decimal::percent(5));
+    let msg = ExecuteMsg::UpdateActionFees {
+        action_fees: vec![multi_asset_fee.clone()],
+        ado_type: ado_version.clone().into_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+    let fee = ACTION_FEES
+        .load(
+            deps.as_ref().storage,
+            &(ado_version.get_type(), "action3".to_string()),
+        )
+        .unwrap();
+    assert_eq!(fee, multi_asset_fee);
+
     // Test unauthorised
     let unauth_info = mock_info("not_owner", &[]);
     let resp = execute(deps.as_mut(), env, unauth_info, msg);
@@ -749,3 +817,225 @@ fn test_all_ado_types() {
     ];
     assert_eq!(value, expected);
 }
+
+fn query_code_id(deps: Deps, key: &str) -> Option<u64> {
+    query(
+        deps,
+        mock_env(),
+        QueryMsg::CodeId {
+            key: key.to_string(),
+        },
+    )
+    .ok()
+    .map(|res| from_json(res).unwrap())
+}
+
+#[test]
+fn test_deprecate_and_version_requirements() {
+    let owner = String::from("owner");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    for (code_id, version) in [(1, "2.0.0"), (2, "2.1.0"), (3, "2.2.0"), (4, "3.0.0")] {
+        let msg = ExecuteMsg::Publish {
+            ado_type: "splitter".to_string(),
+            version: version.to_string(),
+            code_id,
+            action_fees: None,
+            publisher: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    assert_eq!(query_code_id(deps.as_ref(), "splitter@^2.1"), Some(3));
+    assert_eq!(query_code_id(deps.as_ref(), "splitter@2.1.0"), Some(2));
+    assert_eq!(
+        query_code_id(deps.as_ref(), "splitter@>=2.0.0, <2.2.0"),
+        Some(2)
+    );
+    assert_eq!(query_code_id(deps.as_ref(), "splitter@^4"), None);
+
+    let msg = ExecuteMsg::Deprecate {
+        ado_type: "splitter".to_string(),
+        version: "2.2.0".to_string(),
+        reason: Some("Vulnerability".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_owner", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+    assert_eq!(
+        DEPRECATED_VERSIONS
+            .load(deps.as_ref().storage, "splitter@2.2.0")
+            .unwrap(),
+        DeprecationInfo {
+            reason: Some("Vulnerability".to_string())
+        }
+    );
+
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidADOVersion {
+            msg: Some("Version already deprecated".to_string())
+        }
+    );
+
+    // Requirements skip deprecated versions unless they are the only match
+    assert_eq!(query_code_id(deps.as_ref(), "splitter@^2.1"), Some(2));
+    assert_eq!(query_code_id(deps.as_ref(), "splitter@~2.2"), Some(3));
+    // Deprecated versions remain resolvable
+    assert_eq!(query_code_id(deps.as_ref(), "splitter@2.2.0"), Some(3));
+
+    let query_msg = QueryMsg::ADOVersions {
+        ado_type: "splitter".to_string(),
+        start_after: None,
+        limit: None,
+    };
+    let res = query(deps.as_ref(), env, query_msg).unwrap();
+    let value: Vec<ADOVersionResponse> = from_json(res).unwrap();
+    assert_eq!(value.len(), 4);
+    assert_eq!(
+        value[0],
+        ADOVersionResponse {
+            version: "splitter@3.0.0".to_string(),
+            code_id: 4,
+            deprecated: None,
+        }
+    );
+    assert_eq!(
+        value[1],
+        ADOVersionResponse {
+            version: "splitter@2.2.0".to_string(),
+            code_id: 3,
+            deprecated: Some(DeprecationInfo {
+                reason: Some("Vulnerability".to_string())
+            }),
+        }
+    );
+}
+
+#[test]
+fn test_publish_template() {
+    let owner = String::from("owner");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let components = vec![TemplateComponent {
+        name: "token".to_string(),
+        ado_type: "cw721".to_string(),
+        instantiate_msg: r#"{"name":"{{name}}","minter":"{{owner}}"}"#.to_string(),
+    }];
+    let params_schema = vec![TemplateParam {
+        name: "name".to_string(),
+        description: Some("The token name".to_string()),
+        default: None,
+    }];
+    let msg = ExecuteMsg::PublishTemplate {
+        name: "collection".to_string(),
+        version: "1.0.0".to_string(),
+        components: components.clone(),
+        params_schema: params_schema.clone(),
+    };
+
+    // Component ADO types must be published
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidTemplate {
+            msg: "ADO type cw721 is not published".to_string()
+        }
+    );
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::Publish {
+            ado_type: "cw721".to_string(),
+            version: "1.0.0".to_string(),
+            code_id: 1,
+            action_fees: None,
+            publisher: None,
+        },
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_owner", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidTemplate {
+            msg: "Template collection@1.0.0 already published".to_string()
+        }
+    );
+
+    let msg = ExecuteMsg::PublishTemplate {
+        name: "collection".to_string(),
+        version: "1.1.0".to_string(),
+        components: components.clone(),
+        params_schema: vec![],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidTemplate {
+            msg: "Component token uses undeclared parameter name".to_string()
+        }
+    );
+
+    let query_msg = QueryMsg::Template {
+        name: "collection".to_string(),
+        version: None,
+    };
+    let res = query(deps.as_ref(), env, query_msg).unwrap();
+    let value: AppTemplate = from_json(res).unwrap();
+    assert_eq!(
+        value,
+        AppTemplate {
+            name: "collection".to_string(),
+            version: "1.0.0".to_string(),
+            components,
+            params_schema,
+            publisher: owner,
+        }
+    );
+}
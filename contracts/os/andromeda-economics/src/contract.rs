@@ -1,5 +1,6 @@
 use andromeda_std::ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg};
 use andromeda_std::ado_contract::ADOContract;
+use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::common::encode_binary;
 use andromeda_std::common::reply::ReplyId;
 use andromeda_std::error::ContractError;
@@ -66,9 +67,17 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_not_paused(deps.storage, &env, msg.as_ref())?;
     match msg {
         ExecuteMsg::Deposit { address } => execute::deposit_native(deps, info, address),
-        ExecuteMsg::PayFee { payee, action } => execute::pay_fee(deps, env, info, payee, action),
+        ExecuteMsg::PayFee {
+            payee,
+            action,
+            funds,
+        } => execute::pay_fee(deps, env, info, payee, action, funds),
+        ExecuteMsg::SetFeeSponsorship { address, enabled } => {
+            execute::set_fee_sponsorship(deps, info, address, enabled)
+        }
         ExecuteMsg::Withdraw { amount, asset } => {
             execute::withdraw_native(deps, info, amount, asset)
         }
@@ -80,6 +89,14 @@ pub fn execute(
         ExecuteMsg::Ownership(ownership_message) => {
             ADOContract::default().execute_ownership(deps, env, info, ownership_message)
         }
+        ExecuteMsg::Pause { actions, until } => ADOContract::default().execute_pause(
+            ExecuteContext::new(deps, info, env),
+            actions,
+            until,
+        ),
+        ExecuteMsg::Unpause {} => {
+            ADOContract::default().execute_unpause(ExecuteContext::new(deps, info, env))
+        }
     }
 }
 
@@ -105,15 +122,27 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::Balance { address, asset } => {
             Ok(to_json_binary(&query::balance(deps, address, asset)?)?)
         }
+        QueryMsg::FeeSponsorship { address } => {
+            encode_binary(&query::fee_sponsorship(deps, address)?)
+        }
+        QueryMsg::FeeQuote {
+            ado_type,
+            action,
+            payee,
+            funds,
+        } => encode_binary(&query::fee_quote(deps, ado_type, action, payee, funds)?),
         // Base queries
         QueryMsg::Version {} => encode_binary(&ADOContract::default().query_version(deps)?),
         QueryMsg::Type {} => encode_binary(&ADOContract::default().query_type(deps)?),
         QueryMsg::Owner {} => encode_binary(&ADOContract::default().query_contract_owner(deps)?),
+        QueryMsg::PauseStatus {} => {
+            encode_binary(&ADOContract::default().query_pause_status(deps, env)?)
+        }
         QueryMsg::KernelAddress {} => {
             encode_binary(&ADOContract::default().query_kernel_address(deps)?)
         }
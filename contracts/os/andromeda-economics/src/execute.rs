@@ -1,14 +1,33 @@
 use andromeda_std::{
-    ado_contract::ADOContract, amp::AndrAddr, common::reply::ReplyId, error::ContractError,
-    os::aos_querier::AOSQuerier,
+    ado_base::AndromedaQuery,
+    ado_contract::ADOContract,
+    amp::AndrAddr,
+    common::{reply::ReplyId, response::AndrEvent, Funds},
+    error::ContractError,
+    os::{
+        adodb::{ActionFee, FeeAsset},
+        aos_querier::AOSQuerier,
+    },
 };
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    attr, coin, ensure, to_json_binary, Addr, BankMsg, CosmosMsg, DepsMut, Empty, Env, MessageInfo,
-    Response, Storage, SubMsg, Uint128, WasmMsg,
+    attr, coin, ensure, to_json_binary, Addr, BankMsg, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 
-use crate::state::BALANCES;
+use crate::state::{BALANCES, FEE_SPONSORS};
+
+#[cw_serde]
+enum AppQueryMsg {
+    GetAddressesWithNames {},
+}
+
+#[cw_serde]
+struct ComponentAddress {
+    name: String,
+    address: String,
+}
 
 pub fn cw20_deposit(
     deps: DepsMut,
@@ -116,17 +135,140 @@ pub(crate) fn spend_balance(
     Ok(remainder)
 }
 
+/// Opts the given ADO or App contract in or out of paying fees from its balance.
+/// Sender must be the contract itself or its owner.
+pub fn set_fee_sponsorship(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: AndrAddr,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let addr = address.get_raw_address(&deps.as_ref())?;
+    ensure!(
+        info.sender == addr
+            || AOSQuerier::ado_owner_getter(&deps.querier, &addr).ok() == Some(info.sender.clone()),
+        ContractError::Unauthorized {}
+    );
+
+    if enabled {
+        FEE_SPONSORS.save(deps.storage, addr.clone(), &true)?;
+    } else {
+        FEE_SPONSORS.remove(deps.storage, addr.clone());
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "set_fee_sponsorship"),
+        attr("address", addr.to_string()),
+        attr("enabled", enabled.to_string()),
+    ]))
+}
+
+/// Spends as much of the remaining fee as possible from the given address' balance, recording it as a fee payer
+fn charge_fee_payer(
+    storage: &mut dyn Storage,
+    resp: &mut Response,
+    addr: &Addr,
+    asset: &str,
+    remainder: Uint128,
+) -> Result<Uint128, ContractError> {
+    let new_remainder = spend_balance(storage, addr, asset.to_string(), remainder)?;
+    if new_remainder < remainder {
+        resp.attributes.push(attr(
+            "fee_payer",
+            format!("{}:{}", addr, remainder - new_remainder),
+        ));
+    }
+    Ok(new_remainder)
+}
+
+/// Returns the addresses that can be charged a fee for the given ADO, in the order they are charged
+fn fee_payers(deps: Deps, ado: &Addr, payee: &Addr) -> Vec<Addr> {
+    let mut payers = vec![];
+    if FEE_SPONSORS.has(deps.storage, ado.clone()) {
+        payers.push(ado.clone());
+    }
+
+    let app_contract = deps
+        .querier
+        .query_wasm_smart::<Option<Addr>>(ado, &AndromedaQuery::AppContract {})
+        .ok()
+        .flatten();
+    if let Some(app_contract) = app_contract {
+        // The ADO's reported app is only charged if the app lists the ADO as one of its components
+        if FEE_SPONSORS.has(deps.storage, app_contract.clone())
+            && is_app_component(deps, &app_contract, ado)
+        {
+            payers.push(app_contract);
+        }
+    }
+
+    payers.push(payee.clone());
+    payers
+}
+
+/// Checks whether the given app lists the ADO among its component addresses
+fn is_app_component(deps: Deps, app_contract: &Addr, ado: &Addr) -> bool {
+    deps.querier
+        .query_wasm_smart::<Vec<ComponentAddress>>(
+            app_contract,
+            &AppQueryMsg::GetAddressesWithNames {},
+        )
+        .map(|components| {
+            components
+                .iter()
+                .any(|component| component.address == ado.as_str())
+        })
+        .unwrap_or(false)
+}
+
+/// Selects the first asset the fee can be paid in along with the amount owed.
+///
+/// An asset is selected if the combined balance of the given payers covers the fee in full.
+/// If no asset can be covered the primary asset is returned alongside `false`.
+pub(crate) fn select_fee_asset(
+    storage: &dyn Storage,
+    fee: &ActionFee,
+    payers: &[Addr],
+    funds: &[Funds],
+) -> Result<(FeeAsset, Uint128, bool), ContractError> {
+    let mut primary = None;
+    for fee_asset in fee.assets() {
+        let amount = fee.amount_for(&fee_asset, funds)?;
+        let asset = fee_asset.get_asset_string()?;
+        let available = payers.iter().try_fold(Uint128::zero(), |acc, payer| {
+            acc.checked_add(
+                BALANCES
+                    .load(storage, (payer.clone(), asset.to_string()))
+                    .unwrap_or_default(),
+            )
+        })?;
+        if available >= amount {
+            return Ok((fee_asset, amount, true));
+        }
+        if primary.is_none() {
+            primary = Some((fee_asset, amount));
+        }
+    }
+
+    let (fee_asset, amount) = primary.ok_or(ContractError::InsufficientFunds {})?;
+    Ok((fee_asset, amount, false))
+}
+
 /// Charges a fee depending on the sending ADO and the action being performed.
 /// Sender must be an ADO contract else this will error.
 ///
+/// The fee is paid in the first of its accepted assets that can be covered in full.
 /// Fees are charged in the following order:
-/// 1. Payee
+/// 1. ADO, if opted in to fee sponsorship
+/// 2. App contract, if opted in to fee sponsorship
+/// 3. Payee
 pub fn pay_fee(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     payee: Addr,
     action: String,
+    funds: Option<Vec<Funds>>,
 ) -> Result<Response, ContractError> {
     let mut resp = Response::default();
 
@@ -136,7 +278,7 @@ pub fn pay_fee(
         attr("payee", payee.to_string()),
     ];
 
-    let contract_info = deps.querier.query_wasm_contract_info(info.sender);
+    let contract_info = deps.querier.query_wasm_contract_info(info.sender.clone());
     if let Ok(contract_info) = contract_info {
         let code_id = contract_info.code_id;
         let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
@@ -159,36 +301,21 @@ pub fn pay_fee(
             None => Ok(resp),
             Some(fee) => {
                 fee.validate_asset(deps.api)?;
-                let asset = fee.get_asset_string()?;
-
-                // Removing ADO/App payments temporarily pending discussion
-                // Charge ADO first
-                // let mut remainder =
-                //     spend_balance(deps.storage, &info.sender, asset.to_string(), fee.amount)?;
-
-                // Next charge the app
-                // if remainder > Uint128::zero() {
-                //     let app_contract = deps.querier.query_wasm_smart::<Option<Addr>>(
-                //         &info.sender,
-                //         &AndromedaQuery::AppContract {},
-                //     )?;
-                //     remainder = if let Some(app_contract) = app_contract {
-                //         spend_balance(deps.storage, &app_contract, asset.to_string(), remainder)?
-                //     } else {
-                //         remainder
-                //     };
-                // }
-
-                // Next charge the payee
-                // if remainder > Uint128::zero() {
-                let remainder = spend_balance(deps.storage, &payee, asset.to_string(), fee.amount)?;
-                // }
-
-                // If balance remaining then not enough funds to pay fee
-                ensure!(
-                    remainder == Uint128::zero(),
-                    ContractError::InsufficientFunds {}
-                );
+
+                let payers = fee_payers(deps.as_ref(), &info.sender, &payee);
+                let (fee_asset, amount, payable) =
+                    select_fee_asset(deps.storage, &fee, &payers, &funds.unwrap_or_default())?;
+                // If no asset can be covered then not enough funds to pay fee
+                ensure!(payable, ContractError::InsufficientFunds {});
+                let asset = fee_asset.get_asset_string()?;
+
+                let mut remainder = amount;
+                for payer in payers.iter() {
+                    if remainder.is_zero() {
+                        break;
+                    }
+                    remainder = charge_fee_payer(deps.storage, &mut resp, payer, asset, remainder)?;
+                }
 
                 let recipient = if let Some(receiver) = fee.receiver.clone() {
                     receiver
@@ -210,12 +337,22 @@ pub fn pay_fee(
                 BALANCES.save(
                     deps.storage,
                     (recipient.clone(), asset.to_string()),
-                    &(receiver_balance + fee.amount),
+                    &(receiver_balance + amount),
                 )?;
 
                 resp = resp
-                    .add_attribute("paid_fee", format!("{}{}", fee.amount, fee.asset))
-                    .add_attribute("fee_recipient", recipient.to_string());
+                    .add_attribute("paid_fee", format!("{}{}", amount, fee_asset.asset))
+                    .add_attribute("fee_recipient", recipient.to_string())
+                    .add_event(
+                        AndrEvent::FeePaid {
+                            action,
+                            payee: payee.to_string(),
+                            recipient: recipient.to_string(),
+                            asset: fee_asset.asset.clone(),
+                            amount,
+                        }
+                        .into(),
+                    );
                 Ok(resp)
             }
         }
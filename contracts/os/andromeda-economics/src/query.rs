@@ -1,7 +1,13 @@
-use andromeda_std::{amp::AndrAddr, error::ContractError};
+use andromeda_std::{
+    ado_contract::ADOContract, amp::AndrAddr, common::Funds, error::ContractError,
+    os::aos_querier::AOSQuerier, os::economics::FeeQuoteResponse,
+};
 use cosmwasm_std::{Deps, Uint128};
 
-use crate::state::BALANCES;
+use crate::{
+    execute::select_fee_asset,
+    state::{BALANCES, FEE_SPONSORS},
+};
 
 pub fn balance(deps: Deps, address: AndrAddr, asset: String) -> Result<Uint128, ContractError> {
     let addr = address.get_raw_address(&deps)?;
@@ -10,3 +16,33 @@ pub fn balance(deps: Deps, address: AndrAddr, asset: String) -> Result<Uint128,
         .unwrap_or_default();
     Ok(balance)
 }
+
+pub fn fee_sponsorship(deps: Deps, address: AndrAddr) -> Result<bool, ContractError> {
+    let addr = address.get_raw_address(&deps)?;
+    Ok(FEE_SPONSORS.has(deps.storage, addr))
+}
+
+pub fn fee_quote(
+    deps: Deps,
+    ado_type: String,
+    action: String,
+    payee: AndrAddr,
+    funds: Option<Vec<Funds>>,
+) -> Result<Option<FeeQuoteResponse>, ContractError> {
+    let payee = payee.get_raw_address(&deps)?;
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let fee = AOSQuerier::action_fee_getter(&deps.querier, &adodb_addr, &ado_type, &action)?;
+
+    match fee {
+        None => Ok(None),
+        Some(fee) => {
+            let (fee_asset, amount, payable) =
+                select_fee_asset(deps.storage, &fee, &[payee], &funds.unwrap_or_default())?;
+            Ok(Some(FeeQuoteResponse {
+                asset: fee_asset.asset,
+                amount,
+                payable,
+            }))
+        }
+    }
+}
@@ -3,3 +3,5 @@ use cw_storage_plus::Map;
 
 /// Contains all balances for an address
 pub const BALANCES: Map<(Addr, String), Uint128> = Map::new("balances");
+/// ADO and App contracts that have opted in to paying fees from their balance
+pub const FEE_SPONSORS: Map<Addr, bool> = Map::new("fee_sponsors");
@@ -1,18 +1,24 @@
 use andromeda_std::amp::AndrAddr;
+use andromeda_std::common::Funds;
 use andromeda_std::error::ContractError;
 use andromeda_std::testing::mock_querier::MOCK_ADO_PUBLISHER;
 #[cfg(test)]
 use andromeda_std::testing::mock_querier::{
-    mock_dependencies_custom, MOCK_ACTION, MOCK_KERNEL_CONTRACT,
+    mock_dependencies_custom, MOCK_ACTION, MOCK_APP_COMPONENT, MOCK_APP_CONTRACT,
+    MOCK_KERNEL_CONTRACT, MOCK_MULTI_ASSET_ACTION,
+};
+use cosmwasm_std::{
+    attr, coin, from_json, to_json_binary, Addr, BankMsg, CosmosMsg, DepsMut, Uint128,
 };
-use cosmwasm_std::{coin, to_json_binary, Addr, BankMsg, CosmosMsg, Uint128};
 use cw20::Cw20ReceiveMsg;
 
-use crate::contract::{execute, instantiate};
+use crate::contract::{execute, instantiate, query};
 use crate::execute::{cw20_withdraw_msg, spend_balance};
 use crate::state::BALANCES;
 
-use andromeda_std::os::economics::{Cw20HookMsg, ExecuteMsg, InstantiateMsg};
+use andromeda_std::os::economics::{
+    Cw20HookMsg, ExecuteMsg, FeeQuoteResponse, InstantiateMsg, QueryMsg,
+};
 
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
@@ -128,6 +134,7 @@ fn test_pay_fee() {
     let msg = ExecuteMsg::PayFee {
         payee: Addr::unchecked(payee),
         action: MOCK_ACTION.to_string(),
+        funds: None,
     };
 
     // Paying fee without funds
@@ -162,178 +169,285 @@ fn test_pay_fee() {
     assert_eq!(balance, Uint128::from(10u128));
 }
 
-// Temporarily disabled
+fn enable_fee_sponsorship(deps: DepsMut, sender: &str, address: &str) {
+    let msg = ExecuteMsg::SetFeeSponsorship {
+        address: AndrAddr::from_string(address),
+        enabled: true,
+    };
+    execute(deps, mock_env(), mock_info(sender, &[]), msg).unwrap();
+}
+
 // Tests payment for fees via the contract balance
-// #[test]
-// fn test_pay_fee_contract() {
-//     let mut deps = mock_dependencies_custom(&[]);
-//     let env = mock_env();
-//     let info = mock_info("creator", &[]);
-//     let payee = "payee";
-
-//     let msg = ExecuteMsg::PayFee {
-//         payee: Addr::unchecked(payee),
-//         action: MOCK_ACTION.to_string(),
-//     };
-
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (info.sender.clone(), "uusd".to_string()),
-//             &Uint128::from(10u128),
-//         )
-//         .unwrap();
-
-//     let res = execute(deps.as_mut(), env, info.clone(), msg);
-//     assert!(res.is_ok());
-
-//     let balance = BALANCES
-//         .load(deps.as_ref().storage, (info.sender, "uusd".to_string()))
-//         .unwrap();
-//     assert_eq!(balance, Uint128::from(0u128));
-
-//     // Check publisher balance
-//     let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
-//     let balance = BALANCES
-//         .load(deps.as_ref().storage, (publisher, "uusd".to_string()))
-//         .unwrap_or_default();
-//     assert_eq!(balance, Uint128::from(10u128));
-// }
-
-// Temporarily disabled
-// #[test]
-// fn test_pay_fee_app() {
-//     let mut deps = mock_dependencies_custom(&[]);
-//     let env = mock_env();
-//     let info = mock_info("creator", &[]);
-//     let payee = "payee";
-
-//     let msg = ExecuteMsg::PayFee {
-//         payee: Addr::unchecked(payee),
-//         action: MOCK_ACTION.to_string(),
-//     };
-
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
-//             &Uint128::from(10u128),
-//         )
-//         .unwrap();
-
-//     let res = execute(deps.as_mut(), env, info.clone(), msg);
-//     assert!(res.is_ok());
-
-//     let balance = BALANCES
-//         .load(deps.as_ref().storage, (info.sender, "uusd".to_string()))
-//         .unwrap();
-//     assert_eq!(balance, Uint128::from(0u128));
-
-//     // Check publisher balance
-//     let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
-//     let balance = BALANCES
-//         .load(deps.as_ref().storage, (publisher, "uusd".to_string()))
-//         .unwrap_or_default();
-//     assert_eq!(balance, Uint128::from(10u128));
-// }
-
-// Temporarily disabled
+#[test]
+fn test_pay_fee_contract() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let payee = "payee";
+
+    let msg = ExecuteMsg::PayFee {
+        payee: Addr::unchecked(payee),
+        action: MOCK_ACTION.to_string(),
+        funds: None,
+    };
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (info.sender.clone(), "uusd".to_string()),
+            &Uint128::from(10u128),
+        )
+        .unwrap();
+
+    // The contract balance is not spent without opting in
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+
+    enable_fee_sponsorship(deps.as_mut(), info.sender.as_str(), info.sender.as_str());
+
+    let res = execute(deps.as_mut(), env, info.clone(), msg);
+    assert!(res.is_ok());
+
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (info.sender, "uusd".to_string()))
+        .unwrap();
+    assert_eq!(balance, Uint128::from(0u128));
+
+    // Check publisher balance
+    let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (publisher, "uusd".to_string()))
+        .unwrap_or_default();
+    assert_eq!(balance, Uint128::from(10u128));
+}
+
+#[test]
+fn test_pay_fee_app() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_APP_COMPONENT, &[]);
+    let payee = "payee";
+
+    let msg = ExecuteMsg::PayFee {
+        payee: Addr::unchecked(payee),
+        action: MOCK_ACTION.to_string(),
+        funds: None,
+    };
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
+            &Uint128::from(10u128),
+        )
+        .unwrap();
+
+    // Only the contract itself or its owner can opt in
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("attacker", &[]),
+        ExecuteMsg::SetFeeSponsorship {
+            address: AndrAddr::from_string(MOCK_APP_CONTRACT),
+            enabled: true,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    enable_fee_sponsorship(deps.as_mut(), "owner", MOCK_APP_CONTRACT);
+
+    // ADOs not listed as a component of the app cannot spend its balance
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+
+    let res = execute(deps.as_mut(), env, info, msg);
+    assert!(res.is_ok());
+
+    let balance = BALANCES
+        .load(
+            deps.as_ref().storage,
+            (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
+        )
+        .unwrap();
+    assert_eq!(balance, Uint128::from(0u128));
+
+    // Check publisher balance
+    let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (publisher, "uusd".to_string()))
+        .unwrap_or_default();
+    assert_eq!(balance, Uint128::from(10u128));
+}
+
 // Tests payment of fees via fallthrough
-// #[test]
-// fn test_pay_fee_joint() {
-//     let mut deps = mock_dependencies_custom(&[]);
-//     let env = mock_env();
-//     let info = mock_info("creator", &[]);
-//     let payee = "payee";
-
-//     let msg = ExecuteMsg::PayFee {
-//         payee: Addr::unchecked(payee),
-//         action: MOCK_ACTION.to_string(),
-//     };
-
-//     // Contract balance
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (info.sender.clone(), "uusd".to_string()),
-//             &Uint128::from(4u128),
-//         )
-//         .unwrap();
-//     // Payee balance
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (Addr::unchecked(payee), "uusd".to_string()),
-//             &Uint128::from(3u128),
-//         )
-//         .unwrap();
-//     // App balance
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
-//             &Uint128::from(3u128),
-//         )
-//         .unwrap();
-
-//     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
-//     assert!(res.is_ok());
-
-//     // Check contract balance
-//     let balance = BALANCES
-//         .load(
-//             deps.as_ref().storage,
-//             (info.sender.clone(), "uusd".to_string()),
-//         )
-//         .unwrap();
-//     assert_eq!(balance, Uint128::from(0u128));
-
-//     // Check payee balance
-//     let balance = BALANCES
-//         .load(
-//             deps.as_ref().storage,
-//             (Addr::unchecked(payee), "uusd".to_string()),
-//         )
-//         .unwrap();
-//     assert_eq!(balance, Uint128::from(0u128));
-
-//     // Check app balance
-//     let balance = BALANCES
-//         .load(
-//             deps.as_ref().storage,
-//             (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
-//         )
-//         .unwrap();
-//     assert_eq!(balance, Uint128::from(0u128));
-
-//     // Check publisher balance
-//     let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
-//     let balance = BALANCES
-//         .load(deps.as_ref().storage, (publisher, "uusd".to_string()))
-//         .unwrap_or_default();
-//     assert_eq!(balance, Uint128::from(10u128));
-
-//     // Check insufficient funds
-//     // Contract balance
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (info.sender.clone(), "uusd".to_string()),
-//             &Uint128::from(4u128),
-//         )
-//         .unwrap();
-//     // App balance
-//     BALANCES
-//         .save(
-//             deps.as_mut().storage,
-//             (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
-//             &Uint128::from(3u128),
-//         )
-//         .unwrap();
-
-//     let res = execute(deps.as_mut(), env, info, msg).unwrap_err();
-//     assert_eq!(res, ContractError::InsufficientFunds {});
-// }
+#[test]
+fn test_pay_fee_joint() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_APP_COMPONENT, &[]);
+    let payee = "payee";
+
+    let msg = ExecuteMsg::PayFee {
+        payee: Addr::unchecked(payee),
+        action: MOCK_ACTION.to_string(),
+        funds: None,
+    };
+
+    // Contract balance
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (info.sender.clone(), "uusd".to_string()),
+            &Uint128::from(4u128),
+        )
+        .unwrap();
+    // Payee balance
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (Addr::unchecked(payee), "uusd".to_string()),
+            &Uint128::from(3u128),
+        )
+        .unwrap();
+    // App balance
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (Addr::unchecked(MOCK_APP_CONTRACT), "uusd".to_string()),
+            &Uint128::from(3u128),
+        )
+        .unwrap();
+    enable_fee_sponsorship(deps.as_mut(), info.sender.as_str(), info.sender.as_str());
+    enable_fee_sponsorship(deps.as_mut(), "owner", MOCK_APP_CONTRACT);
+
+    let res = execute(deps.as_mut(), env, info.clone(), msg).unwrap();
+    assert_eq!(
+        res.attributes
+            .iter()
+            .filter(|attr| attr.key == "fee_payer")
+            .map(|attr| attr.value.clone())
+            .collect::<Vec<String>>(),
+        vec![
+            format!("{}:4", info.sender),
+            format!("{MOCK_APP_CONTRACT}:3"),
+            format!("{payee}:3"),
+        ]
+    );
+
+    for addr in [info.sender.as_str(), payee, MOCK_APP_CONTRACT] {
+        let balance = BALANCES
+            .load(
+                deps.as_ref().storage,
+                (Addr::unchecked(addr), "uusd".to_string()),
+            )
+            .unwrap();
+        assert_eq!(balance, Uint128::from(0u128));
+    }
+
+    let res: bool = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FeeSponsorship {
+                address: AndrAddr::from_string(MOCK_APP_CONTRACT),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(res);
+}
+
+#[test]
+fn test_pay_fee_multi_asset() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let payee = Addr::unchecked("payee");
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uusd".to_string()),
+            &Uint128::from(5u128),
+        )
+        .unwrap();
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uandr".to_string()),
+            &Uint128::from(100u128),
+        )
+        .unwrap();
+
+    // The payee cannot cover 10uusd so the fee falls through to 20uandr plus 10% of the attached uandr
+    let msg = ExecuteMsg::PayFee {
+        payee: payee.clone(),
+        action: MOCK_MULTI_ASSET_ACTION.to_string(),
+        funds: Some(vec![Funds::Native(coin(100, "uandr"))]),
+    };
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert!(res.attributes.contains(&attr("paid_fee", "30native:uandr")));
+
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (payee.clone(), "uandr".to_string()))
+        .unwrap();
+    assert_eq!(balance, Uint128::from(70u128));
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (payee.clone(), "uusd".to_string()))
+        .unwrap();
+    assert_eq!(balance, Uint128::from(5u128));
+    let balance = BALANCES
+        .load(
+            deps.as_ref().storage,
+            (Addr::unchecked(MOCK_ADO_PUBLISHER), "uandr".to_string()),
+        )
+        .unwrap();
+    assert_eq!(balance, Uint128::from(30u128));
+
+    // No asset can be covered
+    let msg = ExecuteMsg::PayFee {
+        payee: payee.clone(),
+        action: MOCK_MULTI_ASSET_ACTION.to_string(),
+        funds: Some(vec![Funds::Native(coin(1000, "uandr"))]),
+    };
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+
+    // Quotes
+    let quote = |funds: Option<Vec<Funds>>| -> Option<FeeQuoteResponse> {
+        let msg = QueryMsg::FeeQuote {
+            ado_type: "ADOType".to_string(),
+            action: MOCK_MULTI_ASSET_ACTION.to_string(),
+            payee: AndrAddr::from_string(payee.to_string()),
+            funds,
+        };
+        from_json(query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+    };
+    assert_eq!(
+        quote(None),
+        Some(FeeQuoteResponse {
+            asset: "native:uandr".to_string(),
+            amount: Uint128::from(20u128),
+            payable: true,
+        })
+    );
+    assert_eq!(
+        quote(Some(vec![Funds::Native(coin(1000, "uandr"))])),
+        Some(FeeQuoteResponse {
+            asset: "native:uusd".to_string(),
+            amount: Uint128::from(10u128),
+            payable: false,
+        })
+    );
+}
 
 #[test]
 fn test_withdraw() {
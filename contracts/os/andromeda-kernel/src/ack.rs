@@ -1,5 +1,6 @@
+use andromeda_std::{error::ContractError, os::kernel::CreateADOAck};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_json_binary, Binary};
+use cosmwasm_std::{from_json, to_json_binary, Addr, Binary};
 
 /// IBC ACK. See:
 /// https://github.com/cosmos/cosmos-sdk/blob/f999b1ff05a4db4a338a855713864497bedd4396/proto/ibc/core/channel/v1/channel.proto#L141-L147
@@ -19,7 +20,32 @@ pub fn make_ack_fail(err: String) -> Binary {
     to_json_binary(&res).unwrap()
 }
 
-// pub fn make_ack_create_ado_success() -> Binary {
-//     let res = Ack::Result(b"1".into());
-//     to_json_binary(&res).unwrap()
-// }
+pub fn make_ack_create_ado_success(ado_addr: Addr, components: Vec<(String, String)>) -> Binary {
+    let res = Ack::Result(
+        to_json_binary(&CreateADOAck {
+            address: ado_addr.to_string(),
+            components,
+        })
+        .unwrap(),
+    );
+    to_json_binary(&res).unwrap()
+}
+
+/// The data of a successful creation acknowledgement, kernels that predate `CreateADOAck` acknowledge with the address only
+#[cw_serde]
+#[serde(untagged)]
+enum CreateADOAckData {
+    Ack(CreateADOAck),
+    Address(String),
+}
+
+/// Reads the acknowledgement of a cross-chain ADO creation
+pub fn parse_create_ado_ack(data: &Binary) -> Result<CreateADOAck, ContractError> {
+    Ok(match from_json(data)? {
+        CreateADOAckData::Ack(ack) => ack,
+        CreateADOAckData::Address(address) => CreateADOAck {
+            address,
+            components: vec![],
+        },
+    })
+}
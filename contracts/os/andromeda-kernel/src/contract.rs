@@ -11,7 +11,7 @@ use cosmwasm_std::{
 };
 
 use crate::ibc::{IBCLifecycleComplete, SudoMsg};
-use crate::reply::{on_reply_create_ado, on_reply_ibc_hooks_packet_send};
+use crate::reply::{on_reply_amp_msg, on_reply_create_ado, on_reply_ibc_hooks_packet_send};
 use crate::state::CURR_CHAIN;
 use crate::{execute, query, sudo};
 
@@ -45,6 +45,18 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // AMP messages that proceed upon failure record the error rather than reverting
+    if msg.id == ReplyId::AMPMsg.repr() {
+        return on_reply_amp_msg(deps, msg);
+    }
+
+    // Failing cross-chain creation callbacks do not revert the acknowledgement
+    if msg.id == ReplyId::CrossChainCreateCallback.repr() {
+        return Ok(
+            Response::default().add_attribute("callback_success", msg.result.is_ok().to_string())
+        );
+    }
+
     if msg.result.is_err() {
         return Err(ContractError::Std(StdError::generic_err(format!(
             "{}:{}",
@@ -73,6 +85,11 @@ pub fn execute(
         info,
         amp_ctx: None,
     };
+    ADOContract::default().ensure_not_paused(
+        execute_env.deps.storage,
+        &execute_env.env,
+        msg.as_ref(),
+    )?;
 
     match msg {
         ExecuteMsg::AMPReceive(packet) => execute::amp_receive(
@@ -90,7 +107,8 @@ pub fn execute(
             msg,
             owner,
             chain,
-        } => execute::create(execute_env, ado_type, msg, owner, chain),
+            force,
+        } => execute::create(execute_env, ado_type, msg, owner, chain, force),
         ExecuteMsg::AssignChannels {
             ics20_channel_id,
             direct_channel_id,
@@ -103,6 +121,13 @@ pub fn execute(
             chain,
             kernel_address,
         ),
+        ExecuteMsg::ReceiveTransferLeg { message, leg } => {
+            execute::receive_transfer_leg(execute_env, message, leg)
+        }
+        ExecuteMsg::RefundTransfer {
+            sender,
+            transfer_id,
+        } => execute::refund_transfer(execute_env, sender, transfer_id),
         ExecuteMsg::Recover {} => execute::recover(execute_env),
         ExecuteMsg::UpdateChainName { chain_name } => {
             execute::update_chain_name(execute_env, chain_name)
@@ -115,6 +140,10 @@ pub fn execute(
             execute_env.info,
             ownership_message,
         ),
+        ExecuteMsg::Pause { actions, until } => {
+            ADOContract::default().execute_pause(execute_env, actions, until)
+        }
+        ExecuteMsg::Unpause {} => ADOContract::default().execute_unpause(execute_env),
     }
 }
 
@@ -139,7 +168,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::KeyAddress { key } => encode_binary(&query::key_address(deps, key)?),
         QueryMsg::VerifyAddress { address } => {
@@ -148,9 +177,18 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
         QueryMsg::ChannelInfo { chain } => encode_binary(&query::channel_info(deps, chain)?),
         QueryMsg::Recoveries { addr } => encode_binary(&query::recoveries(deps, addr)?),
         QueryMsg::ChainName {} => encode_binary(&query::chain_name(deps)?),
+        QueryMsg::PacketStatus { id } => encode_binary(&query::packet_status(deps, id)?),
+        QueryMsg::PacketsByOrigin {
+            origin,
+            start_after,
+            limit,
+        } => encode_binary(&query::packets_by_origin(deps, origin, start_after, limit)?),
         // Base queries
         QueryMsg::Version {} => encode_binary(&ADOContract::default().query_version(deps)?),
         QueryMsg::Type {} => encode_binary(&ADOContract::default().query_type(deps)?),
         QueryMsg::Owner {} => encode_binary(&ADOContract::default().query_contract_owner(deps)?),
+        QueryMsg::PauseStatus {} => {
+            encode_binary(&ADOContract::default().query_pause_status(deps, env)?)
+        }
     }
 }
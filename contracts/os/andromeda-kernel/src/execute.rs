@@ -6,30 +6,51 @@ use andromeda_std::amp::{ADO_DB_KEY, VFS_KEY};
 use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::common::has_coins_merged;
 use andromeda_std::common::reply::ReplyId;
+use andromeda_std::common::response::AndrEvent;
 use andromeda_std::error::ContractError;
 use andromeda_std::os::aos_querier::AOSQuerier;
-use andromeda_std::os::kernel::{ChannelInfo, IbcExecuteMsg, InternalMsg};
+use andromeda_std::os::kernel::{
+    AMPMsgResult, AMPPktResponse, ChannelInfo, IbcExecuteMsg, IbcHooksTransferLeg, InternalMsg,
+    PacketHop, PacketInfo, PacketStatus,
+};
 
 use andromeda_std::os::vfs::vfs_resolve_symlink;
 use cosmwasm_std::{
     attr, ensure, to_json_binary, Addr, BankMsg, Binary, Coin, ContractInfoResponse, CosmosMsg,
-    DepsMut, Env, IbcMsg, MessageInfo, Response, StdError, SubMsg, WasmMsg,
+    DepsMut, Env, IbcMsg, MessageInfo, ReplyOn, Response, StdError, Storage, SubMsg, WasmMsg,
 };
 
-use crate::ibc::{generate_transfer_message, PACKET_LIFETIME};
+use crate::ibc::{generate_transfer_message, packet_data_hash, PACKET_LIFETIME};
 use crate::query;
 use crate::state::{
-    IBCHooksPacketSendState, ADO_OWNER, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CURR_CHAIN,
-    IBC_FUND_RECOVERY, KERNEL_ADDRESSES, OUTGOING_IBC_HOOKS_PACKETS,
+    next_packet_id, save_packet, AMPMsgReplyFrame, IBCHooksPacketSendState, IncomingTransfer,
+    PendingAMPMsg, ADO_OWNER, AMP_MSG_REPLY_FRAMES, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN,
+    CROSS_CHAIN_CREATORS, CURR_CHAIN, DIRECT_PACKET_IDS, DIRECT_PACKET_KEYS, IBC_FUND_RECOVERY,
+    IBC_HOOKS_TRANSFER_ID, INCOMING_IBC_HOOKS_TRANSFERS, KERNEL_ADDRESSES,
+    OUTGOING_IBC_HOOKS_PACKETS,
 };
 
-pub fn send(ctx: ExecuteContext, message: AMPMsg) -> Result<Response, ContractError> {
+pub fn send(mut ctx: ExecuteContext, message: AMPMsg) -> Result<Response, ContractError> {
     ensure!(
         has_coins_merged(ctx.info.funds.as_slice(), message.funds.as_slice()),
         ContractError::InsufficientFunds {}
     );
-    let res = MsgHandler(message).handle(ctx.deps, ctx.info, ctx.env, ctx.amp_ctx, 0)?;
-    Ok(res)
+    let mut handler = MsgHandler(message);
+    let res = handler.handle(
+        ctx.deps.branch(),
+        ctx.info.clone(),
+        ctx.env.clone(),
+        ctx.amp_ctx,
+        0,
+    )?;
+    track_relayed_msgs(
+        ctx.deps.storage,
+        &ctx.env,
+        res,
+        ctx.info.sender.to_string(),
+        &ctx.info.sender,
+        &[handler],
+    )
 }
 
 pub fn amp_receive(
@@ -58,6 +79,7 @@ pub fn amp_receive(
         }
     );
 
+    let mut handlers = vec![];
     for (idx, message) in packet.messages.iter().enumerate() {
         let mut handler = MsgHandler::new(message.clone());
         let msg_res = handler.handle(
@@ -70,6 +92,7 @@ pub fn amp_receive(
         res.messages.extend_from_slice(&msg_res.messages);
         res.attributes.extend_from_slice(&msg_res.attributes);
         res.events.extend_from_slice(&msg_res.events);
+        handlers.push(handler);
     }
 
     let message_funds = packet
@@ -82,9 +105,146 @@ pub fn amp_receive(
         ContractError::InsufficientFunds {}
     );
 
+    let res = track_relayed_msgs(
+        deps.storage,
+        &env,
+        res,
+        packet.ctx.get_origin(),
+        &info.sender,
+        &handlers,
+    )?;
     Ok(res.add_attribute("action", "handle_amp_packet"))
 }
 
+/// Logs the relayed packet and records the relayed messages that proceed upon failure so their replies can be matched to them.
+///
+/// Sets the response data to a summary of the relayed messages, assuming they all succeed.
+/// The summary is updated by `ReplyId::AMPMsg` replies for any message that fails.
+fn track_relayed_msgs(
+    storage: &mut dyn Storage,
+    env: &Env,
+    res: Response,
+    origin: String,
+    sender: &Addr,
+    handlers: &[MsgHandler],
+) -> Result<Response, ContractError> {
+    let mut pending = vec![];
+    let mut results = vec![];
+    for (idx, handler) in handlers.iter().enumerate() {
+        let AMPMsg {
+            recipient,
+            message,
+            funds,
+            ..
+        } = handler.message();
+        if handler.proceeds_on_error() {
+            let is_bank_send = Binary::default() == message.clone();
+            pending.push(PendingAMPMsg {
+                index: idx as u64,
+                refund_addr: sender.clone(),
+                funds: funds.clone(),
+                funds_recipient: is_bank_send.then(|| recipient.clone()),
+            });
+        }
+        results.push(AMPMsgResult {
+            index: idx as u64,
+            recipient: recipient.clone(),
+            success: true,
+            error: None,
+        });
+    }
+
+    let recipients = results.iter().map(|r| r.recipient.clone()).collect();
+    let packet_id = log_packet(storage, env, &res, origin, sender, recipients)?;
+
+    let data = to_json_binary(&AMPPktResponse {
+        packet_id,
+        results: results.clone(),
+    })?;
+    if !pending.is_empty() {
+        let mut frames = AMP_MSG_REPLY_FRAMES.may_load(storage)?.unwrap_or_default();
+        frames.push(AMPMsgReplyFrame {
+            packet_id,
+            pending,
+            results,
+        });
+        AMP_MSG_REPLY_FRAMES.save(storage, &frames)?;
+    }
+
+    Ok(res
+        .add_attribute("packet_id", packet_id.to_string())
+        .set_data(data))
+}
+
+/// Logs a relayed packet along with any cross-chain hops made by the response's messages, returning the packet identifier
+fn log_packet(
+    storage: &mut dyn Storage,
+    env: &Env,
+    res: &Response,
+    origin: String,
+    sender: &Addr,
+    recipients: Vec<AndrAddr>,
+) -> Result<u64, ContractError> {
+    let packet_id = next_packet_id(storage)?;
+    // Cross-chain hops are resolved once acknowledged
+    let mut hops = vec![];
+    let mut direct_keys = vec![];
+    for sub_msg in res.messages.iter() {
+        if let CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id, data, ..
+        }) = &sub_msg.msg
+        {
+            let hash = packet_data_hash(data);
+            let key = (channel_id.as_str(), hash.as_str());
+            let mut ids = DIRECT_PACKET_IDS
+                .may_load(storage, key)?
+                .unwrap_or_default();
+            ids.push(packet_id);
+            DIRECT_PACKET_IDS.save(storage, key, &ids)?;
+            direct_keys.push((channel_id.clone(), hash));
+            hops.push(PacketHop {
+                channel: channel_id.clone(),
+                sequence: None,
+                status: PacketStatus::Pending,
+            });
+        }
+    }
+    if !direct_keys.is_empty() {
+        DIRECT_PACKET_KEYS.save(storage, packet_id, &direct_keys)?;
+    }
+    let mut outgoing_packets = OUTGOING_IBC_HOOKS_PACKETS
+        .may_load(storage)?
+        .unwrap_or_default();
+    if outgoing_packets.iter().any(|p| p.packet_id.is_none()) {
+        for outgoing in outgoing_packets
+            .iter_mut()
+            .filter(|p| p.packet_id.is_none())
+        {
+            outgoing.packet_id = Some(packet_id);
+            hops.push(PacketHop {
+                channel: outgoing.channel_id.clone(),
+                sequence: None,
+                status: PacketStatus::Pending,
+            });
+        }
+        OUTGOING_IBC_HOOKS_PACKETS.save(storage, &outgoing_packets)?;
+    }
+    save_packet(
+        storage,
+        PacketInfo {
+            id: packet_id,
+            origin,
+            previous_sender: sender.to_string(),
+            recipients,
+            status: PacketStatus::Pending,
+            hops,
+            error: None,
+            block_height: env.block.height,
+        },
+    )?;
+    Ok(packet_id)
+}
+
 pub fn upsert_key_address(
     execute_ctx: ExecuteContext,
     key: String,
@@ -120,48 +280,82 @@ pub fn create(
     msg: Binary,
     owner: Option<AndrAddr>,
     chain: Option<String>,
+    force: Option<bool>,
 ) -> Result<Response, ContractError> {
     // If chain is provided an owner must be provided
     ensure!(
         chain.is_none() || owner.is_some(),
         ContractError::Unauthorized {}
     );
-    if let Some(_chain) = chain {
-        Err(ContractError::CrossChainComponentsCurrentlyDisabled {})
-        // let channel_info = if let Some(channel_info) =
-        //     CHAIN_TO_CHANNEL.may_load(execute_ctx.deps.storage, &chain)?
-        // {
-        //     Ok::<ChannelInfo, ContractError>(channel_info)
-        // } else {
-        //     return Err(ContractError::InvalidPacket {
-        //         error: Some(format!("Channel not found for chain {chain}")),
-        //     });
-        // }?;
-        // let kernel_msg = IbcExecuteMsg::CreateADO {
-        //     instantiation_msg: msg.clone(),
-        //     owner: owner.clone().unwrap(),
-        //     ado_type: ado_type.clone(),
-        // };
-        // let ibc_msg = IbcMsg::SendPacket {
-        //     channel_id: channel_info.direct_channel_id.clone().unwrap(),
-        //     data: to_json_binary(&kernel_msg)?,
-        //     timeout: execute_ctx
-        //         .env
-        //         .block
-        //         .time
-        //         .plus_seconds(PACKET_LIFETIME)
-        //         .into(),
-        // };
-        // Ok(Response::default()
-        //     .add_message(ibc_msg)
-        //     .add_attributes(vec![
-        //         attr("action", "execute_create"),
-        //         attr("ado_type", ado_type),
-        //         attr("owner", owner.unwrap().to_string()),
-        //         attr("chain", chain),
-        //         attr("receiving_kernel_address", channel_info.kernel_address),
-        //         attr("msg", msg.to_string()),
-        //     ]))
+    if let Some(chain) = chain {
+        let channel_info = if let Some(channel_info) =
+            CHAIN_TO_CHANNEL.may_load(execute_ctx.deps.storage, &chain)?
+        {
+            Ok::<ChannelInfo, ContractError>(channel_info)
+        } else {
+            return Err(ContractError::InvalidPacket {
+                error: Some(format!("Channel not found for chain {chain}")),
+            });
+        }?;
+        let channel_id = if let Some(direct_channel_id) = channel_info.direct_channel_id {
+            Ok::<String, ContractError>(direct_channel_id)
+        } else {
+            return Err(ContractError::InvalidPacket {
+                error: Some(format!("Channel not found for chain {chain}")),
+            });
+        }?;
+        let owner = owner.unwrap();
+        let kernel_msg = IbcExecuteMsg::CreateADO {
+            instantiation_msg: msg.clone(),
+            owner: owner.clone(),
+            ado_type: ado_type.clone(),
+            force,
+        };
+        let data = to_json_binary(&kernel_msg)?;
+
+        // The creator is notified of the result once the packet is acknowledged
+        let hash = packet_data_hash(&data);
+        let key = (channel_id.as_str(), hash.as_str());
+        let mut creators = CROSS_CHAIN_CREATORS
+            .may_load(execute_ctx.deps.storage, key)?
+            .unwrap_or_default();
+        creators.push(execute_ctx.info.sender.clone());
+        CROSS_CHAIN_CREATORS.save(execute_ctx.deps.storage, key, &creators)?;
+
+        let ibc_msg = IbcMsg::SendPacket {
+            channel_id: channel_id.clone(),
+            data,
+            timeout: execute_ctx
+                .env
+                .block
+                .time
+                .plus_seconds(PACKET_LIFETIME)
+                .into(),
+        };
+        let res = Response::default().add_message(ibc_msg);
+        let sender = execute_ctx.info.sender.clone();
+        let packet_id = log_packet(
+            execute_ctx.deps.storage,
+            &execute_ctx.env,
+            &res,
+            sender.to_string(),
+            &sender,
+            vec![AndrAddr::from_string(format!(
+                "ibc://{chain}/{}",
+                channel_info.kernel_address
+            ))],
+        )?;
+        Ok(res
+            .add_attribute("packet_id", packet_id.to_string())
+            .add_attributes(vec![
+                attr("action", "execute_create"),
+                attr("ado_type", ado_type),
+                attr("owner", owner.to_string()),
+                attr("chain", chain),
+                attr("channel", channel_id),
+                attr("receiving_kernel_address", channel_info.kernel_address),
+                attr("msg", msg.to_string()),
+            ]))
     } else {
         let vfs_addr = KERNEL_ADDRESSES.load(execute_ctx.deps.storage, VFS_KEY)?;
         let adodb_addr = KERNEL_ADDRESSES.load(execute_ctx.deps.storage, ADO_DB_KEY)?;
@@ -169,8 +363,12 @@ pub fn create(
         let ado_owner = owner.unwrap_or(AndrAddr::from_string(execute_ctx.info.sender.to_string()));
         let owner_addr =
             ado_owner.get_raw_address_from_vfs(&execute_ctx.deps.as_ref(), vfs_addr)?;
-        let code_id =
-            AOSQuerier::code_id_getter(&execute_ctx.deps.querier, &adodb_addr, &ado_type)?;
+        let code_id = AOSQuerier::non_deprecated_code_id_getter(
+            &execute_ctx.deps.querier,
+            &adodb_addr,
+            &ado_type,
+            force.unwrap_or(false),
+        )?;
         let wasm_msg = WasmMsg::Instantiate {
             admin: Some(owner_addr.to_string()),
             code_id,
@@ -323,6 +521,157 @@ pub fn recover(execute_ctx: ExecuteContext) -> Result<Response, ContractError> {
         .add_submessage(sub_msg))
 }
 
+/// Receives one leg of a multi-coin transfer sent over IBC hooks
+///
+/// Received funds are held until every leg of the transfer has landed, at which point the message is relayed with the combined funds.
+pub fn receive_transfer_leg(
+    mut ctx: ExecuteContext,
+    message: AMPMsg,
+    leg: IbcHooksTransferLeg,
+) -> Result<Response, ContractError> {
+    // Each leg is delivered by a single ICS20 transfer
+    ensure!(
+        ctx.info.funds.len() == 1,
+        ContractError::InvalidFunds {
+            msg: "Transfer legs must carry exactly one coin".to_string(),
+        }
+    );
+    ensure!(
+        leg.legs > 0,
+        ContractError::InvalidPacket {
+            error: Some("Transfer must have at least one leg".to_string())
+        }
+    );
+
+    let sender = ctx.info.sender.clone();
+    let transfer_id = leg.transfer_id;
+    let mut transfer =
+        match INCOMING_IBC_HOOKS_TRANSFERS.may_load(ctx.deps.storage, (&sender, transfer_id))? {
+            Some(transfer) => {
+                // Every leg must describe the same transfer and message as the first, only the funds differ
+                ensure!(
+                    transfer.leg == leg
+                        && transfer.message.recipient == message.recipient
+                        && transfer.message.message == message.message
+                        && transfer.message.config == message.config,
+                    ContractError::InvalidPacket {
+                        error: Some(format!(
+                            "Leg does not match the recorded legs of transfer {transfer_id}"
+                        ))
+                    }
+                );
+                transfer
+            }
+            None => IncomingTransfer {
+                message,
+                leg,
+                received: 0,
+                funds: vec![],
+            },
+        };
+    let amount = ctx.info.funds[0].clone();
+    match transfer.funds.iter_mut().find(|c| c.denom == amount.denom) {
+        Some(coin) => coin.amount = coin.amount.checked_add(amount.amount)?,
+        None => transfer.funds.push(amount),
+    }
+    transfer.received += 1;
+
+    let attrs = vec![
+        attr("action", "receive_transfer_leg"),
+        attr("transfer_id", transfer_id.to_string()),
+        attr(
+            "legs_received",
+            format!("{}/{}", transfer.received, transfer.leg.legs),
+        ),
+    ];
+    if transfer.received < transfer.leg.legs {
+        INCOMING_IBC_HOOKS_TRANSFERS.save(ctx.deps.storage, (&sender, transfer_id), &transfer)?;
+        return Ok(Response::default().add_attributes(attrs));
+    }
+    INCOMING_IBC_HOOKS_TRANSFERS.remove(ctx.deps.storage, (&sender, transfer_id));
+
+    let mut message = transfer.message;
+    message.funds = transfer.funds;
+    // As with single-coin transfers the message is relayed on behalf of the authenticated ibc-hooks sender,
+    // the origin is never taken from the payload
+    let mut handler = MsgHandler(message);
+    let res = handler.handle(
+        ctx.deps.branch(),
+        ctx.info.clone(),
+        ctx.env.clone(),
+        None,
+        0,
+    )?;
+    Ok(track_relayed_msgs(
+        ctx.deps.storage,
+        &ctx.env,
+        res,
+        sender.to_string(),
+        &sender,
+        &[handler],
+    )?
+    .add_attributes(attrs))
+}
+
+/// Returns the funds of an expired, incomplete multi-coin transfer to its recovery address on the source chain
+///
+/// Legs that failed to arrive are recovered by the sending kernel, this returns the legs that did arrive.
+pub fn refund_transfer(
+    execute_ctx: ExecuteContext,
+    sender: String,
+    transfer_id: u64,
+) -> Result<Response, ContractError> {
+    let sender = execute_ctx.deps.api.addr_validate(&sender)?;
+    let Some(transfer) =
+        INCOMING_IBC_HOOKS_TRANSFERS.may_load(execute_ctx.deps.storage, (&sender, transfer_id))?
+    else {
+        return Err(ContractError::InvalidPacket {
+            error: Some(format!("Transfer {transfer_id} not found")),
+        });
+    };
+    ensure!(
+        execute_ctx.env.block.time > transfer.leg.expiry,
+        ContractError::InvalidPacket {
+            error: Some(format!("Transfer {transfer_id} has not expired")),
+        }
+    );
+    INCOMING_IBC_HOOKS_TRANSFERS.remove(execute_ctx.deps.storage, (&sender, transfer_id));
+
+    let IbcHooksTransferLeg {
+        source_chain,
+        recovery_addr,
+        ..
+    } = transfer.leg;
+    let Some(channel) = CHAIN_TO_CHANNEL
+        .may_load(execute_ctx.deps.storage, &source_chain)?
+        .and_then(|channel_info| channel_info.ics20_channel_id)
+    else {
+        return Err(ContractError::InvalidPacket {
+            error: Some(format!("Channel not found for chain {source_chain}")),
+        });
+    };
+
+    let timeout = execute_ctx.env.block.time.plus_seconds(PACKET_LIFETIME);
+    let msgs: Vec<IbcMsg> = transfer
+        .funds
+        .into_iter()
+        .map(|amount| IbcMsg::Transfer {
+            channel_id: channel.clone(),
+            to_address: recovery_addr.clone(),
+            amount,
+            timeout: timeout.into(),
+        })
+        .collect();
+
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "refund_transfer")
+        .add_attribute("transfer_id", transfer_id.to_string())
+        .add_attribute("chain", source_chain)
+        .add_attribute("channel", channel)
+        .add_attribute("recovery_addr", recovery_addr))
+}
+
 pub fn update_chain_name(
     execute_ctx: ExecuteContext,
     chain_name: String,
@@ -363,6 +712,24 @@ impl MsgHandler {
         self.clone()
     }
 
+    /// Whether the message is local and proceeds upon failure, in which case its failure is caught by the kernel
+    pub fn proceeds_on_error(&self) -> bool {
+        !self.message().config.exit_at_error
+            && self.message().recipient.get_protocol() != Some("ibc")
+    }
+
+    /// The reply ID and reply behaviour for the sub message relaying the message.
+    ///
+    /// Messages that proceed upon failure always reply so that their outcome can be recorded.
+    fn reply_config(&self) -> (u64, ReplyOn) {
+        let config = &self.message().config;
+        if config.exit_at_error {
+            (ReplyId::AMPMsgExitAtError.repr(), config.reply_on.clone())
+        } else {
+            (ReplyId::AMPMsg.repr(), ReplyOn::Always)
+        }
+    }
+
     #[inline]
     pub fn handle(
         &mut self,
@@ -404,7 +771,7 @@ impl MsgHandler {
         info: MessageInfo,
         _env: Env,
         ctx: Option<AMPCtx>,
-        sequence: u64,
+        _sequence: u64,
     ) -> Result<Response, ContractError> {
         let mut res = Response::default();
         let AMPMsg {
@@ -415,6 +782,7 @@ impl MsgHandler {
             ..
         } = self.message();
         let recipient_addr = recipient.get_raw_address(&deps.as_ref())?;
+        let (reply_id, reply_on) = self.reply_config();
 
         let adodb_addr = KERNEL_ADDRESSES.load(deps.storage, ADO_DB_KEY)?;
 
@@ -432,17 +800,22 @@ impl MsgHandler {
                 amount: funds.clone(),
             };
 
-            let mut attrs = vec![];
-            for (idx, fund) in funds.iter().enumerate() {
-                attrs.push(attr(format!("funds:{sequence}:{idx}"), fund.to_string()));
+            res = res.add_submessage(SubMsg {
+                id: reply_id,
+                msg: CosmosMsg::Bank(sub_msg),
+                gas_limit: config.gas_limit,
+                reply_on,
+            });
+            // A send that proceeds upon failure may be refunded, so its event is emitted by its reply once it succeeds
+            if !self.proceeds_on_error() {
+                res = res.add_event(
+                    AndrEvent::FundsSent {
+                        recipient: recipient_addr.to_string(),
+                        funds: funds.clone(),
+                    }
+                    .into(),
+                );
             }
-            attrs.push(attr(format!("recipient:{sequence}"), recipient_addr));
-            res = res
-                .add_submessage(SubMsg::reply_on_error(
-                    CosmosMsg::Bank(sub_msg),
-                    ReplyId::AMPMsg.repr(),
-                ))
-                .add_attributes(attrs);
         } else {
             let origin = if let Some(amp_ctx) = ctx {
                 amp_ctx.get_origin()
@@ -463,29 +836,25 @@ impl MsgHandler {
                     error: Some("Recipient is not a contract".to_string()),
                 })?;
 
-            let sub_msg = if config.direct
+            let mut sub_msg = if config.direct
                 || AOSQuerier::ado_type_getter(&deps.querier, &adodb_addr, recipient_code_id)?
                     .is_none()
             {
                 // Message is direct (no AMP Ctx)
                 self.message()
-                    .generate_sub_msg_direct(recipient_addr.clone(), ReplyId::AMPMsg.repr())
+                    .generate_sub_msg_direct(recipient_addr.clone(), reply_id)
             } else {
                 let amp_msg =
                     AMPMsg::new(recipient_addr.clone(), message.clone(), Some(funds.clone()));
 
                 let new_packet = AMPPkt::new(origin, previous_sender, vec![amp_msg]);
 
-                new_packet.to_sub_msg(
-                    recipient_addr.clone(),
-                    Some(funds.clone()),
-                    ReplyId::AMPMsg.repr(),
-                )?
+                new_packet.to_sub_msg(recipient_addr.clone(), Some(funds.clone()), reply_id)?
             };
+            sub_msg.reply_on = reply_on;
+            sub_msg.gas_limit = config.gas_limit;
 
-            res = res
-                .add_submessage(sub_msg)
-                .add_attributes(vec![attr(format!("recipient:{sequence}"), recipient_addr)]);
+            res = res.add_submessage(sub_msg);
         }
         Ok(res)
     }
@@ -594,7 +963,6 @@ impl MsgHandler {
                 error: Some(format!("Channel not found for chain {chain}")),
             });
         }?;
-        let msg_funds = &funds[0].clone();
         let recovery_addr = if let Some(IBCConfig {
             recovery_addr: Some(recovery_addr),
         }) = config.ibc_config.clone()
@@ -606,33 +974,62 @@ impl MsgHandler {
         } else {
             Ok::<Addr, ContractError>(info.sender)
         }?;
-        let outgoing_state = IBCHooksPacketSendState {
-            channel_id: channel.clone(),
-            amount: msg_funds.clone(),
-            recovery_addr,
+
+        // Multiple coins are sent as one ICS20 transfer per coin, grouped under a single transfer
+        let leg = if funds.len() > 1 {
+            let transfer_id = IBC_HOOKS_TRANSFER_ID
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            IBC_HOOKS_TRANSFER_ID.save(deps.storage, &(transfer_id + 1))?;
+            Some(IbcHooksTransferLeg {
+                transfer_id,
+                legs: funds.len() as u64,
+                source_chain: CURR_CHAIN.load(deps.storage)?,
+                recovery_addr: recovery_addr.to_string(),
+                expiry: env.block.time.plus_seconds(PACKET_LIFETIME),
+            })
+        } else {
+            None
         };
 
         let mut outgoing_packets = OUTGOING_IBC_HOOKS_PACKETS
             .load(deps.storage)
             .unwrap_or_default();
-        outgoing_packets.push(outgoing_state);
-        OUTGOING_IBC_HOOKS_PACKETS.save(deps.storage, &outgoing_packets)?;
+        let mut res = Response::default();
+        for coin in funds {
+            outgoing_packets.push(IBCHooksPacketSendState {
+                channel_id: channel.clone(),
+                amount: coin.clone(),
+                recovery_addr: recovery_addr.clone(),
+                transfer_id: leg.as_ref().map(|leg| leg.transfer_id),
+                packet_id: None,
+            });
 
-        let msg = generate_transfer_message(
-            &deps.as_ref(),
-            recipient.clone(),
-            message.clone(),
-            msg_funds.clone(),
-            channel.clone(),
-            env.contract.address.to_string(),
-            channel_info.kernel_address.clone(),
-            env.block.time,
-        )?;
-        Ok(Response::default()
-            .add_submessage(SubMsg::reply_always(
+            let msg = generate_transfer_message(
+                &deps.as_ref(),
+                recipient.clone(),
+                message.clone(),
+                coin.clone(),
+                channel.clone(),
+                env.contract.address.to_string(),
+                channel_info.kernel_address.clone(),
+                env.block.time,
+                leg.clone(),
+            )?;
+            res = res.add_submessage(SubMsg::reply_always(
                 msg,
                 ReplyId::IBCHooksPacketSend.repr(),
-            ))
+            ));
+        }
+        OUTGOING_IBC_HOOKS_PACKETS.save(deps.storage, &outgoing_packets)?;
+
+        if let Some(leg) = leg {
+            res = res.add_attribute(
+                format!("transfer_id:{sequence}"),
+                leg.transfer_id.to_string(),
+            );
+        }
+        Ok(res
             .add_attribute(format!("method:{sequence}"), "execute_send_message")
             .add_attribute(format!("channel:{sequence}"), channel)
             .add_attribute(
@@ -1,14 +1,23 @@
-use crate::ack::{make_ack_fail, make_ack_success};
+use crate::ack::{make_ack_fail, make_ack_success, parse_create_ado_ack, Ack};
 use crate::execute;
 use crate::proto::{DenomTrace, MsgTransfer, QueryDenomTraceRequest};
-use crate::state::{CHANNEL_TO_CHAIN, KERNEL_ADDRESSES};
+use crate::state::{
+    update_packet_hop, ADO_CREATE_IBC, CHANNEL_TO_CHAIN, CROSS_CHAIN_CREATORS, DIRECT_PACKET_IDS,
+    KERNEL_ADDRESSES,
+};
+use crate::sudo::ibc_lifecycle::recover_outgoing_packet;
 use andromeda_std::amp::VFS_KEY;
 use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::common::reply::ReplyId;
 use andromeda_std::error::{ContractError, Never};
 use andromeda_std::{
     amp::{messages::AMPMsg, AndrAddr},
-    os::{kernel::IbcExecuteMsg, vfs::ExecuteMsg as VFSExecuteMsg},
+    os::{
+        kernel::{
+            CreateADOAck, CreateCallbackMsg, IbcExecuteMsg, IbcHooksTransferLeg, PacketStatus,
+        },
+        vfs::ExecuteMsg as VFSExecuteMsg,
+    },
 };
 use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
@@ -16,8 +25,9 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     ensure, from_json, to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Empty, Env,
     Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
-    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg,
-    IbcPacketTimeoutMsg, IbcReceiveResponse, MessageInfo, SubMsg, Timestamp, WasmMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacket, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, MessageInfo, Storage, SubMsg,
+    Timestamp, WasmMsg,
 };
 use itertools::Itertools;
 use sha256::digest;
@@ -46,15 +56,32 @@ pub enum SudoMsg {
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn ibc_packet_timeout(
-    _deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
-    _msg: IbcPacketTimeoutMsg,
+    msg: IbcPacketTimeoutMsg,
 ) -> Result<IbcBasicResponse, ContractError> {
-    // As with ack above, nothing to do here. If we cared about
-    // keeping track of state between the two chains then we'd want to
-    // respond to this likely as it means that the packet in question
-    // isn't going anywhere.
-    Ok(IbcBasicResponse::new().add_attribute("method", "ibc_packet_timeout"))
+    let mut res = IbcBasicResponse::new().add_attribute("method", "ibc_packet_timeout");
+    update_direct_packet(deps.storage, &msg.packet, PacketStatus::TimedOut, None)?;
+    if let Ok(IbcExecuteMsg::CreateADO { ado_type, .. }) = from_json(&msg.packet.data) {
+        let callback = cross_chain_create_callback(
+            deps.branch(),
+            &msg.packet,
+            ado_type,
+            Err("Packet timed out".to_string()),
+        )?;
+        res = res.add_submessages(callback);
+    }
+    // Any funds attached to the timed out packet are made available for recovery
+    let recovery = recover_outgoing_packet(
+        deps.storage,
+        &msg.packet.src.channel_id,
+        msg.packet.sequence,
+        "timeout",
+    )?;
+    Ok(match recovery {
+        Some(event) => res.add_event(event),
+        None => res,
+    })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -114,11 +141,178 @@ pub fn ibc_packet_receive(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn ibc_packet_ack(
-    _deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
-    _msg: IbcPacketAckMsg,
+    msg: IbcPacketAckMsg,
 ) -> Result<IbcBasicResponse, ContractError> {
-    Ok(IbcBasicResponse::new())
+    // Packets that are not kernel messages, such as ICS20 transfers, are only logged
+    let packet_msg: Option<IbcExecuteMsg> = from_json(&msg.original_packet.data).ok();
+    let ack: Ack = from_json(&msg.acknowledgement.data)?;
+    let res = match packet_msg {
+        Some(IbcExecuteMsg::CreateADO {
+            ado_type, owner, ..
+        }) => {
+            let result = match &ack {
+                Ack::Result(data) => Ok(parse_create_ado_ack(data)?),
+                Ack::Error(error) => Err(error.clone()),
+            };
+            let callback = cross_chain_create_callback(
+                deps.branch(),
+                &msg.original_packet,
+                ado_type.clone(),
+                result,
+            )?;
+            ibc_create_ado_ack(
+                ack.clone(),
+                ado_type,
+                owner,
+                msg.original_packet.src.channel_id.clone(),
+            )?
+            .add_submessages(callback)
+        }
+        _ => IbcBasicResponse::new().add_attribute("method", "ibc_packet_ack"),
+    };
+    match &ack {
+        Ack::Result(_) => update_direct_packet(
+            deps.storage,
+            &msg.original_packet,
+            PacketStatus::Succeeded,
+            None,
+        )?,
+        Ack::Error(error) => update_direct_packet(
+            deps.storage,
+            &msg.original_packet,
+            PacketStatus::Failed,
+            Some(error.clone()),
+        )?,
+    }
+
+    // Any funds attached to a failed packet are made available for recovery
+    if let Ack::Error(_) = ack {
+        let recovery = recover_outgoing_packet(
+            deps.storage,
+            &msg.original_packet.src.channel_id,
+            msg.original_packet.sequence,
+            "ack_error",
+        )?;
+        if let Some(event) = recovery {
+            return Ok(res.add_event(event));
+        }
+    }
+
+    Ok(res)
+}
+
+/// Hashes the data of a packet sent over the direct channel, used to match acknowledgements to logged packets
+pub fn packet_data_hash(data: &Binary) -> String {
+    digest(data.as_slice())
+}
+
+/// Updates the logged packet that sent the given packet over the direct channel, if any
+fn update_direct_packet(
+    storage: &mut dyn Storage,
+    packet: &IbcPacket,
+    status: PacketStatus,
+    error: Option<String>,
+) -> Result<(), ContractError> {
+    let hash = packet_data_hash(&packet.data);
+    let key = (packet.src.channel_id.as_str(), hash.as_str());
+    let mut ids = DIRECT_PACKET_IDS
+        .may_load(storage, key)?
+        .unwrap_or_default();
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let id = ids.remove(0);
+    if ids.is_empty() {
+        DIRECT_PACKET_IDS.remove(storage, key);
+    } else {
+        DIRECT_PACKET_IDS.save(storage, key, &ids)?;
+    }
+    update_packet_hop(
+        storage,
+        id,
+        &packet.src.channel_id,
+        packet.sequence,
+        status,
+        error,
+    )
+}
+
+/// Handles the acknowledgement of a cross-chain ADO creation, reporting the address of the new ADO
+pub fn ibc_create_ado_ack(
+    ack: Ack,
+    ado_type: String,
+    owner: AndrAddr,
+    channel: String,
+) -> Result<IbcBasicResponse, ContractError> {
+    let res = IbcBasicResponse::new()
+        .add_attribute("method", "ibc_create_ado_ack")
+        .add_attribute("ado_type", ado_type)
+        .add_attribute("owner", owner.to_string())
+        .add_attribute("channel", channel);
+    match ack {
+        Ack::Result(data) => {
+            let ack = parse_create_ado_ack(&data)?;
+            Ok(res
+                .add_attribute("success", "true")
+                .add_attribute("ado_address", ack.address))
+        }
+        Ack::Error(error) => Ok(res
+            .add_attribute("success", "false")
+            .add_attribute("error", error)),
+    }
+}
+
+/// Notifies the contract that requested a cross-chain ADO creation of its result.
+///
+/// Callbacks are only sent to contracts and a failing callback does not revert the acknowledgement.
+fn cross_chain_create_callback(
+    deps: DepsMut,
+    packet: &IbcPacket,
+    ado_type: String,
+    result: Result<CreateADOAck, String>,
+) -> Result<Option<SubMsg>, ContractError> {
+    let hash = packet_data_hash(&packet.data);
+    let key = (packet.src.channel_id.as_str(), hash.as_str());
+    let mut creators = CROSS_CHAIN_CREATORS
+        .may_load(deps.storage, key)?
+        .unwrap_or_default();
+    if creators.is_empty() {
+        return Ok(None);
+    }
+    let creator = creators.remove(0);
+    if creators.is_empty() {
+        CROSS_CHAIN_CREATORS.remove(deps.storage, key);
+    } else {
+        CROSS_CHAIN_CREATORS.save(deps.storage, key, &creators)?;
+    }
+
+    if deps.querier.query_wasm_contract_info(&creator).is_err() {
+        return Ok(None);
+    }
+    let chain = CHANNEL_TO_CHAIN
+        .may_load(deps.storage, &packet.src.channel_id)?
+        .unwrap_or_default();
+    let (address, components, error) = match result {
+        Ok(ack) => (Some(ack.address), ack.components, None),
+        Err(error) => (None, vec![], Some(error)),
+    };
+    let callback = CreateCallbackMsg::CrossChainCreateAck {
+        chain,
+        ado_type,
+        address,
+        components,
+        error,
+    };
+    Ok(Some(SubMsg::reply_on_error(
+        WasmMsg::Execute {
+            contract_addr: creator.to_string(),
+            msg: to_json_binary(&callback)?,
+            funds: vec![],
+        },
+        ReplyId::CrossChainCreateCallback.repr(),
+    )))
 }
 
 pub fn do_ibc_packet_receive(
@@ -156,7 +350,8 @@ pub fn do_ibc_packet_receive(
             instantiation_msg,
             owner,
             ado_type,
-        } => ibc_create_ado(execute_env, owner, ado_type, instantiation_msg),
+            force,
+        } => ibc_create_ado(execute_env, owner, ado_type, instantiation_msg, force),
         IbcExecuteMsg::RegisterUsername { username, address } => {
             ibc_register_username(execute_env, username, address)
         }
@@ -164,18 +359,20 @@ pub fn do_ibc_packet_receive(
 }
 
 pub fn ibc_create_ado(
-    _execute_ctx: ExecuteContext,
-    _owner: AndrAddr,
-    _ado_type: String,
-    _msg: Binary,
+    execute_ctx: ExecuteContext,
+    owner: AndrAddr,
+    ado_type: String,
+    msg: Binary,
+    force: Option<bool>,
 ) -> Result<IbcReceiveResponse, ContractError> {
-    Err(ContractError::CrossChainComponentsCurrentlyDisabled {})
-    // let res = execute::create(execute_env, ado_type, msg, Some(owner), None)?;
-    // Ok(IbcReceiveResponse::new()
-    //     .add_attributes(res.attributes)
-    //     .add_events(res.events)
-    //     .add_submessages(res.messages)
-    //     .set_ack(make_ack_create_ado_success()))
+    // The acknowledgement is overwritten with the new ADO's address once it has been instantiated
+    ADO_CREATE_IBC.save(execute_ctx.deps.storage, &true)?;
+    let res = execute::create(execute_ctx, ado_type, msg, Some(owner), None, force)?;
+    Ok(IbcReceiveResponse::new()
+        .add_attributes(res.attributes)
+        .add_events(res.events)
+        .add_submessages(res.messages)
+        .set_ack(make_ack_success()))
 }
 
 pub fn ibc_register_username(
@@ -261,6 +458,7 @@ pub fn generate_transfer_message(
     from_addr: String,
     to_addr: String,
     time: Timestamp,
+    leg: Option<IbcHooksTransferLeg>,
 ) -> Result<MsgTransfer, ContractError> {
     // Convert funds denom
     let new_denom = if funds.denom.starts_with("ibc/") {
@@ -280,7 +478,10 @@ pub fn generate_transfer_message(
     };
     let new_coin = Coin::new(funds.amount.u128(), new_denom);
     let msg = AMPMsg::new(recipient.get_raw_path(), message, Some(vec![new_coin]));
-    let serialized = msg.to_ibc_hooks_memo(to_addr.clone(), from_addr.clone());
+    let serialized = match leg {
+        Some(leg) => msg.to_ibc_hooks_leg_memo(to_addr.clone(), from_addr.clone(), leg),
+        None => msg.to_ibc_hooks_memo(to_addr.clone(), from_addr.clone()),
+    };
 
     let ts = time.plus_seconds(PACKET_LIFETIME);
 
@@ -42,6 +42,7 @@ pub fn mock_create(
         msg: to_json_binary(&msg).unwrap(),
         owner,
         chain,
+        force: None,
     }
 }
 
@@ -3,12 +3,16 @@ use andromeda_std::{
     error::ContractError,
     os::{
         aos_querier::AOSQuerier,
-        kernel::{ChainNameResponse, ChannelInfoResponse, VerifyAddressResponse},
+        kernel::{ChainNameResponse, ChannelInfoResponse, PacketInfo, VerifyAddressResponse},
     },
 };
-use cosmwasm_std::{Addr, Coin, Deps};
+use cosmwasm_std::{Addr, Coin, Deps, Order};
+use cw_storage_plus::Bound;
 
-use crate::state::{CHAIN_TO_CHANNEL, CURR_CHAIN, IBC_FUND_RECOVERY, KERNEL_ADDRESSES};
+use crate::state::{packets, CHAIN_TO_CHANNEL, CURR_CHAIN, IBC_FUND_RECOVERY, KERNEL_ADDRESSES};
+
+const DEFAULT_LIMIT: u32 = 10u32;
+const MAX_LIMIT: u32 = 30u32;
 
 pub fn key_address(deps: Deps, key: String) -> Result<Addr, ContractError> {
     Ok(KERNEL_ADDRESSES.load(deps.storage, &key)?)
@@ -61,3 +65,27 @@ pub fn chain_name(deps: Deps) -> Result<ChainNameResponse, ContractError> {
         chain_name: CURR_CHAIN.may_load(deps.storage)?.unwrap_or_default(),
     })
 }
+
+pub fn packet_status(deps: Deps, id: u64) -> Result<PacketInfo, ContractError> {
+    Ok(packets().load(deps.storage, id)?)
+}
+
+pub fn packets_by_origin(
+    deps: Deps,
+    origin: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<PacketInfo>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let packets: Result<Vec<PacketInfo>, ContractError> = packets()
+        .idx
+        .origin
+        .prefix(origin)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| Ok(r?.1))
+        .collect();
+    packets
+}
@@ -1,22 +1,40 @@
 use crate::{
+    ack::make_ack_create_ado_success,
     proto::MsgTransferResponse,
     state::{
-        IBCHooksPacketSendState, OutgoingPacket, ADO_OWNER, OUTGOING_IBC_HOOKS_PACKETS,
+        fail_packet, update_packet_hop, IBCHooksPacketSendState, OutgoingPacket, PendingAMPMsg,
+        ADO_CREATE_IBC, ADO_OWNER, AMP_MSG_REPLY_FRAMES, OUTGOING_IBC_HOOKS_PACKETS,
         OUTGOING_IBC_PACKETS,
     },
 };
 use andromeda_std::{
     ado_base::{ownership::OwnershipMessage, AndromedaMsg},
     common::reply::ReplyId,
-    common::response::get_reply_address,
+    common::response::{get_reply_address, AndrEvent},
     error::ContractError,
-    os::aos_querier::AOSQuerier,
+    os::{
+        aos_querier::AOSQuerier,
+        kernel::{AMPPktResponse, PacketStatus},
+    },
 };
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    ensure, wasm_execute, Addr, DepsMut, Empty, Env, Reply, Response, SubMsg, SubMsgResponse,
-    SubMsgResult,
+    ensure, to_json_binary, wasm_execute, Addr, BankMsg, DepsMut, Empty, Env, Reply, Response,
+    SubMsg, SubMsgResponse, SubMsgResult,
 };
 
+/// Query of an app contract listing the address of each of its components
+#[cw_serde]
+enum AppQueryMsg {
+    GetAddressesWithNames {},
+}
+
+#[cw_serde]
+struct ComponentAddress {
+    name: String,
+    address: String,
+}
+
 /// Handles the reply from an ADO creation
 ///
 /// Sends an execute message to assign the new owner to the ADO
@@ -27,19 +45,34 @@ pub fn on_reply_create_ado(deps: DepsMut, env: Env, msg: Reply) -> Result<Respon
     let curr_owner =
         AOSQuerier::ado_owner_getter(&deps.querier, &Addr::unchecked(ado_addr.clone()))?;
     let mut res = Response::default();
+    // ADOs created on behalf of another chain report their address back via the acknowledgement,
+    // along with the addresses of their components when they are apps
+    if ADO_CREATE_IBC.may_load(deps.storage)?.unwrap_or(false) {
+        ADO_CREATE_IBC.remove(deps.storage);
+        let components: Vec<ComponentAddress> = deps
+            .querier
+            .query_wasm_smart(&ado_addr, &AppQueryMsg::GetAddressesWithNames {})
+            .unwrap_or_default();
+        res = res.set_data(make_ack_create_ado_success(
+            Addr::unchecked(ado_addr.clone()),
+            components
+                .into_iter()
+                .map(|component| (component.name, component.address))
+                .collect(),
+        ));
+    }
     if curr_owner == env.contract.address {
         let msg = AndromedaMsg::Ownership(OwnershipMessage::UpdateOwner {
             new_owner,
             expiration: None,
         });
-        let wasm_msg = wasm_execute(ado_addr, &msg, vec![])?;
+        let wasm_msg = wasm_execute(ado_addr.clone(), &msg, vec![])?;
         let sub_msg: SubMsg<Empty> =
             SubMsg::reply_on_success(wasm_msg, ReplyId::UpdateOwnership as u64);
         res = res.add_submessage(sub_msg);
     }
 
-    Ok(res)
-    // .set_data(to_json_binary(&ado_addr)?)
+    Ok(res.add_attribute("ado_address", ado_addr))
 }
 
 use ::prost::Message;
@@ -75,6 +108,8 @@ pub fn on_reply_ibc_hooks_packet_send(
         channel_id,
         recovery_addr,
         amount,
+        transfer_id,
+        packet_id,
     } = outgoing_packets.remove(0);
 
     OUTGOING_IBC_HOOKS_PACKETS.save(deps.storage, &outgoing_packets)?;
@@ -84,12 +119,91 @@ pub fn on_reply_ibc_hooks_packet_send(
         &OutgoingPacket {
             recovery_addr: recovery_addr.clone(),
             amount,
+            transfer_id,
+            packet_id,
         },
     )?;
+    if let Some(packet_id) = packet_id {
+        update_packet_hop(
+            deps.storage,
+            packet_id,
+            &channel_id,
+            sequence,
+            PacketStatus::Pending,
+            None,
+        )?;
+    }
 
-    Ok(Response::default()
+    let mut res = Response::default()
         .add_attribute("action", "ibc_hooks_packet_send")
         .add_attribute("channel_id", channel_id)
         .add_attribute("sequence", sequence.to_string())
-        .add_attribute("recovery_addr", recovery_addr))
+        .add_attribute("recovery_addr", recovery_addr);
+    if let Some(transfer_id) = transfer_id {
+        res = res.add_attribute("transfer_id", transfer_id.to_string());
+    }
+    Ok(res)
+}
+
+/// Handles the reply from relaying a message that proceeds upon failure
+///
+/// Records the outcome of the message and returns any attached funds to the sender should it have failed, or emits `FundsSent` for a successful bank send.
+/// The response data is set to the updated summary of the relayed messages.
+pub fn on_reply_amp_msg(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let mut frames = AMP_MSG_REPLY_FRAMES
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let Some(mut frame) = frames.pop() else {
+        return Err(ContractError::InvalidPacket {
+            error: Some("No pending AMP messages".to_string()),
+        });
+    };
+    ensure!(
+        !frame.pending.is_empty(),
+        ContractError::InvalidPacket {
+            error: Some("No pending AMP messages".to_string()),
+        }
+    );
+    let PendingAMPMsg {
+        index,
+        refund_addr,
+        funds,
+        funds_recipient,
+    } = frame.pending.remove(0);
+
+    let mut res = Response::default().add_attribute("action", "handle_amp_msg_reply");
+    if let SubMsgResult::Err(error) = msg.result {
+        if let Some(result) = frame.results.iter_mut().find(|r| r.index == index) {
+            result.success = false;
+            result.error = Some(error.clone());
+        }
+        if !funds.is_empty() {
+            res = res.add_message(BankMsg::Send {
+                to_address: refund_addr.to_string(),
+                amount: funds,
+            });
+        }
+        fail_packet(deps.storage, frame.packet_id, format!("{index}: {error}"))?;
+        res = res.add_attribute(format!("error:{index}"), error);
+    } else if let Some(recipient) = funds_recipient {
+        let recipient = recipient.get_raw_address(&deps.as_ref())?;
+        res = res.add_event(
+            AndrEvent::FundsSent {
+                recipient: recipient.to_string(),
+                funds,
+            }
+            .into(),
+        );
+    }
+
+    let data = to_json_binary(&AMPPktResponse {
+        packet_id: frame.packet_id,
+        results: frame.results.clone(),
+    })?;
+    if !frame.pending.is_empty() {
+        frames.push(frame);
+    }
+    AMP_MSG_REPLY_FRAMES.save(deps.storage, &frames)?;
+
+    Ok(res.set_data(data))
 }
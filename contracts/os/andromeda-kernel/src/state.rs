@@ -1,19 +1,65 @@
-use andromeda_std::os::kernel::ChannelInfo;
+use andromeda_std::{
+    amp::{messages::AMPMsg, AndrAddr},
+    error::ContractError,
+    os::kernel::{
+        AMPMsgResult, ChannelInfo, IbcHooksTransferLeg, PacketHop, PacketInfo, PacketStatus,
+    },
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Coin, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 #[cw_serde]
 pub struct IBCHooksPacketSendState {
     pub channel_id: String,
     pub recovery_addr: Addr,
     pub amount: Coin,
+    /// The multi-coin transfer the packet is a leg of, if any
+    pub transfer_id: Option<u64>,
+    /// The logged packet the transfer was sent for
+    pub packet_id: Option<u64>,
 }
 
 #[cw_serde]
 pub struct OutgoingPacket {
     pub recovery_addr: Addr,
     pub amount: Coin,
+    /// The multi-coin transfer the packet is a leg of, if any
+    pub transfer_id: Option<u64>,
+    /// The logged packet the transfer was sent for
+    pub packet_id: Option<u64>,
+}
+
+#[cw_serde]
+pub struct IncomingTransfer {
+    /// The message to relay once all legs have been received
+    pub message: AMPMsg,
+    pub leg: IbcHooksTransferLeg,
+    /// The number of legs received so far
+    pub received: u64,
+    /// The funds received so far
+    pub funds: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct PendingAMPMsg {
+    /// The index of the message within its packet
+    pub index: u64,
+    /// The address any attached funds are returned to should the message fail
+    pub refund_addr: Addr,
+    pub funds: Vec<Coin>,
+    /// The recipient of the funds should the message be a bank send, emitted in a `FundsSent` event once the send succeeds
+    pub funds_recipient: Option<AndrAddr>,
+}
+
+#[cw_serde]
+pub struct AMPMsgReplyFrame {
+    /// The logged packet the messages were relayed for
+    pub packet_id: u64,
+    /// Messages awaiting a reply, in the order they were sent
+    pub pending: Vec<PendingAMPMsg>,
+    /// The outcome of every message relayed by the execution
+    pub results: Vec<AMPMsgResult>,
 }
 
 pub const KERNEL_ADDRESSES: Map<&str, Addr> = Map::new("kernel_addresses");
@@ -22,6 +68,8 @@ pub const CURR_CHAIN: Item<String> = Item::new("kernel_curr_chain");
 
 //Temporary storage for creating a new ADO to assign a new owner
 pub const ADO_OWNER: Item<Addr> = Item::new("ado_owner");
+//Temporary storage to mark an ADO creation as requested by a kernel on another chain
+pub const ADO_CREATE_IBC: Item<bool> = Item::new("ado_create_ibc");
 
 // Mapping from chain name to channel info
 pub const CHAIN_TO_CHANNEL: Map<&str, ChannelInfo> = Map::new("kernel_channels");
@@ -36,3 +84,154 @@ pub const OUTGOING_IBC_HOOKS_PACKETS: Item<Vec<IBCHooksPacketSendState>> =
 pub const OUTGOING_IBC_PACKETS: Map<(&String, u64), OutgoingPacket> =
     Map::new("outgoing_ibc_packets");
 pub const IBC_FUND_RECOVERY: Map<&Addr, Vec<Coin>> = Map::new("ibc_fund_recovery");
+
+/// The identifier of the next multi-coin transfer sent over IBC hooks
+pub const IBC_HOOKS_TRANSFER_ID: Item<u64> = Item::new("ibc_hooks_transfer_id");
+/// Multi-coin transfers awaiting their remaining legs, keyed by the IBC hooks sender and transfer identifier
+pub const INCOMING_IBC_HOOKS_TRANSFERS: Map<(&Addr, u64), IncomingTransfer> =
+    Map::new("incoming_ibc_hooks_transfers");
+
+/// Stack of frames for messages that proceed upon failure, one frame per kernel execution relaying such messages
+///
+/// A frame is popped once all of its pending messages have replied
+pub const AMP_MSG_REPLY_FRAMES: Item<Vec<AMPMsgReplyFrame>> = Item::new("amp_msg_reply_frames");
+
+/// The maximum number of relayed packets kept in the packet log, older packets are pruned
+pub const MAX_PACKET_HISTORY: u64 = 1000;
+/// The identifier of the most recently logged packet
+pub const PACKET_ID: Item<u64> = Item::new("packet_id");
+/// Logged packets awaiting acknowledgement over the direct channel, keyed by channel and packet data hash
+pub const DIRECT_PACKET_IDS: Map<(&str, &str), Vec<u64>> = Map::new("direct_packet_ids");
+/// The channel and packet data hash of each direct hop made by a logged packet, used to prune `DIRECT_PACKET_IDS` along with the packet
+pub const DIRECT_PACKET_KEYS: Map<u64, Vec<(String, String)>> = Map::new("direct_packet_keys");
+/// Contracts awaiting the result of a cross-chain ADO creation, keyed by channel and packet data hash
+pub const CROSS_CHAIN_CREATORS: Map<(&str, &str), Vec<Addr>> = Map::new("cross_chain_creators");
+
+pub struct PacketIndexes<'a> {
+    pub origin: MultiIndex<'a, String, PacketInfo, u64>,
+}
+
+impl<'a> IndexList<PacketInfo> for PacketIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PacketInfo>> + '_> {
+        let v: Vec<&dyn Index<PacketInfo>> = vec![&self.origin];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn packets<'a>() -> IndexedMap<'a, u64, PacketInfo, PacketIndexes<'a>> {
+    let indexes = PacketIndexes {
+        origin: MultiIndex::new(|_pk: &[u8], p| p.origin.clone(), "packets", "packet_origin"),
+    };
+    IndexedMap::new("packets", indexes)
+}
+
+/// Assigns the next packet identifier
+pub fn next_packet_id(storage: &mut dyn Storage) -> Result<u64, ContractError> {
+    let id = PACKET_ID.may_load(storage)?.unwrap_or_default() + 1;
+    PACKET_ID.save(storage, &id)?;
+    Ok(id)
+}
+
+/// Logs the given packet, pruning the oldest packet should the log be full
+pub fn save_packet(storage: &mut dyn Storage, mut packet: PacketInfo) -> Result<(), ContractError> {
+    if packet.id > MAX_PACKET_HISTORY {
+        let pruned_id = packet.id - MAX_PACKET_HISTORY;
+        packets().remove(storage, pruned_id)?;
+        prune_direct_packet_ids(storage, pruned_id)?;
+    }
+    packet.status = packet_status(&packet);
+    packets().save(storage, packet.id, &packet)?;
+    Ok(())
+}
+
+/// Stops tracking the direct hops of a pruned packet that were never acknowledged or timed out
+fn prune_direct_packet_ids(storage: &mut dyn Storage, id: u64) -> Result<(), ContractError> {
+    let keys = DIRECT_PACKET_KEYS
+        .may_load(storage, id)?
+        .unwrap_or_default();
+    DIRECT_PACKET_KEYS.remove(storage, id);
+    for (channel, hash) in keys {
+        let key = (channel.as_str(), hash.as_str());
+        let Some(mut ids) = DIRECT_PACKET_IDS.may_load(storage, key)? else {
+            continue;
+        };
+        ids.retain(|packet_id| *packet_id != id);
+        if ids.is_empty() {
+            DIRECT_PACKET_IDS.remove(storage, key);
+        } else {
+            DIRECT_PACKET_IDS.save(storage, key, &ids)?;
+        }
+    }
+    Ok(())
+}
+
+/// Records a failure for the given packet, does nothing if the packet has been pruned
+pub fn fail_packet(storage: &mut dyn Storage, id: u64, error: String) -> Result<(), ContractError> {
+    let Some(mut packet) = packets().may_load(storage, id)? else {
+        return Ok(());
+    };
+    packet.error = Some(error);
+    packet.status = packet_status(&packet);
+    packets().save(storage, id, &packet)?;
+    Ok(())
+}
+
+/// Updates the hop of the given packet over the given channel, does nothing if the packet has been pruned
+///
+/// Hops are matched by sequence, falling back to the first hop over the channel without a known sequence.
+pub fn update_packet_hop(
+    storage: &mut dyn Storage,
+    id: u64,
+    channel: &str,
+    sequence: u64,
+    status: PacketStatus,
+    error: Option<String>,
+) -> Result<(), ContractError> {
+    let Some(mut packet) = packets().may_load(storage, id)? else {
+        return Ok(());
+    };
+    let hop = match packet
+        .hops
+        .iter()
+        .position(|hop| hop.channel == channel && hop.sequence == Some(sequence))
+    {
+        Some(idx) => Some(idx),
+        None => packet
+            .hops
+            .iter()
+            .position(|hop| hop.channel == channel && hop.sequence.is_none()),
+    };
+    if let Some(idx) = hop {
+        packet.hops[idx] = PacketHop {
+            channel: channel.to_string(),
+            sequence: Some(sequence),
+            status,
+        };
+    }
+    if error.is_some() {
+        packet.error = error;
+    }
+    packet.status = packet_status(&packet);
+    packets().save(storage, id, &packet)?;
+    Ok(())
+}
+
+fn packet_status(packet: &PacketInfo) -> PacketStatus {
+    if packet
+        .hops
+        .iter()
+        .any(|hop| hop.status == PacketStatus::TimedOut)
+    {
+        PacketStatus::TimedOut
+    } else if packet.error.is_some() {
+        PacketStatus::Failed
+    } else if packet
+        .hops
+        .iter()
+        .any(|hop| hop.status == PacketStatus::Pending)
+    {
+        PacketStatus::Pending
+    } else {
+        PacketStatus::Succeeded
+    }
+}
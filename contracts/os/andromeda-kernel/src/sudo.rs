@@ -4,9 +4,12 @@ use cosmwasm_std::{DepsMut, Response};
 pub mod ibc_lifecycle {
     // As with most IBC Hooks methods these were adapted from:
     // https://github.com/osmosis-labs/osmosis/blob/main/cosmwasm/contracts/crosschain-swaps/src/ibc_lifecycle.rs
-    use cosmwasm_std::Coin;
+    use andromeda_std::os::kernel::PacketStatus;
+    use cosmwasm_std::{Coin, Event, Storage};
 
-    use crate::state::{OutgoingPacket, IBC_FUND_RECOVERY, OUTGOING_IBC_PACKETS};
+    use crate::state::{
+        update_packet_hop, OutgoingPacket, IBC_FUND_RECOVERY, OUTGOING_IBC_PACKETS,
+    };
 
     use super::*;
 
@@ -14,39 +17,48 @@ pub mod ibc_lifecycle {
         deps: DepsMut,
         source_channel: String,
         sequence: u64,
-        _ack: String,
+        ack: String,
         success: bool,
     ) -> Result<Response, ContractError> {
         let response = Response::new().add_attribute("action", "receive_ack");
 
         // Check if there is an inflight packet for the received (channel, sequence)
-        let sent_packet =
-            OUTGOING_IBC_PACKETS.may_load(deps.storage, (&source_channel, sequence))?;
-        let Some(inflight_packet) = sent_packet else {
+        let Some(inflight_packet) =
+            OUTGOING_IBC_PACKETS.may_load(deps.storage, (&source_channel, sequence))?
+        else {
             // If there isn't, continue
             return Ok(response.add_attribute("msg", "received unexpected ack"));
         };
-        OUTGOING_IBC_PACKETS.remove(deps.storage, (&source_channel, sequence));
+
+        if let Some(packet_id) = inflight_packet.packet_id {
+            let (status, error) = if success {
+                (PacketStatus::Succeeded, None)
+            } else {
+                (PacketStatus::Failed, Some(ack.clone()))
+            };
+            update_packet_hop(
+                deps.storage,
+                packet_id,
+                &source_channel,
+                sequence,
+                status,
+                error,
+            )?;
+        }
 
         if success {
             // If the ack was successful, continue
+            OUTGOING_IBC_PACKETS.remove(deps.storage, (&source_channel, sequence));
             return Ok(response.add_attribute("msg", "received successful ack"));
         };
 
-        let OutgoingPacket {
-            recovery_addr,
-            amount,
-        } = inflight_packet;
-        IBC_FUND_RECOVERY.update(deps.storage, &recovery_addr, |cur_amount_opt| {
-            let mut recoveries = cur_amount_opt.unwrap_or_default();
-            recoveries.push(amount.clone());
-            Ok::<Vec<Coin>, ContractError>(recoveries)
-        })?;
-
-        Ok(response
-            .add_attribute("msg", "msg failed")
-            .add_attribute("recovery_addr", recovery_addr)
-            .add_attribute("recovery_amount", amount.to_string()))
+        let recovery =
+            recover_outgoing_packet(deps.storage, &source_channel, sequence, "ack_error")?;
+        let response = response.add_attribute("msg", "msg failed");
+        Ok(match recovery {
+            Some(event) => response.add_event(event),
+            None => response,
+        })
     }
 
     pub fn receive_timeout(
@@ -56,28 +68,71 @@ pub mod ibc_lifecycle {
     ) -> Result<Response, ContractError> {
         let response = Response::new().add_attribute("action", "receive_timeout");
 
-        // Check if there is an inflight packet for the received (channel, sequence)
-        let sent_packet =
-            OUTGOING_IBC_PACKETS.may_load(deps.storage, (&source_channel, sequence))?;
-        let Some(inflight_packet) = sent_packet else {
-            // If there isn't, continue
-            return Ok(response.add_attribute("msg", "received unexpected timeout"));
+        if let Some(OutgoingPacket {
+            packet_id: Some(packet_id),
+            ..
+        }) = OUTGOING_IBC_PACKETS.may_load(deps.storage, (&source_channel, sequence))?
+        {
+            update_packet_hop(
+                deps.storage,
+                packet_id,
+                &source_channel,
+                sequence,
+                PacketStatus::TimedOut,
+                None,
+            )?;
+        }
+
+        let recovery = recover_outgoing_packet(deps.storage, &source_channel, sequence, "timeout")?;
+        Ok(match recovery {
+            Some(event) => response.add_event(event),
+            // If there isn't an inflight packet for the received (channel, sequence), continue
+            None => response.add_attribute("msg", "received unexpected timeout"),
+        })
+    }
+
+    /// Removes the in-flight packet for the given `(channel, sequence)` and credits its funds to the recovery address.
+    ///
+    /// Returns an `ibc_fund_recovery` event describing the recovery, or `None` if no in-flight packet was found.
+    pub fn recover_outgoing_packet(
+        storage: &mut dyn Storage,
+        source_channel: &String,
+        sequence: u64,
+        reason: &str,
+    ) -> Result<Option<Event>, ContractError> {
+        let Some(inflight_packet) =
+            OUTGOING_IBC_PACKETS.may_load(storage, (source_channel, sequence))?
+        else {
+            return Ok(None);
         };
         // Remove the in-flight packet
-        OUTGOING_IBC_PACKETS.remove(deps.storage, (&source_channel, sequence));
+        OUTGOING_IBC_PACKETS.remove(storage, (source_channel, sequence));
 
         let OutgoingPacket {
             recovery_addr,
             amount,
+            transfer_id,
+            ..
         } = inflight_packet;
-        IBC_FUND_RECOVERY.update(deps.storage, &recovery_addr, |cur_amount_opt| {
+        IBC_FUND_RECOVERY.update(storage, &recovery_addr, |cur_amount_opt| {
             let mut recoveries = cur_amount_opt.unwrap_or_default();
-            recoveries.push(amount.clone());
+            match recoveries.iter_mut().find(|c| c.denom == amount.denom) {
+                Some(coin) => coin.amount = coin.amount.checked_add(amount.amount)?,
+                None => recoveries.push(amount.clone()),
+            }
             Ok::<Vec<Coin>, ContractError>(recoveries)
         })?;
 
-        Ok(response
+        let mut event = Event::new("ibc_fund_recovery")
+            .add_attribute("reason", reason)
+            .add_attribute("channel", source_channel)
+            .add_attribute("sequence", sequence.to_string())
             .add_attribute("recovery_addr", recovery_addr)
-            .add_attribute("recovery_amount", amount.to_string()))
+            .add_attribute("recovery_amount", amount.to_string());
+        // Remaining legs of a multi-coin transfer are refunded by the receiving kernel once the transfer expires
+        if let Some(transfer_id) = transfer_id {
+            event = event.add_attribute("transfer_id", transfer_id.to_string());
+        }
+        Ok(Some(event))
     }
 }
@@ -1,7 +1,7 @@
 use crate::{execute::MsgHandler, state::KERNEL_ADDRESSES};
 use andromeda_std::{
     amp::{
-        messages::{AMPCtx, AMPMsg, AMPPkt},
+        messages::{AMPCtx, AMPMsg, AMPMsgConfig, AMPPkt},
         ADO_DB_KEY,
     },
     common::reply::ReplyId,
@@ -14,7 +14,7 @@ use andromeda_std::{
 use cosmwasm_std::{
     coin,
     testing::{mock_env, mock_info},
-    to_json_binary, Addr, BankMsg, Binary, SubMsg,
+    to_json_binary, Addr, BankMsg, Binary, ReplyOn, SubMsg, WasmMsg,
 };
 
 struct TestHandleLocalCase {
@@ -43,7 +43,7 @@ fn test_handle_local() {
                     None,
                 )],
             )
-            .to_sub_msg(MOCK_APP_CONTRACT, None, ReplyId::AMPMsg.repr())
+            .to_sub_msg(MOCK_APP_CONTRACT, None, ReplyId::AMPMsgExitAtError.repr())
             .unwrap(),
             expected_error: None,
         },
@@ -61,7 +61,7 @@ fn test_handle_local() {
                     None,
                 )],
             )
-            .to_sub_msg(MOCK_APP_CONTRACT, None, ReplyId::AMPMsg.repr())
+            .to_sub_msg(MOCK_APP_CONTRACT, None, ReplyId::AMPMsgExitAtError.repr())
             .unwrap(),
             expected_error: None,
         },
@@ -86,7 +86,7 @@ fn test_handle_local() {
             .to_sub_msg(
                 MOCK_APP_CONTRACT,
                 Some(vec![coin(100, "denom"), coin(200, "denom_two")]),
-                ReplyId::AMPMsg.repr(),
+                ReplyId::AMPMsgExitAtError.repr(),
             )
             .unwrap(),
             expected_error: None,
@@ -101,7 +101,10 @@ fn test_handle_local() {
                 to_json_binary(&true).unwrap(),
                 None,
             )
-            .generate_sub_msg_direct(Addr::unchecked(INVALID_CONTRACT), ReplyId::AMPMsg.repr()),
+            .generate_sub_msg_direct(
+                Addr::unchecked(INVALID_CONTRACT),
+                ReplyId::AMPMsgExitAtError.repr(),
+            ),
             expected_error: None,
         },
         TestHandleLocalCase {
@@ -118,7 +121,10 @@ fn test_handle_local() {
                 to_json_binary(&true).unwrap(),
                 Some(vec![coin(100, "denom"), coin(200, "denom_two")]),
             )
-            .generate_sub_msg_direct(Addr::unchecked(INVALID_CONTRACT), ReplyId::AMPMsg.repr()),
+            .generate_sub_msg_direct(
+                Addr::unchecked(INVALID_CONTRACT),
+                ReplyId::AMPMsgExitAtError.repr(),
+            ),
             expected_error: None,
         },
         TestHandleLocalCase {
@@ -135,7 +141,10 @@ fn test_handle_local() {
                 to_json_binary(&true).unwrap(),
                 Some(vec![coin(100, "denom"), coin(200, "denom_two")]),
             )
-            .generate_sub_msg_direct(Addr::unchecked(INVALID_CONTRACT), ReplyId::AMPMsg.repr()),
+            .generate_sub_msg_direct(
+                Addr::unchecked(INVALID_CONTRACT),
+                ReplyId::AMPMsgExitAtError.repr(),
+            ),
             expected_error: Some(ContractError::InvalidPacket {
                 error: Some("Recipient is not a contract".to_string()),
             }),
@@ -154,7 +163,10 @@ fn test_handle_local() {
                 to_json_binary(&true).unwrap(),
                 Some(vec![coin(100, "denom"), coin(200, "denom_two")]),
             )
-            .generate_sub_msg_direct(Addr::unchecked(INVALID_CONTRACT), ReplyId::AMPMsg.repr()),
+            .generate_sub_msg_direct(
+                Addr::unchecked(INVALID_CONTRACT),
+                ReplyId::AMPMsgExitAtError.repr(),
+            ),
             expected_error: Some(ContractError::InvalidPathname {
                 error: Some(format!(
                     "{:?} does not exist in the file system",
@@ -171,15 +183,75 @@ fn test_handle_local() {
                 Some(vec![coin(100, "denom"), coin(200, "denom_two")]),
             ),
             ctx: None,
-            expected_submessage: SubMsg::reply_on_error(
+            expected_submessage: SubMsg::reply_always(
                 BankMsg::Send {
                     to_address: "receiver".to_string(),
                     amount: vec![coin(100, "denom"), coin(200, "denom_two")],
                 },
+                ReplyId::AMPMsgExitAtError.repr(),
+            ),
+            expected_error: None,
+        },
+        TestHandleLocalCase {
+            name: "Bank send message with reply on error",
+            sender: "sender",
+            msg: AMPMsg::new(
+                "receiver",
+                Binary::default(),
+                Some(vec![coin(100, "denom")]),
+            )
+            .with_config(AMPMsgConfig::new(Some(ReplyOn::Error), None, None, None)),
+            ctx: None,
+            expected_submessage: SubMsg::reply_on_error(
+                BankMsg::Send {
+                    to_address: "receiver".to_string(),
+                    amount: vec![coin(100, "denom")],
+                },
+                ReplyId::AMPMsgExitAtError.repr(),
+            ),
+            expected_error: None,
+        },
+        TestHandleLocalCase {
+            name: "Bank send message proceeding upon failure",
+            sender: "sender",
+            msg: AMPMsg::new(
+                "receiver",
+                Binary::default(),
+                Some(vec![coin(100, "denom")]),
+            )
+            .with_config(AMPMsgConfig::new(
+                Some(ReplyOn::Never),
+                Some(false),
+                None,
+                None,
+            )),
+            ctx: None,
+            expected_submessage: SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: "receiver".to_string(),
+                    amount: vec![coin(100, "denom")],
+                },
                 ReplyId::AMPMsg.repr(),
             ),
             expected_error: None,
         },
+        TestHandleLocalCase {
+            name: "Valid message direct to Non-ADO with gas limit",
+            sender: "sender",
+            msg: AMPMsg::new(INVALID_CONTRACT, to_json_binary(&true).unwrap(), None)
+                .with_config(AMPMsgConfig::new(None, None, Some(100_000), None)),
+            ctx: None,
+            expected_submessage: SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: INVALID_CONTRACT.to_string(),
+                    msg: to_json_binary(&true).unwrap(),
+                    funds: vec![],
+                },
+                ReplyId::AMPMsgExitAtError.repr(),
+            )
+            .with_gas_limit(100_000),
+            expected_error: None,
+        },
         TestHandleLocalCase {
             name: "Bank send no funds",
             sender: "sender",
@@ -190,7 +262,7 @@ fn test_handle_local() {
                     to_address: "receiver".to_string(),
                     amount: vec![],
                 },
-                ReplyId::AMPMsg.repr(),
+                ReplyId::AMPMsgExitAtError.repr(),
             ),
             expected_error: Some(ContractError::InvalidPacket {
                 error: Some("No message or funds supplied".to_string()),
@@ -1,23 +1,40 @@
 use crate::{
-    contract::{execute, instantiate},
-    ibc::PACKET_LIFETIME,
-    state::{ADO_OWNER, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CURR_CHAIN, KERNEL_ADDRESSES},
+    ack::{make_ack_create_ado_success, make_ack_fail, make_ack_success},
+    contract::{execute, instantiate, query, reply},
+    ibc::{ibc_packet_ack, ibc_packet_receive, ibc_packet_timeout, PACKET_LIFETIME},
+    state::{
+        packets, OutgoingPacket, ADO_CREATE_IBC, ADO_OWNER, AMP_MSG_REPLY_FRAMES, CHAIN_TO_CHANNEL,
+        CHANNEL_TO_CHAIN, CURR_CHAIN, DIRECT_PACKET_IDS, DIRECT_PACKET_KEYS, IBC_FUND_RECOVERY,
+        IBC_HOOKS_TRANSFER_ID, INCOMING_IBC_HOOKS_TRANSFERS, KERNEL_ADDRESSES, MAX_PACKET_HISTORY,
+        OUTGOING_IBC_HOOKS_PACKETS, OUTGOING_IBC_PACKETS, PACKET_ID,
+    },
+    sudo::ibc_lifecycle::{receive_ack, receive_timeout},
 };
 use andromeda_std::{
     amp::{
-        messages::{AMPMsg, AMPPkt},
-        ADO_DB_KEY, VFS_KEY,
+        messages::{AMPMsg, AMPMsgConfig, AMPPkt},
+        AndrAddr, ADO_DB_KEY, VFS_KEY,
     },
+    common::{reply::ReplyId, response::AndrEvent},
     error::ContractError,
-    os::kernel::{ChannelInfo, ExecuteMsg, IbcExecuteMsg, InstantiateMsg, InternalMsg},
+    os::kernel::{
+        AMPMsgResult, AMPPktResponse, ChannelInfo, CreateCallbackMsg, ExecuteMsg, IbcExecuteMsg,
+        IbcHooksTransferLeg, InstantiateMsg, InternalMsg, PacketInfo, PacketStatus, QueryMsg,
+    },
     testing::mock_querier::{
-        mock_dependencies_custom, MOCK_ADODB_CONTRACT, MOCK_FAKE_KERNEL_CONTRACT,
-        MOCK_KERNEL_CONTRACT, MOCK_VFS_CONTRACT,
+        mock_dependencies_custom, MOCK_ADODB_CONTRACT, MOCK_APP_CONTRACT, MOCK_DEPRECATED_ADO_TYPE,
+        MOCK_FAKE_KERNEL_CONTRACT, MOCK_KERNEL_CONTRACT, MOCK_VFS_CONTRACT,
     },
 };
 use cosmwasm_std::{
-    testing::{mock_dependencies, mock_env, mock_info},
-    to_json_binary, Addr, Binary, CosmosMsg, IbcMsg,
+    attr, coin, from_json,
+    testing::{
+        mock_dependencies, mock_env, mock_ibc_packet_ack, mock_ibc_packet_recv,
+        mock_ibc_packet_timeout, mock_info,
+    },
+    to_json_binary, to_json_string, Addr, BankMsg, Binary, Coin, CosmosMsg, Event,
+    IbcAcknowledgement, IbcMsg, Order, Reply, ReplyOn, SubMsg, SubMsgResponse, SubMsgResult,
+    WasmMsg,
 };
 
 #[test]
@@ -102,10 +119,432 @@ fn test_create_ado() {
         msg: Binary::default(),
         owner: None,
         chain: None,
+        force: None,
     };
-    let res = execute(deps.as_mut(), env, info.clone(), create_msg).unwrap();
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), create_msg).unwrap();
     assert_eq!(1, res.messages.len());
     assert_eq!(ADO_OWNER.load(deps.as_ref().storage).unwrap(), info.sender);
+
+    // Deprecated versions are refused unless forced
+    let create_msg = ExecuteMsg::Create {
+        ado_type: MOCK_DEPRECATED_ADO_TYPE.to_string(),
+        msg: Binary::default(),
+        owner: None,
+        chain: None,
+        force: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), create_msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DeprecatedADOVersion {
+            ado_version: MOCK_DEPRECATED_ADO_TYPE.to_string(),
+            code_id: 4
+        }
+    );
+
+    let create_msg = ExecuteMsg::Create {
+        ado_type: MOCK_DEPRECATED_ADO_TYPE.to_string(),
+        msg: Binary::default(),
+        owner: None,
+        chain: None,
+        force: Some(true),
+    };
+    let res = execute(deps.as_mut(), env, info, create_msg).unwrap();
+    assert_eq!(1, res.messages.len());
+}
+
+#[test]
+fn test_create_ado_cross_chain() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info("creator", &[]);
+    let env = mock_env();
+    let chain = "chain";
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+
+    let create_msg = ExecuteMsg::Create {
+        ado_type: "ado_type".to_string(),
+        msg: Binary::default(),
+        owner: None,
+        chain: Some(chain.to_string()),
+        force: None,
+    };
+    // An owner must be provided for cross-chain creation
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), create_msg).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let owner = AndrAddr::from_string("remote_owner");
+    let create_msg = ExecuteMsg::Create {
+        ado_type: "ado_type".to_string(),
+        msg: Binary::default(),
+        owner: Some(owner.clone()),
+        chain: Some(chain.to_string()),
+        force: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), create_msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPacket {
+            error: Some("Channel not found for chain chain".to_string())
+        }
+    );
+
+    let channel_info = ChannelInfo {
+        kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+        ics20_channel_id: Some("1".to_string()),
+        direct_channel_id: Some("2".to_string()),
+        supported_modules: vec![],
+    };
+    CHAIN_TO_CHANNEL
+        .save(deps.as_mut().storage, chain, &channel_info)
+        .unwrap();
+
+    let res = execute(deps.as_mut(), env.clone(), info, create_msg).unwrap();
+    assert_eq!(res.messages.len(), 1);
+
+    let expected = IbcMsg::SendPacket {
+        channel_id: channel_info.direct_channel_id.unwrap(),
+        data: to_json_binary(&IbcExecuteMsg::CreateADO {
+            instantiation_msg: Binary::default(),
+            owner,
+            ado_type: "ado_type".to_string(),
+            force: None,
+        })
+        .unwrap(),
+        timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+    };
+    assert_eq!(res.messages.first().unwrap().msg, CosmosMsg::Ibc(expected));
+
+    // The creation is logged as a packet awaiting acknowledgement
+    let packet = packets().load(deps.as_ref().storage, 1).unwrap();
+    assert_eq!(packet.origin, "creator");
+    assert_eq!(
+        packet.recipients,
+        vec![AndrAddr::from_string(format!(
+            "ibc://chain/{MOCK_FAKE_KERNEL_CONTRACT}"
+        ))]
+    );
+    assert_eq!(packet.status, PacketStatus::Pending);
+    assert_eq!(packet.hops[0].channel, "2");
+}
+
+#[test]
+fn test_ibc_create_ado() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info("creator", &[]);
+    let env = mock_env();
+    let channel = "channel-2";
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+
+    let packet_data = IbcExecuteMsg::CreateADO {
+        instantiation_msg: Binary::default(),
+        owner: AndrAddr::from_string("remote_owner"),
+        ado_type: "ado_type".to_string(),
+        force: None,
+    };
+
+    // Packets from unknown channels are rejected with an error acknowledgement
+    let msg = mock_ibc_packet_recv(channel, &packet_data).unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), env.clone(), msg).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        res.acknowledgement,
+        make_ack_fail(ContractError::Unauthorized {}.to_string())
+    );
+
+    CHANNEL_TO_CHAIN
+        .save(deps.as_mut().storage, channel, &"chain".to_string())
+        .unwrap();
+    let msg = mock_ibc_packet_recv(channel, &packet_data).unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), env, msg).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(
+        ADO_OWNER.load(deps.as_ref().storage).unwrap(),
+        Addr::unchecked("remote_owner")
+    );
+    assert!(ADO_CREATE_IBC.load(deps.as_ref().storage).unwrap());
+}
+
+#[test]
+fn test_ibc_create_ado_ack() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let packet_data = IbcExecuteMsg::CreateADO {
+        instantiation_msg: Binary::default(),
+        owner: AndrAddr::from_string("remote_owner"),
+        ado_type: "ado_type".to_string(),
+        force: None,
+    };
+
+    let ack = IbcAcknowledgement::new(make_ack_create_ado_success(
+        Addr::unchecked("remote_ado"),
+        vec![],
+    ));
+    let msg = mock_ibc_packet_ack("channel-2", &packet_data, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), env.clone(), msg).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "ado_address" && attr.value == "remote_ado"));
+
+    let ack = IbcAcknowledgement::new(make_ack_fail("error".to_string()));
+    let msg = mock_ibc_packet_ack("channel-2", &packet_data, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), env, msg).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "success" && attr.value == "false"));
+}
+
+#[test]
+fn test_cross_chain_create_callback() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info("app_contract", &[]);
+    let env = mock_env();
+    let chain = "chain";
+    let channel = "channel-2";
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    let channel_info = ChannelInfo {
+        kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+        ics20_channel_id: None,
+        direct_channel_id: Some(channel.to_string()),
+        supported_modules: vec![],
+    };
+    CHAIN_TO_CHANNEL
+        .save(deps.as_mut().storage, chain, &channel_info)
+        .unwrap();
+    CHANNEL_TO_CHAIN
+        .save(deps.as_mut().storage, channel, &chain.to_string())
+        .unwrap();
+
+    let owner = AndrAddr::from_string("remote_owner");
+    let create_msg = ExecuteMsg::Create {
+        ado_type: "app-contract".to_string(),
+        msg: Binary::default(),
+        owner: Some(owner.clone()),
+        chain: Some(chain.to_string()),
+        force: None,
+    };
+    execute(deps.as_mut(), env.clone(), info, create_msg).unwrap();
+
+    let packet_data = IbcExecuteMsg::CreateADO {
+        instantiation_msg: Binary::default(),
+        owner,
+        ado_type: "app-contract".to_string(),
+        force: None,
+    };
+    let ack = IbcAcknowledgement::new(make_ack_create_ado_success(
+        Addr::unchecked("remote_app"),
+        vec![("token".to_string(), "remote_token".to_string())],
+    ));
+    let msg = mock_ibc_packet_ack(channel, &packet_data, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), env.clone(), msg.clone()).unwrap();
+
+    let callback = CreateCallbackMsg::CrossChainCreateAck {
+        chain: chain.to_string(),
+        ado_type: "app-contract".to_string(),
+        address: Some("remote_app".to_string()),
+        components: vec![("token".to_string(), "remote_token".to_string())],
+        error: None,
+    };
+    let expected = SubMsg::reply_on_error(
+        WasmMsg::Execute {
+            contract_addr: "app_contract".to_string(),
+            msg: to_json_binary(&callback).unwrap(),
+            funds: vec![],
+        },
+        ReplyId::CrossChainCreateCallback.repr(),
+    );
+    assert_eq!(res.messages, vec![expected]);
+
+    // The creator is only notified once
+    let res = ibc_packet_ack(deps.as_mut(), env, msg).unwrap();
+    assert!(res.messages.is_empty());
+}
+
+#[test]
+fn test_ibc_packet_timeout_recovery() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let packet_data = IbcExecuteMsg::SendMessage {
+        recipient: AndrAddr::from_string("recipient"),
+        message: Binary::default(),
+    };
+    let msg = mock_ibc_packet_timeout("channel-1", &packet_data).unwrap();
+    let channel = msg.packet.src.channel_id.clone();
+    let sequence = msg.packet.sequence;
+    let recovery_addr = Addr::unchecked("recovery_addr");
+
+    // No in-flight packet, nothing to recover
+    let res = ibc_packet_timeout(deps.as_mut(), env.clone(), msg.clone()).unwrap();
+    assert!(res.events.is_empty());
+
+    OUTGOING_IBC_PACKETS
+        .save(
+            deps.as_mut().storage,
+            (&channel, sequence),
+            &OutgoingPacket {
+                recovery_addr: recovery_addr.clone(),
+                amount: Coin::new(100, "uandr"),
+                transfer_id: None,
+                packet_id: None,
+            },
+        )
+        .unwrap();
+    let res = ibc_packet_timeout(deps.as_mut(), env, msg).unwrap();
+    assert_eq!(res.events.len(), 1);
+    assert_eq!(res.events[0].ty, "ibc_fund_recovery");
+
+    let recoveries = IBC_FUND_RECOVERY
+        .load(deps.as_ref().storage, &recovery_addr)
+        .unwrap();
+    assert_eq!(recoveries, vec![Coin::new(100, "uandr")]);
+    assert!(!OUTGOING_IBC_PACKETS.has(deps.as_ref().storage, (&channel, sequence)));
+}
+
+#[test]
+fn test_ibc_packet_ack_recovery() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let packet_data = IbcExecuteMsg::SendMessage {
+        recipient: AndrAddr::from_string("recipient"),
+        message: Binary::default(),
+    };
+    let recovery_addr = Addr::unchecked("recovery_addr");
+
+    let ack = IbcAcknowledgement::new(make_ack_success());
+    let msg = mock_ibc_packet_ack("channel-1", &packet_data, ack).unwrap();
+    let channel = msg.original_packet.src.channel_id.clone();
+    let sequence = msg.original_packet.sequence;
+    let outgoing_packet = OutgoingPacket {
+        recovery_addr: recovery_addr.clone(),
+        amount: Coin::new(100, "uandr"),
+        transfer_id: None,
+        packet_id: None,
+    };
+    OUTGOING_IBC_PACKETS
+        .save(
+            deps.as_mut().storage,
+            (&channel, sequence),
+            &outgoing_packet,
+        )
+        .unwrap();
+
+    // A successful ack does not recover funds
+    let res = ibc_packet_ack(deps.as_mut(), env.clone(), msg).unwrap();
+    assert!(res.events.is_empty());
+    assert!(IBC_FUND_RECOVERY
+        .may_load(deps.as_ref().storage, &recovery_addr)
+        .unwrap()
+        .is_none());
+
+    let ack = IbcAcknowledgement::new(make_ack_fail("error".to_string()));
+    let msg = mock_ibc_packet_ack("channel-1", &packet_data, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), env, msg).unwrap();
+    assert_eq!(res.events.len(), 1);
+    let recoveries = IBC_FUND_RECOVERY
+        .load(deps.as_ref().storage, &recovery_addr)
+        .unwrap();
+    assert_eq!(recoveries, vec![Coin::new(100, "uandr")]);
+}
+
+#[test]
+fn test_ibc_packet_ack_non_kernel_packet() {
+    #[cosmwasm_schema::cw_serde]
+    struct Ics20Packet {
+        denom: String,
+        amount: String,
+    }
+
+    let mut deps = mock_dependencies_custom(&[]);
+    let packet_data = Ics20Packet {
+        denom: "uandr".to_string(),
+        amount: "100".to_string(),
+    };
+    let ack = IbcAcknowledgement::new(make_ack_success());
+    let msg = mock_ibc_packet_ack("channel-1", &packet_data, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(res.attributes, vec![attr("method", "ibc_packet_ack")]);
+}
+
+#[test]
+fn test_ibc_hooks_lifecycle_recovery() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let channel = "channel-1".to_string();
+    let recovery_addr = Addr::unchecked("recovery_addr");
+    let outgoing_packet = OutgoingPacket {
+        recovery_addr: recovery_addr.clone(),
+        amount: Coin::new(100, "uandr"),
+        transfer_id: None,
+        packet_id: None,
+    };
+    for sequence in 1..=3 {
+        OUTGOING_IBC_PACKETS
+            .save(
+                deps.as_mut().storage,
+                (&channel, sequence),
+                &outgoing_packet,
+            )
+            .unwrap();
+    }
+
+    let res = receive_ack(deps.as_mut(), channel.clone(), 1, "".to_string(), true).unwrap();
+    assert!(res.events.is_empty());
+
+    let res = receive_ack(deps.as_mut(), channel.clone(), 2, "".to_string(), false).unwrap();
+    assert_eq!(res.events.len(), 1);
+
+    let res = receive_timeout(deps.as_mut(), channel.clone(), 3).unwrap();
+    assert_eq!(res.events.len(), 1);
+
+    // Recoveries of the same denom are merged
+    let recoveries = IBC_FUND_RECOVERY
+        .load(deps.as_ref().storage, &recovery_addr)
+        .unwrap();
+    assert_eq!(recoveries, vec![Coin::new(200, "uandr")]);
+    for sequence in 1..=3 {
+        assert!(!OUTGOING_IBC_PACKETS.has(deps.as_ref().storage, (&channel, sequence)));
+    }
 }
 
 #[test]
@@ -390,3 +829,698 @@ fn test_handle_ibc_direct() {
     // * message fails even though it is a non-default binary message
     assert!(res.is_ok());
 }
+
+#[test]
+fn test_send_proceed_on_error() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+
+    let funds = vec![coin(100, "uandr")];
+    let message = AMPMsg::new("receiver", Binary::default(), Some(funds.clone()))
+        .with_config(AMPMsgConfig::new(None, Some(false), None, None));
+    let info = mock_info("sender", &funds);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::Send {
+            message: message.clone(),
+        },
+    )
+    .unwrap();
+    // The send may still fail, so no funds are reported as sent until it succeeds
+    let funds_sent: Event = AndrEvent::FundsSent {
+        recipient: "receiver".to_string(),
+        funds: funds.clone(),
+    }
+    .into();
+    assert!(!res.events.contains(&funds_sent));
+    assert_eq!(res.messages[0].id, ReplyId::AMPMsg.repr());
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Always);
+    assert_eq!(
+        AMP_MSG_REPLY_FRAMES
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let reply_msg = Reply {
+        id: ReplyId::AMPMsg.repr(),
+        result: SubMsgResult::Err("insufficient funds".to_string()),
+    };
+    let res = reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+    assert!(!res.events.contains(&funds_sent));
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "sender".to_string(),
+            amount: funds.clone(),
+        })
+    );
+    let summary: AMPPktResponse = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(
+        summary.results,
+        vec![AMPMsgResult {
+            index: 0,
+            recipient: AndrAddr::from_string("receiver"),
+            success: false,
+            error: Some("insufficient funds".to_string()),
+        }]
+    );
+    assert!(AMP_MSG_REPLY_FRAMES
+        .load(deps.as_ref().storage)
+        .unwrap()
+        .is_empty());
+
+    // A successful send reports the funds as sent
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::Send { message },
+    )
+    .unwrap();
+    let reply_msg = Reply {
+        id: ReplyId::AMPMsg.repr(),
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    };
+    let res = reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(res.events, vec![funds_sent]);
+
+    // Messages that exit at error still revert the relay
+    let reply_msg = Reply {
+        id: ReplyId::AMPMsgExitAtError.repr(),
+        result: SubMsgResult::Err("insufficient funds".to_string()),
+    };
+    assert!(reply(deps.as_mut(), env, reply_msg).is_err());
+}
+
+#[test]
+fn test_send_multi_coin_ibc_hooks() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+    CHAIN_TO_CHANNEL
+        .save(
+            deps.as_mut().storage,
+            "chain2",
+            &ChannelInfo {
+                kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+                ics20_channel_id: Some("channel-1".to_string()),
+                direct_channel_id: None,
+                supported_modules: vec![],
+            },
+        )
+        .unwrap();
+
+    let funds = vec![coin(100, "uandr"), coin(200, "uusd")];
+    let message = AMPMsg::new(
+        "ibc://chain2/receiver",
+        Binary::default(),
+        Some(funds.clone()),
+    );
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("sender", &funds),
+        ExecuteMsg::Send { message },
+    )
+    .unwrap();
+
+    // One transfer per coin
+    assert_eq!(res.messages.len(), 2);
+    for msg in res.messages.iter() {
+        assert_eq!(msg.id, ReplyId::IBCHooksPacketSend.repr());
+    }
+    let outgoing = OUTGOING_IBC_HOOKS_PACKETS
+        .load(deps.as_ref().storage)
+        .unwrap();
+    assert_eq!(outgoing.len(), 2);
+    for (packet, amount) in outgoing.iter().zip(funds) {
+        assert_eq!(packet.amount, amount);
+        assert_eq!(packet.transfer_id, Some(0));
+        assert_eq!(packet.recovery_addr, Addr::unchecked("sender"));
+    }
+    assert_eq!(
+        IBC_HOOKS_TRANSFER_ID.load(deps.as_ref().storage).unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_receive_transfer_legs() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+    let leg = IbcHooksTransferLeg {
+        transfer_id: 0,
+        legs: 2,
+        source_chain: "chain2".to_string(),
+        recovery_addr: "recovery".to_string(),
+        expiry: env.block.time.plus_seconds(PACKET_LIFETIME),
+    };
+    let receive_leg = |amount: Coin| ExecuteMsg::ReceiveTransferLeg {
+        message: AMPMsg::new("receiver", Binary::default(), Some(vec![amount])),
+        leg: leg.clone(),
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("hooks_sender", &[coin(100, "uandr")]),
+        receive_leg(coin(100, "uandr")),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    let transfer = INCOMING_IBC_HOOKS_TRANSFERS
+        .load(deps.as_ref().storage, (&Addr::unchecked("hooks_sender"), 0))
+        .unwrap();
+    assert_eq!(transfer.received, 1);
+
+    // Legs are tracked per sender
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("hooks_sender", &[coin(100, "uandr"), coin(200, "uusd")]),
+        receive_leg(coin(100, "uandr")),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: "Transfer legs must carry exactly one coin".to_string()
+        }
+    );
+
+    // Legs must match the recorded transfer and message
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("hooks_sender", &[coin(200, "uusd")]),
+        ExecuteMsg::ReceiveTransferLeg {
+            message: AMPMsg::new(
+                "other_receiver",
+                Binary::default(),
+                Some(vec![coin(200, "uusd")]),
+            ),
+            leg: leg.clone(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPacket {
+            error: Some("Leg does not match the recorded legs of transfer 0".to_string())
+        }
+    );
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("hooks_sender", &[coin(200, "uusd")]),
+        ExecuteMsg::ReceiveTransferLeg {
+            message: AMPMsg::new("receiver", Binary::default(), Some(vec![coin(200, "uusd")])),
+            leg: IbcHooksTransferLeg {
+                legs: 3,
+                ..leg.clone()
+            },
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPacket {
+            error: Some("Leg does not match the recorded legs of transfer 0".to_string())
+        }
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("hooks_sender", &[coin(200, "uusd")]),
+        receive_leg(coin(200, "uusd")),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "receiver".to_string(),
+            amount: vec![coin(100, "uandr"), coin(200, "uusd")],
+        })
+    );
+    assert!(!INCOMING_IBC_HOOKS_TRANSFERS
+        .has(deps.as_ref().storage, (&Addr::unchecked("hooks_sender"), 0)));
+}
+
+#[test]
+fn test_receive_transfer_leg_forged_origin() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+
+    // A direct caller completing a transfer is the origin of the relayed message
+    let funds = vec![coin(100, "uandr")];
+    let message = AMPMsg::new(
+        MOCK_APP_CONTRACT,
+        to_json_binary(&true).unwrap(),
+        Some(funds.clone()),
+    );
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("attacker", &funds),
+        ExecuteMsg::ReceiveTransferLeg {
+            message: message.clone(),
+            leg: IbcHooksTransferLeg {
+                transfer_id: 0,
+                legs: 1,
+                source_chain: "chain2".to_string(),
+                recovery_addr: "recovery".to_string(),
+                expiry: env.block.time.plus_seconds(PACKET_LIFETIME),
+            },
+        },
+    )
+    .unwrap();
+    let expected = AMPPkt::new("attacker", "attacker", vec![message.clone()])
+        .to_sub_msg(
+            MOCK_APP_CONTRACT,
+            Some(funds),
+            ReplyId::AMPMsgExitAtError.repr(),
+        )
+        .unwrap();
+    assert_eq!(res.messages[0].msg, expected.msg);
+
+    // Legs naming an origin are rejected
+    let leg_msg = to_json_string(&ExecuteMsg::ReceiveTransferLeg {
+        message,
+        leg: IbcHooksTransferLeg {
+            transfer_id: 1,
+            legs: 1,
+            source_chain: "chain2".to_string(),
+            recovery_addr: "recovery".to_string(),
+            expiry: env.block.time.plus_seconds(PACKET_LIFETIME),
+        },
+    })
+    .unwrap();
+    assert!(from_json::<ExecuteMsg>(leg_msg.as_bytes()).is_ok());
+    let forged = leg_msg.replace(
+        "\"recovery_addr\":\"recovery\"",
+        "\"recovery_addr\":\"recovery\",\"origin\":\"owner\"",
+    );
+    assert!(from_json::<ExecuteMsg>(forged.as_bytes()).is_err());
+}
+
+#[test]
+fn test_refund_transfer() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    CHAIN_TO_CHANNEL
+        .save(
+            deps.as_mut().storage,
+            "chain2",
+            &ChannelInfo {
+                kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+                ics20_channel_id: Some("channel-1".to_string()),
+                direct_channel_id: None,
+                supported_modules: vec![],
+            },
+        )
+        .unwrap();
+    let expiry = env.block.time.plus_seconds(PACKET_LIFETIME);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("hooks_sender", &[coin(100, "uandr")]),
+        ExecuteMsg::ReceiveTransferLeg {
+            message: AMPMsg::new("receiver", Binary::default(), None),
+            leg: IbcHooksTransferLeg {
+                transfer_id: 0,
+                legs: 2,
+                source_chain: "chain2".to_string(),
+                recovery_addr: "recovery".to_string(),
+                expiry,
+            },
+        },
+    )
+    .unwrap();
+
+    let refund_msg = ExecuteMsg::RefundTransfer {
+        sender: "hooks_sender".to_string(),
+        transfer_id: 0,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        refund_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPacket {
+            error: Some("Transfer 0 has not expired".to_string())
+        }
+    );
+
+    env.block.time = expiry.plus_seconds(1);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        refund_msg,
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Ibc(IbcMsg::Transfer {
+            channel_id: "channel-1".to_string(),
+            to_address: "recovery".to_string(),
+            amount: coin(100, "uandr"),
+            timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+        })
+    );
+    assert!(!INCOMING_IBC_HOOKS_TRANSFERS
+        .has(deps.as_ref().storage, (&Addr::unchecked("hooks_sender"), 0)));
+}
+
+#[test]
+fn test_packet_history() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+
+    let funds = vec![coin(100, "uandr")];
+    for _ in 0..2 {
+        let message = AMPMsg::new("receiver", Binary::default(), Some(funds.clone()));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender", &funds),
+            ExecuteMsg::Send { message },
+        )
+        .unwrap();
+        let summary: AMPPktResponse = from_json(res.data.unwrap()).unwrap();
+        assert!(summary.packet_id > 0);
+    }
+
+    let packet: PacketInfo =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::PacketStatus { id: 1 }).unwrap())
+            .unwrap();
+    assert_eq!(
+        packet,
+        PacketInfo {
+            id: 1,
+            origin: "sender".to_string(),
+            previous_sender: "sender".to_string(),
+            recipients: vec![AndrAddr::from_string("receiver")],
+            status: PacketStatus::Succeeded,
+            hops: vec![],
+            error: None,
+            block_height: env.block.height,
+        }
+    );
+
+    let packets: Vec<PacketInfo> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::PacketsByOrigin {
+                origin: "sender".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(packets.len(), 2);
+
+    let packets: Vec<PacketInfo> = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::PacketsByOrigin {
+                origin: "sender".to_string(),
+                start_after: Some(1),
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].id, 2);
+}
+
+#[test]
+fn test_packet_history_ibc_timeout() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+    CHAIN_TO_CHANNEL
+        .save(
+            deps.as_mut().storage,
+            "chain2",
+            &ChannelInfo {
+                kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+                ics20_channel_id: None,
+                direct_channel_id: Some("channel-2".to_string()),
+                supported_modules: vec![],
+            },
+        )
+        .unwrap();
+
+    let message = AMPMsg::new(
+        "ibc://chain2/receiver",
+        to_json_binary(&"message").unwrap(),
+        None,
+    );
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[]),
+        ExecuteMsg::Send { message },
+    )
+    .unwrap();
+    let CosmosMsg::Ibc(IbcMsg::SendPacket { data, .. }) = res.messages[0].msg.clone() else {
+        panic!("Expected an IBC packet");
+    };
+
+    let packet = packets().load(deps.as_ref().storage, 1).unwrap();
+    assert_eq!(packet.status, PacketStatus::Pending);
+    assert_eq!(packet.hops[0].channel, "channel-2");
+
+    let packet_data: IbcExecuteMsg = from_json(data).unwrap();
+    let msg = mock_ibc_packet_timeout("channel-2", &packet_data).unwrap();
+    ibc_packet_timeout(deps.as_mut(), env, msg.clone()).unwrap();
+
+    let packet = packets().load(deps.as_ref().storage, 1).unwrap();
+    assert_eq!(packet.status, PacketStatus::TimedOut);
+    assert_eq!(packet.hops[0].sequence, Some(msg.packet.sequence));
+}
+
+#[test]
+fn test_prune_unacknowledged_direct_packets() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            ADO_DB_KEY,
+            &Addr::unchecked(MOCK_ADODB_CONTRACT),
+        )
+        .unwrap();
+    CHAIN_TO_CHANNEL
+        .save(
+            deps.as_mut().storage,
+            "chain2",
+            &ChannelInfo {
+                kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+                ics20_channel_id: None,
+                direct_channel_id: Some("channel-2".to_string()),
+                supported_modules: vec![],
+            },
+        )
+        .unwrap();
+
+    let message = AMPMsg::new(
+        "ibc://chain2/receiver",
+        to_json_binary(&"message").unwrap(),
+        None,
+    );
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[]),
+        ExecuteMsg::Send { message },
+    )
+    .unwrap();
+    assert_eq!(
+        DIRECT_PACKET_IDS
+            .range(deps.as_ref().storage, None, None, Order::Ascending)
+            .count(),
+        1
+    );
+
+    // The packet is never acknowledged and is pruned from the log
+    PACKET_ID
+        .save(deps.as_mut().storage, &MAX_PACKET_HISTORY)
+        .unwrap();
+    let funds = vec![coin(100, "uandr")];
+    let message = AMPMsg::new("receiver", Binary::default(), Some(funds.clone()));
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("sender", &funds),
+        ExecuteMsg::Send { message },
+    )
+    .unwrap();
+    assert!(!packets().has(deps.as_ref().storage, 1));
+    assert_eq!(
+        DIRECT_PACKET_IDS
+            .range(deps.as_ref().storage, None, None, Order::Ascending)
+            .count(),
+        0
+    );
+    assert!(!DIRECT_PACKET_KEYS.has(deps.as_ref().storage, 1));
+}
@@ -2,7 +2,7 @@ use andromeda_std::ado_contract::ADOContract;
 use andromeda_std::os::vfs::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
-    common::encode_binary,
+    common::{context::ExecuteContext, encode_binary},
     error::ContractError,
 };
 use cosmwasm_std::{
@@ -55,6 +55,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_not_paused(deps.storage, &env, msg.as_ref())?;
     let execute_env = execute::ExecuteEnv { deps, env, info };
 
     match msg {
@@ -82,6 +83,28 @@ pub fn execute(
         ExecuteMsg::RegisterUserCrossChain { chain, address } => {
             execute::register_user_cross_chain(execute_env, chain, address)
         }
+        ExecuteMsg::RemovePath {
+            name,
+            parent_address,
+        } => execute::remove_path(execute_env, name, parent_address),
+        ExecuteMsg::RenamePath {
+            name,
+            new_name,
+            parent_address,
+        } => execute::rename_path(execute_env, name, new_name, parent_address),
+        ExecuteMsg::MovePath {
+            name,
+            parent_address,
+            new_parent_address,
+        } => execute::move_path(execute_env, name, parent_address, new_parent_address),
+        ExecuteMsg::TransferUsername { address } => {
+            execute::transfer_username(execute_env, address)
+        }
+        ExecuteMsg::AcceptUsername { username } => execute::accept_username(execute_env, username),
+        ExecuteMsg::UnregisterUser {} => execute::unregister_user(execute_env),
+        ExecuteMsg::UpdateSymlinkMaxDepth { max_depth } => {
+            execute::update_symlink_max_depth(execute_env, max_depth)
+        }
         // Base message
         ExecuteMsg::Ownership(ownership_message) => ADOContract::default().execute_ownership(
             execute_env.deps,
@@ -89,6 +112,16 @@ pub fn execute(
             execute_env.info,
             ownership_message,
         ),
+        ExecuteMsg::Pause { actions, until } => ADOContract::default().execute_pause(
+            ExecuteContext::new(execute_env.deps, execute_env.info, execute_env.env),
+            actions,
+            until,
+        ),
+        ExecuteMsg::Unpause {} => ADOContract::default().execute_unpause(ExecuteContext::new(
+            execute_env.deps,
+            execute_env.info,
+            execute_env.env,
+        )),
     }
 }
 
@@ -98,7 +131,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::ResolvePath { path } => encode_binary(&query::resolve_path(deps, path)?),
         QueryMsg::SubDir {
@@ -111,10 +144,14 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
         QueryMsg::GetUsername { address } => encode_binary(&query::get_username(deps, address)?),
         QueryMsg::GetLibrary { address } => encode_binary(&query::get_library_name(deps, address)?),
         QueryMsg::ResolveSymlink { path } => encode_binary(&query::get_symlink(deps, path)?),
+        QueryMsg::SymlinkMaxDepth {} => encode_binary(&query::symlink_max_depth(deps)?),
         // Base queries
         QueryMsg::Version {} => encode_binary(&ADOContract::default().query_version(deps)?),
         QueryMsg::Type {} => encode_binary(&ADOContract::default().query_type(deps)?),
         QueryMsg::Owner {} => encode_binary(&ADOContract::default().query_contract_owner(deps)?),
+        QueryMsg::PauseStatus {} => {
+            encode_binary(&ADOContract::default().query_pause_status(deps, env)?)
+        }
         QueryMsg::KernelAddress {} => {
             encode_binary(&ADOContract::default().query_kernel_address(deps)?)
         }
@@ -8,12 +8,13 @@ use andromeda_std::os::{
     vfs::{validate_component_name, validate_username},
 };
 use cosmwasm_std::{
-    attr, ensure, to_json_binary, Addr, DepsMut, Env, MessageInfo, Response, SubMsg, WasmMsg,
+    attr, ensure, to_json_binary, Addr, DepsMut, Env, MessageInfo, Order, Response, SubMsg, WasmMsg,
 };
 
 use crate::state::{
-    add_path_symlink, add_pathname, paths, resolve_pathname, ADDRESS_LIBRARY, ADDRESS_USERNAME,
-    LIBRARIES, USERS,
+    add_path_symlink, add_pathname, move_pathname, paths, remove_pathname, remove_unnamed_subpaths,
+    resolve_pathname, PathInfo, ADDRESS_LIBRARY, ADDRESS_USERNAME, LIBRARIES, MAX_DEPTH,
+    PENDING_USERNAME_TRANSFERS, SYMLINK_MAX_DEPTH, USERS,
 };
 
 pub struct ExecuteEnv<'a> {
@@ -22,13 +23,13 @@ pub struct ExecuteEnv<'a> {
     pub info: MessageInfo,
 }
 
-pub fn add_path(
-    env: ExecuteEnv,
-    name: String,
-    address: Addr,
+/// Resolves the given parent directory, defaulting to the sender's home directory.
+///
+/// Only the kernel or the VFS owner may provide a parent directory.
+fn resolve_parent_address(
+    env: &ExecuteEnv,
     parent_address: Option<AndrAddr>,
-) -> Result<Response, ContractError> {
-    let name = name.to_lowercase();
+) -> Result<Addr, ContractError> {
     let kernel_address = ADOContract::default().get_kernel_address(env.deps.storage)?;
     ensure!(
         parent_address.is_none()
@@ -37,13 +38,23 @@ pub fn add_path(
                 .is_contract_owner(env.deps.storage, env.info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
-    let parent_andr_addr = parent_address.unwrap_or(AndrAddr::from_string(env.info.sender));
-    let parent_addr = resolve_pathname(
+    let parent_andr_addr = parent_address.unwrap_or(AndrAddr::from_string(env.info.sender.clone()));
+    resolve_pathname(
         env.deps.storage,
         env.deps.api,
         parent_andr_addr,
         &mut vec![],
-    )?;
+    )
+}
+
+pub fn add_path(
+    env: ExecuteEnv,
+    name: String,
+    address: Addr,
+    parent_address: Option<AndrAddr>,
+) -> Result<Response, ContractError> {
+    let name = name.to_lowercase();
+    let parent_addr = resolve_parent_address(&env, parent_address)?;
     validate_component_name(name.clone())?;
     add_pathname(
         env.deps.storage,
@@ -67,21 +78,7 @@ pub fn add_symlink(
 ) -> Result<Response, ContractError> {
     let name = name.to_lowercase();
     let symlink = symlink.to_lowercase();
-    let kernel_address = ADOContract::default().get_kernel_address(env.deps.storage)?;
-    ensure!(
-        parent_address.is_none()
-            || env.info.sender == kernel_address
-            || ADOContract::default()
-                .is_contract_owner(env.deps.storage, env.info.sender.as_str())?,
-        ContractError::Unauthorized {}
-    );
-    let parent_andr_addr = parent_address.unwrap_or(AndrAddr::from_string(env.info.sender));
-    let parent_addr = resolve_pathname(
-        env.deps.storage,
-        env.deps.api,
-        parent_andr_addr,
-        &mut vec![],
-    )?;
+    let parent_addr = resolve_parent_address(&env, parent_address)?;
     validate_component_name(name.clone())?;
     add_path_symlink(
         env.deps.storage,
@@ -98,6 +95,83 @@ pub fn add_symlink(
     ]))
 }
 
+pub fn remove_path(
+    env: ExecuteEnv,
+    name: String,
+    parent_address: Option<AndrAddr>,
+) -> Result<Response, ContractError> {
+    let name = name.to_lowercase();
+    let parent_addr = resolve_parent_address(&env, parent_address)?;
+    let info = remove_pathname(env.deps.storage, parent_addr.clone(), name.clone())?;
+    // Paths beneath the removed path go with it unless the directory is still named elsewhere
+    let removed = match info.symlink {
+        Some(_) => vec![],
+        None => remove_unnamed_subpaths(env.deps.storage, info.address.clone())?,
+    };
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "remove_path"),
+        attr("addr", info.address),
+        attr("name", name),
+        attr("parent", parent_addr),
+        attr("removed_subpaths", removed.len().to_string()),
+    ]))
+}
+
+pub fn rename_path(
+    env: ExecuteEnv,
+    name: String,
+    new_name: String,
+    parent_address: Option<AndrAddr>,
+) -> Result<Response, ContractError> {
+    let name = name.to_lowercase();
+    let new_name = new_name.to_lowercase();
+    let parent_addr = resolve_parent_address(&env, parent_address)?;
+    validate_component_name(new_name.clone())?;
+    move_pathname(
+        env.deps.storage,
+        parent_addr.clone(),
+        name.clone(),
+        parent_addr.clone(),
+        new_name.clone(),
+    )?;
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "rename_path"),
+        attr("name", name),
+        attr("new_name", new_name),
+        attr("parent", parent_addr),
+    ]))
+}
+
+pub fn move_path(
+    env: ExecuteEnv,
+    name: String,
+    parent_address: Option<AndrAddr>,
+    new_parent_address: Option<AndrAddr>,
+) -> Result<Response, ContractError> {
+    let name = name.to_lowercase();
+    let parent_addr = resolve_parent_address(&env, parent_address)?;
+    let new_parent_addr = resolve_parent_address(&env, new_parent_address)?;
+    ensure!(
+        parent_addr != new_parent_addr,
+        ContractError::InvalidPathname {
+            error: Some("Path is already in the given directory".to_string())
+        }
+    );
+    move_pathname(
+        env.deps.storage,
+        parent_addr.clone(),
+        name.clone(),
+        new_parent_addr.clone(),
+        name.clone(),
+    )?;
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "move_path"),
+        attr("name", name),
+        attr("parent", parent_addr),
+        attr("new_parent", new_parent_addr),
+    ]))
+}
+
 pub fn add_child(
     env: ExecuteEnv,
     name: String,
@@ -219,6 +293,116 @@ pub fn register_user(
     ]))
 }
 
+pub fn transfer_username(env: ExecuteEnv, address: Addr) -> Result<Response, ContractError> {
+    let ExecuteEnv { deps, info, .. } = env;
+    let Some(username) = ADDRESS_USERNAME.may_load(deps.storage, info.sender.as_str())? else {
+        return Err(ContractError::InvalidUsername {
+            error: Some("Sender does not have a username".to_string()),
+        });
+    };
+    // Usernames that are valid addresses must remain with that address
+    ensure!(
+        deps.api.addr_validate(&username).is_err(),
+        ContractError::InvalidUsername {
+            error: Some("Usernames that are valid addresses cannot be transferred".to_string())
+        }
+    );
+    let address = deps.api.addr_validate(address.as_str())?;
+    ensure!(
+        !ADDRESS_USERNAME.has(deps.storage, address.as_str()),
+        ContractError::InvalidUsername {
+            error: Some("Recipient already has a username".to_string())
+        }
+    );
+
+    // The transfer is only completed once accepted by the recipient
+    PENDING_USERNAME_TRANSFERS.save(
+        deps.storage,
+        username.as_str(),
+        &(info.sender.clone(), address.clone()),
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "transfer_username"),
+        attr("username", username),
+        attr("from", info.sender),
+        attr("to", address),
+    ]))
+}
+
+pub fn accept_username(env: ExecuteEnv, username: String) -> Result<Response, ContractError> {
+    let ExecuteEnv { deps, info, .. } = env;
+    let username = username.to_lowercase();
+    let Some((from, to)) = PENDING_USERNAME_TRANSFERS.may_load(deps.storage, username.as_str())?
+    else {
+        return Err(ContractError::InvalidUsername {
+            error: Some(format!("No pending transfer for {username}")),
+        });
+    };
+    ensure!(info.sender == to, ContractError::Unauthorized {});
+    PENDING_USERNAME_TRANSFERS.remove(deps.storage, username.as_str());
+    // The username may have been released or re-registered since the transfer was proposed
+    ensure!(
+        USERS.may_load(deps.storage, username.as_str())? == Some(from.clone()),
+        ContractError::InvalidUsername {
+            error: Some(format!("{username} is no longer owned by {from}"))
+        }
+    );
+    ensure!(
+        !ADDRESS_USERNAME.has(deps.storage, to.as_str()),
+        ContractError::InvalidUsername {
+            error: Some("Recipient already has a username".to_string())
+        }
+    );
+
+    USERS.save(deps.storage, username.as_str(), &to)?;
+    ADDRESS_USERNAME.remove(deps.storage, from.as_str());
+    ADDRESS_USERNAME.save(deps.storage, to.as_str(), &username)?;
+
+    // Paths registered directly under the username move with it
+    let home_paths: Vec<PathInfo> = paths()
+        .idx
+        .parent
+        .prefix(from.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(_, info)| info))
+        .collect::<Result<_, _>>()?;
+    for path in home_paths {
+        move_pathname(
+            deps.storage,
+            from.clone(),
+            path.name.clone(),
+            to.clone(),
+            path.name,
+        )?;
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "accept_username"),
+        attr("username", username),
+        attr("from", from),
+        attr("to", to),
+    ]))
+}
+
+pub fn unregister_user(env: ExecuteEnv) -> Result<Response, ContractError> {
+    let Some(username) = ADDRESS_USERNAME.may_load(env.deps.storage, env.info.sender.as_str())?
+    else {
+        return Err(ContractError::InvalidUsername {
+            error: Some("Sender does not have a username".to_string()),
+        });
+    };
+    USERS.remove(env.deps.storage, username.as_str());
+    ADDRESS_USERNAME.remove(env.deps.storage, env.info.sender.as_str());
+    PENDING_USERNAME_TRANSFERS.remove(env.deps.storage, username.as_str());
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "unregister_user"),
+        attr("addr", env.info.sender),
+        attr("username", username),
+    ]))
+}
+
 pub fn register_library(
     env: ExecuteEnv,
     lib_name: String,
@@ -244,6 +428,31 @@ pub fn register_library(
     ]))
 }
 
+pub fn update_symlink_max_depth(env: ExecuteEnv, max_depth: u8) -> Result<Response, ContractError> {
+    let kernel_address = ADOContract::default().get_kernel_address(env.deps.storage)?;
+    ensure!(
+        env.info.sender == kernel_address
+            || ADOContract::default()
+                .is_contract_owner(env.deps.storage, env.info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure!(
+        max_depth > 0 && max_depth <= MAX_DEPTH,
+        ContractError::InvalidParameter {
+            error: Some(format!(
+                "Symlink max depth must be between 1 and {MAX_DEPTH}"
+            ))
+        }
+    );
+
+    SYMLINK_MAX_DEPTH.save(env.deps.storage, &max_depth)?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_symlink_max_depth"),
+        attr("max_depth", max_depth.to_string()),
+    ]))
+}
+
 pub fn register_user_cross_chain(
     env: ExecuteEnv,
     chain: String,
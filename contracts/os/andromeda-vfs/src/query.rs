@@ -4,7 +4,7 @@ use cosmwasm_std::{Addr, Deps};
 
 use crate::state::{
     get_paths, get_subdir, resolve_pathname, resolve_symlink, PathInfo, ADDRESS_LIBRARY,
-    ADDRESS_USERNAME,
+    ADDRESS_USERNAME, DEFAULT_SYMLINK_MAX_DEPTH, SYMLINK_MAX_DEPTH,
 };
 
 pub fn resolve_path(deps: Deps, path: AndrAddr) -> Result<Addr, ContractError> {
@@ -30,6 +30,12 @@ pub fn get_symlink(deps: Deps, addr: AndrAddr) -> Result<AndrAddr, ContractError
     resolve_symlink(deps.storage, deps.api, addr)
 }
 
+pub fn symlink_max_depth(deps: Deps) -> Result<u8, ContractError> {
+    Ok(SYMLINK_MAX_DEPTH
+        .may_load(deps.storage)?
+        .unwrap_or(DEFAULT_SYMLINK_MAX_DEPTH))
+}
+
 pub fn get_username(deps: Deps, addr: Addr) -> Result<String, ContractError> {
     let username = ADDRESS_USERNAME
         .may_load(deps.storage, addr.to_string().as_str())?
@@ -4,7 +4,7 @@ use andromeda_std::{
     os::vfs::{validate_path_name, SubDirBound},
 };
 use cosmwasm_std::{ensure, Addr, Api, Storage};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -50,6 +50,13 @@ pub const USERS: Map<&str, Addr> = Map::new("users");
 pub const LIBRARIES: Map<&str, Addr> = Map::new("libraries");
 pub const ADDRESS_USERNAME: Map<&str, String> = Map::new("address_username");
 pub const ADDRESS_LIBRARY: Map<&str, String> = Map::new("address_library");
+/// Proposed username transfers awaiting acceptance, keyed by username and storing the current owner and the recipient
+pub const PENDING_USERNAME_TRANSFERS: Map<&str, (Addr, Addr)> =
+    Map::new("pending_username_transfers");
+/// The maximum number of symlinks followed when resolving a path
+pub const SYMLINK_MAX_DEPTH: Item<u8> = Item::new("symlink_max_depth");
+
+pub const DEFAULT_SYMLINK_MAX_DEPTH: u8 = 10;
 
 /**
    Splits a pathname into its components.
@@ -76,6 +83,25 @@ pub fn resolve_pathname(
     api: &dyn Api,
     pathname: AndrAddr,
     resolved_paths: &mut Vec<(Addr, String)>,
+) -> Result<Addr, ContractError> {
+    resolve_pathname_at_depth(storage, api, pathname, resolved_paths, &mut 0)
+}
+
+/**
+   Resolves a given path to an address, following any symlinks within it as they are reached.
+
+    * **storage**: CosmWasm storage struct
+    * **api**: CosmWasm API struct
+    * **path**: The full path to be resolved
+    * **resolved_paths**: A vector of resolved paths to prevent looping or paths that are too long
+    * **symlink_depth**: The number of symlinks followed so far, limited to the configured maximum
+*/
+fn resolve_pathname_at_depth(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    pathname: AndrAddr,
+    resolved_paths: &mut Vec<(Addr, String)>,
+    symlink_depth: &mut u8,
 ) -> Result<Addr, ContractError> {
     let pathname = pathname.to_lowercase();
     // As cross-chain queries are not currently possible we need to ensure the pathname being resolved is local
@@ -88,8 +114,8 @@ pub fn resolve_pathname(
 
     if pathname.is_vfs_path() {
         match pathname.get_root_dir() {
-            "home" => resolve_home_path(storage, api, pathname, resolved_paths),
-            "lib" => resolve_lib_path(storage, api, pathname, resolved_paths),
+            "home" => resolve_home_path(storage, api, pathname, resolved_paths, symlink_depth),
+            "lib" => resolve_lib_path(storage, api, pathname, resolved_paths, symlink_depth),
             &_ => Err(ContractError::InvalidAddress {}),
         }
     } else {
@@ -104,29 +130,51 @@ pub fn resolve_pathname(
     * **api**: CosmWasm API struct
     * **path**: The full path to be resolved
     * **resolved_paths**: A vector of resolved paths to prevent looping or paths that are too long
+    * **symlink_depth**: The number of symlinks followed so far, limited to the configured maximum
 */
 fn resolve_home_path(
     storage: &dyn Storage,
     api: &dyn Api,
     path: AndrAddr,
     resolved_paths: &mut Vec<(Addr, String)>,
+    symlink_depth: &mut u8,
 ) -> Result<Addr, ContractError> {
     validate_path_name(api, path.to_string())?;
     let parts = split_pathname(path.to_string());
 
     let amount_to_skip = if parts[0].starts_with('~') { 0 } else { 1 };
-    let username_or_address = parts[amount_to_skip]
-        .strip_prefix('~')
-        .unwrap_or(&parts[amount_to_skip]);
-    let user_address = match api.addr_validate(username_or_address) {
-        Ok(addr) => addr,
-        Err(_e) => USERS.load(storage, username_or_address)?,
-    };
+    let user_address = resolve_home_root(storage, api, &parts[amount_to_skip])?;
 
     let mut remaining_parts = parts.to_vec();
 
     remaining_parts.drain(0..amount_to_skip + 1);
-    resolve_path(storage, api, remaining_parts, user_address, resolved_paths)
+    resolve_path(
+        storage,
+        api,
+        remaining_parts,
+        user_address,
+        resolved_paths,
+        symlink_depth,
+    )
+}
+
+/**
+   Resolves the user directory of a home path, accepting either a username or an address.
+
+    * **storage**: CosmWasm storage struct
+    * **api**: CosmWasm API struct
+    * **part**: The user section of the path, optionally prefixed with '~'
+*/
+fn resolve_home_root(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    part: &str,
+) -> Result<Addr, ContractError> {
+    let username_or_address = part.strip_prefix('~').unwrap_or(part);
+    match api.addr_validate(username_or_address) {
+        Ok(addr) => Ok(addr),
+        Err(_e) => Ok(USERS.load(storage, username_or_address)?),
+    }
 }
 
 /**
@@ -136,27 +184,49 @@ fn resolve_home_path(
     * **api**: CosmWasm API struct
     * **path**: The full path to be resolved
     * **resolved_paths**: A vector of resolved paths to prevent looping or paths that are too long
+    * **symlink_depth**: The number of symlinks followed so far, limited to the configured maximum
 */
 fn resolve_lib_path(
     storage: &dyn Storage,
     api: &dyn Api,
     path: AndrAddr,
     resolved_paths: &mut Vec<(Addr, String)>,
+    symlink_depth: &mut u8,
 ) -> Result<Addr, ContractError> {
     let parts = split_pathname(path.to_string());
 
-    let library_or_address = parts[1].as_str();
-
-    let lib_address = match api.addr_validate(library_or_address) {
-        Ok(addr) => addr,
-        Err(_e) => LIBRARIES.load(storage, library_or_address)?,
-    };
+    let lib_address = resolve_lib_root(storage, api, parts[1].as_str())?;
     let mut remaining_parts = parts.to_vec();
     remaining_parts.drain(0..2);
-    resolve_path(storage, api, remaining_parts, lib_address, resolved_paths)
+    resolve_path(
+        storage,
+        api,
+        remaining_parts,
+        lib_address,
+        resolved_paths,
+        symlink_depth,
+    )
+}
+
+/**
+   Resolves the library directory of a library path, accepting either a library name or an address.
+
+    * **storage**: CosmWasm storage struct
+    * **api**: CosmWasm API struct
+    * **part**: The library section of the path
+*/
+fn resolve_lib_root(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    part: &str,
+) -> Result<Addr, ContractError> {
+    match api.addr_validate(part) {
+        Ok(addr) => Ok(addr),
+        Err(_e) => Ok(LIBRARIES.load(storage, part)?),
+    }
 }
 
-const MAX_DEPTH: u8 = 50;
+pub const MAX_DEPTH: u8 = 50;
 
 /**
    Resolves a given path after the first section has been resolved.
@@ -168,6 +238,7 @@ const MAX_DEPTH: u8 = 50;
     * **parts**: The remaining parts of the path to resolve
     * **parent_address**: The address of the parent lib/user
     * **resolved_paths**: A vector of resolved paths to prevent looping or paths that are too long
+    * **symlink_depth**: The number of symlinks followed so far, limited to the configured maximum
 */
 fn resolve_path(
     storage: &dyn Storage,
@@ -175,6 +246,7 @@ fn resolve_path(
     parts: Vec<String>,
     parent_address: Addr,
     resolved_paths: &mut Vec<(Addr, String)>,
+    symlink_depth: &mut u8,
 ) -> Result<Addr, ContractError> {
     let mut address = parent_address;
     // Preemptive length check to prevent resolving paths that are too long
@@ -199,7 +271,17 @@ fn resolve_path(
         let info = paths().load(storage, &(address.clone(), part.clone()))?;
         resolved_paths.push((address, part.clone()));
         address = match info.symlink {
-            Some(symlink) => resolve_pathname(storage, api, symlink, resolved_paths)?,
+            Some(symlink) => {
+                let max_depth = SYMLINK_MAX_DEPTH
+                    .may_load(storage)?
+                    .unwrap_or(DEFAULT_SYMLINK_MAX_DEPTH);
+                ensure!(
+                    *symlink_depth < max_depth,
+                    ContractError::SymlinkDepthExceeded { max_depth }
+                );
+                *symlink_depth += 1;
+                resolve_pathname_at_depth(storage, api, symlink, resolved_paths, symlink_depth)?
+            }
             None => info.address,
         };
     }
@@ -300,38 +382,209 @@ pub fn add_path_symlink(
             symlink: Some(symlink.clone()),
         },
     )?;
-    if symlink.get_protocol().is_none() {
-        // Ensure that the symlink resolves to a valid address
-        let pathname = AndrAddr::from_string(format!("~{}/{}", parent_addr, name));
+    // Ensure that the symlink chain terminates, and that it resolves to a valid address if it is local
+    let pathname = resolve_symlink(
+        storage,
+        api,
+        AndrAddr::from_string(format!("~{}/{}", parent_addr, name)),
+    )?;
+    if pathname.get_protocol().is_none() {
         resolve_pathname(storage, api, pathname, &mut vec![])?;
     }
 
     Ok(())
 }
 
+pub fn remove_pathname(
+    storage: &mut dyn Storage,
+    parent_addr: Addr,
+    name: String,
+) -> Result<PathInfo, ContractError> {
+    let key = (parent_addr, name);
+    let Some(info) = paths().may_load(storage, &key)? else {
+        return Err(ContractError::InvalidPathname {
+            error: Some(format!("{} does not exist in {}", key.1, key.0)),
+        });
+    };
+    paths().remove(storage, &key)?;
+    Ok(info)
+}
+
+/**
+   Removes every path beneath the given directory once no path names the directory, continuing with any subdirectory left unnamed by the removal.
+
+   Symlinks do not name a directory, their targets are resolved on use.
+
+    * **storage**: CosmWasm storage struct
+    * **address**: The address of the directory
+*/
+pub fn remove_unnamed_subpaths(
+    storage: &mut dyn Storage,
+    address: Addr,
+) -> Result<Vec<PathInfo>, ContractError> {
+    let mut removed = vec![];
+    let mut dirs = vec![address];
+    while let Some(dir) = dirs.pop() {
+        if is_named(storage, &dir) {
+            continue;
+        }
+        let children: Vec<PathInfo> = paths()
+            .idx
+            .parent
+            .prefix(dir)
+            .range(storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|r| r.map(|(_, info)| info))
+            .collect::<Result<_, _>>()?;
+        for child in children {
+            paths().remove(storage, &(child.parent_address.clone(), child.name.clone()))?;
+            if child.symlink.is_none() {
+                dirs.push(child.address.clone());
+            }
+            removed.push(child);
+        }
+    }
+    Ok(removed)
+}
+
+/// Whether any path names the given address
+fn is_named(storage: &dyn Storage, address: &Addr) -> bool {
+    paths()
+        .idx
+        .address
+        .prefix(address.clone())
+        .keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/**
+   Moves a path to a new parent directory and/or name, the parent index is updated accordingly.
+
+    * **storage**: CosmWasm storage struct
+    * **parent_addr**: The current parent of the path
+    * **name**: The current name of the path
+    * **new_parent_addr**: The parent to move the path to
+    * **new_name**: The name to give the path
+*/
+pub fn move_pathname(
+    storage: &mut dyn Storage,
+    parent_addr: Addr,
+    name: String,
+    new_parent_addr: Addr,
+    new_name: String,
+) -> Result<(), ContractError> {
+    let new_key = (new_parent_addr.clone(), new_name.clone());
+    ensure!(
+        paths().may_load(storage, &new_key)?.is_none(),
+        ContractError::InvalidPathname {
+            error: Some(format!("{new_name} already exists in {new_parent_addr}"))
+        }
+    );
+    let info = remove_pathname(storage, parent_addr, name)?;
+    paths().save(
+        storage,
+        &new_key,
+        &PathInfo {
+            name: new_name,
+            parent_address: new_parent_addr,
+            ..info
+        },
+    )?;
+    Ok(())
+}
+
+/**
+   Fully expands any symlinks within a given path, following chained symlinks and symlinked directories.
+
+   Resolution stops once the path contains no further symlinks or it points to another chain (`ibc://`).
+   Errors if a symlink cycle is found or more than the configured maximum number of symlinks are followed.
+
+    * **storage**: CosmWasm storage struct
+    * **api**: CosmWasm API struct
+    * **path**: The full path to be expanded
+*/
 pub fn resolve_symlink(
     storage: &dyn Storage,
     api: &dyn Api,
     path: AndrAddr,
 ) -> Result<AndrAddr, ContractError> {
-    let mut parts = split_pathname(path.to_string());
-    if !path.is_vfs_path() || path.get_protocol().is_some() || parts.len() <= 2 {
-        return Ok(path);
+    let max_depth = SYMLINK_MAX_DEPTH
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_SYMLINK_MAX_DEPTH);
+    let mut path = path;
+    let mut visited: Vec<String> = vec![];
+    while let Some(expanded) = expand_symlink(storage, api, &path)? {
+        let current = path.to_string().trim_end_matches('/').to_string();
+        ensure!(
+            !visited.contains(&current),
+            ContractError::SymlinkCycle { path: current }
+        );
+        ensure!(
+            (visited.len() as u8) < max_depth,
+            ContractError::SymlinkDepthExceeded { max_depth }
+        );
+        visited.push(current);
+        path = expanded;
     }
-    let final_part = parts.pop().unwrap();
-    let reconstructed_addr = parts.join("/");
-    // Need to prepend a '/' unless the path starts with '~'
-    let remaining_path = if reconstructed_addr.starts_with('~') {
-        AndrAddr::from_string(reconstructed_addr)
-    } else {
-        AndrAddr::from_string(format!("/{reconstructed_addr}"))
+
+    Ok(path)
+}
+
+/**
+   Replaces the first symlink found within a given path with its target, returns `None` if the path contains no symlinks.
+
+    * **storage**: CosmWasm storage struct
+    * **api**: CosmWasm API struct
+    * **path**: The full path to be expanded
+*/
+fn expand_symlink(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    path: &AndrAddr,
+) -> Result<Option<AndrAddr>, ContractError> {
+    if !path.is_vfs_path() || path.get_protocol().is_some() {
+        return Ok(None);
+    }
+    let parts = split_pathname(path.to_string());
+    if parts.is_empty() {
+        return Ok(None);
+    }
+    let amount_to_skip = match path.get_root_dir() {
+        "home" if parts[0].starts_with('~') => 1,
+        "home" | "lib" => 2,
+        &_ => return Ok(None),
+    };
+    if parts.len() <= amount_to_skip {
+        return Ok(None);
+    }
+
+    let mut address = match path.get_root_dir() {
+        "lib" => resolve_lib_root(storage, api, &parts[1])?,
+        &_ => resolve_home_root(storage, api, &parts[amount_to_skip - 1])?,
     };
-    let addr = resolve_pathname(storage, api, remaining_path, &mut vec![])?;
-    let info = paths().load(storage, &(addr, final_part))?;
-    match info.symlink {
-        Some(symlink) => Ok(symlink),
-        None => Ok(path),
+    for (idx, part) in parts.iter().enumerate().skip(amount_to_skip) {
+        let info = paths().load(storage, &(address, part.clone()))?;
+        if let Some(symlink) = info.symlink {
+            let remaining_parts = &parts[idx + 1..];
+            if remaining_parts.is_empty() {
+                return Ok(Some(symlink));
+            }
+            let target = symlink.to_string().trim_end_matches('/').to_string();
+            // Symlinks to a raw address are treated as that address' home directory
+            let target = if symlink.is_vfs_path() {
+                target
+            } else {
+                format!("~{target}")
+            };
+            return Ok(Some(AndrAddr::from_string(format!(
+                "{target}/{}",
+                remaining_parts.join("/")
+            ))));
+        }
+        address = info.address;
     }
+
+    Ok(None)
 }
 
 #[cfg(test)]
@@ -385,6 +638,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{username}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -394,6 +648,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("~{username}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -403,6 +658,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("~/{username}")),
             &mut vec![],
+            &mut 0,
         );
         assert!(res.is_err());
 
@@ -424,6 +680,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{username}/{first_directory}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -432,6 +689,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("~{username}/{first_directory}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -459,6 +717,7 @@ mod test {
                 "/home/{username}/{first_directory}/{second_directory}"
             )),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, second_directory_address);
@@ -483,6 +742,7 @@ mod test {
                 "/home/{username}/{first_directory}/{second_directory}/{file}"
             )),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, file_address)
@@ -510,6 +770,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("/lib/{lib_name}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -532,6 +793,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("/lib/{lib_name}/{first_directory}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -559,6 +821,7 @@ mod test {
                 "/lib/{lib_name}/{first_directory}/{second_directory}"
             )),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, second_directory_address);
@@ -583,6 +846,7 @@ mod test {
                 "/lib/{lib_name}/{first_directory}/{second_directory}/{file}"
             )),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, file_address)
@@ -619,6 +883,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{username}/{first_directory}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -641,6 +906,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{symlink_parent}/{symlink_name}")),
             &mut vec![],
+            &mut 0,
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -697,6 +963,117 @@ mod test {
         assert_eq!(res, AndrAddr::from_string("/home/someuser"));
     }
 
+    #[test]
+    fn test_resolve_symlink_recursive() {
+        let mut deps = mock_dependencies();
+        let username_address = Addr::unchecked("useraddress");
+        let first_directory_address = Addr::unchecked("dir1address");
+        let file_address = Addr::unchecked("fileaddress");
+        let lib_address = Addr::unchecked("libaddress");
+        let app_address = Addr::unchecked("appaddress");
+
+        USERS
+            .save(deps.as_mut().storage, "u1", &username_address)
+            .unwrap();
+        // Library names short enough not to be valid mock addresses resolve through the library registry
+        LIBRARIES
+            .save(deps.as_mut().storage, "l1", &lib_address)
+            .unwrap();
+
+        let DepsMut { api, storage, .. } = deps.as_mut();
+        add_pathname(
+            storage,
+            username_address.clone(),
+            "d1".to_string(),
+            first_directory_address.clone(),
+        )
+        .unwrap();
+        add_pathname(
+            storage,
+            first_directory_address,
+            "f1".to_string(),
+            file_address.clone(),
+        )
+        .unwrap();
+        add_pathname(storage, lib_address, "app".to_string(), app_address.clone()).unwrap();
+
+        let symlinks = [
+            ("alias", "/home/u1/d1"),
+            ("alias_two", "~u1/alias"),
+            ("lib_alias", "/lib/l1/app"),
+            ("remote", "ibc://chain/home/u2/app"),
+        ];
+        for (name, symlink) in symlinks {
+            add_path_symlink(
+                storage,
+                api,
+                username_address.clone(),
+                name.to_string(),
+                AndrAddr::from_string(symlink),
+            )
+            .unwrap();
+        }
+
+        // Chained symlink used as an intermediate directory
+        let res = resolve_symlink(
+            deps.as_ref().storage,
+            deps.as_ref().api,
+            AndrAddr::from_string("/home/u1/alias_two/f1"),
+        )
+        .unwrap();
+        assert_eq!(res, AndrAddr::from_string("/home/u1/d1/f1"));
+
+        let res = resolve_pathname(
+            deps.as_ref().storage,
+            deps.as_ref().api,
+            AndrAddr::from_string("/home/u1/alias_two/f1"),
+            &mut vec![],
+        )
+        .unwrap();
+        assert_eq!(res, file_address);
+
+        let res = resolve_pathname(
+            deps.as_ref().storage,
+            deps.as_ref().api,
+            AndrAddr::from_string("~u1/lib_alias"),
+            &mut vec![],
+        )
+        .unwrap();
+        assert_eq!(res, app_address);
+
+        let res = resolve_symlink(
+            deps.as_ref().storage,
+            deps.as_ref().api,
+            AndrAddr::from_string("~u1/remote/component"),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            AndrAddr::from_string("ibc://chain/home/u2/app/component")
+        );
+
+        SYMLINK_MAX_DEPTH.save(deps.as_mut().storage, &1).unwrap();
+        let res = resolve_symlink(
+            deps.as_ref().storage,
+            deps.as_ref().api,
+            AndrAddr::from_string("/home/u1/alias_two/f1"),
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            ContractError::SymlinkDepthExceeded { max_depth: 1 }
+        );
+        let res = resolve_pathname(
+            deps.as_ref().storage,
+            deps.as_ref().api,
+            AndrAddr::from_string("/home/u1/alias_two/f1"),
+            &mut vec![],
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            ContractError::SymlinkDepthExceeded { max_depth: 1 }
+        );
+    }
+
     #[test]
     fn test_resolve_path_too_long() {
         let mut deps = mock_dependencies();
@@ -715,6 +1092,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(path),
             &mut vec![],
+            &mut 0,
         );
 
         assert!(res.is_err());
@@ -736,6 +1114,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(path),
             &mut vec![],
+            &mut 0,
         );
 
         assert!(res.is_err());
@@ -768,6 +1147,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(path.clone()),
             &mut vec![],
+            &mut 0,
         );
 
         assert!(res.is_ok());
@@ -791,6 +1171,7 @@ mod test {
             deps.as_ref().api,
             AndrAddr::from_string(new_path),
             &mut vec![],
+            &mut 0,
         );
 
         assert!(res.is_err());
@@ -834,8 +1215,8 @@ mod test {
         assert!(res.is_err());
         assert_eq!(
             res.unwrap_err(),
-            ContractError::InvalidPathname {
-                error: Some("Pathname contains a looping reference".to_string())
+            ContractError::SymlinkCycle {
+                path: format!("/home/{username}/{first_directory}/{username}")
             }
         )
     }
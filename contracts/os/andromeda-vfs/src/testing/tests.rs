@@ -1,6 +1,8 @@
 use crate::{
     contract::{execute, instantiate, query},
-    state::{add_pathname, resolve_pathname, PathInfo, ADDRESS_LIBRARY, ADDRESS_USERNAME, USERS},
+    state::{
+        add_pathname, paths, resolve_pathname, PathInfo, ADDRESS_LIBRARY, ADDRESS_USERNAME, USERS,
+    },
 };
 
 use andromeda_std::{
@@ -840,3 +842,417 @@ fn test_get_paths() {
     let val: Vec<String> = from_json(res).unwrap();
     assert_eq!(val.len(), 2);
 }
+
+#[test]
+fn test_remove_path() {
+    let mut deps = mock_dependencies();
+    let sender = "sender";
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    let msg = ExecuteMsg::AddPath {
+        name: "f1".to_string(),
+        address: Addr::unchecked("f1addr"),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::RemovePath {
+        name: "f1".to_string(),
+        parent_address: Some(AndrAddr::from_string(sender)),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_the_owner", &[]),
+        msg,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Paths beneath f1 are removed along with it
+    let msg = ExecuteMsg::AddPath {
+        name: "f2".to_string(),
+        address: Addr::unchecked("f2addr"),
+        parent_address: Some(AndrAddr::from_string(format!("~{sender}/f1"))),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::AddPath {
+        name: "f3".to_string(),
+        address: Addr::unchecked("f3addr"),
+        parent_address: Some(AndrAddr::from_string(format!("~{sender}/f1/f2"))),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::RemovePath {
+        name: "f1".to_string(),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+    assert!(!paths().has(
+        deps.as_ref().storage,
+        &(Addr::unchecked("f1addr"), "f2".to_string())
+    ));
+    assert!(!paths().has(
+        deps.as_ref().storage,
+        &(Addr::unchecked("f2addr"), "f3".to_string())
+    ));
+    let res = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("~{sender}/f1")),
+        &mut vec![],
+    );
+    assert!(res.is_err());
+
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPathname {
+            error: Some(format!("f1 does not exist in {sender}"))
+        }
+    );
+
+    // Paths beneath a directory that is still named elsewhere are kept
+    for name in ["d1", "d2"] {
+        let msg = ExecuteMsg::AddPath {
+            name: name.to_string(),
+            address: Addr::unchecked("daddr"),
+            parent_address: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+    let msg = ExecuteMsg::AddPath {
+        name: "c1".to_string(),
+        address: Addr::unchecked("c1addr"),
+        parent_address: Some(AndrAddr::from_string(format!("~{sender}/d1"))),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::RemovePath {
+        name: "d1".to_string(),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env, info, msg).unwrap();
+    let resolved_addr = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("~{sender}/d2/c1")),
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(resolved_addr, Addr::unchecked("c1addr"));
+}
+
+#[test]
+fn test_rename_path() {
+    let mut deps = mock_dependencies();
+    let sender = "sender";
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    for (name, address) in [("f1", "f1addr"), ("f2", "f2addr")] {
+        let msg = ExecuteMsg::AddPath {
+            name: name.to_string(),
+            address: Addr::unchecked(address),
+            parent_address: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    // Cannot overwrite an existing path
+    let msg = ExecuteMsg::RenamePath {
+        name: "f1".to_string(),
+        new_name: "f2".to_string(),
+        parent_address: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPathname {
+            error: Some(format!("f2 already exists in {sender}"))
+        }
+    );
+
+    let msg = ExecuteMsg::RenamePath {
+        name: "f1".to_string(),
+        new_name: "f3".to_string(),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env, info, msg).unwrap();
+    let resolved_addr = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("~{sender}/f3")),
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(resolved_addr, Addr::unchecked("f1addr"));
+    assert!(resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("~{sender}/f1")),
+        &mut vec![],
+    )
+    .is_err());
+}
+
+#[test]
+fn test_move_path() {
+    let mut deps = mock_dependencies();
+    let sender = "sender";
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    for (name, address) in [("dir", "diraddr"), ("f1", "f1addr")] {
+        let msg = ExecuteMsg::AddPath {
+            name: name.to_string(),
+            address: Addr::unchecked(address),
+            parent_address: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    let msg = ExecuteMsg::MovePath {
+        name: "f1".to_string(),
+        parent_address: None,
+        new_parent_address: Some(AndrAddr::from_string(format!("~{sender}/dir"))),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_the_owner", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(deps.as_mut(), env, info, msg).unwrap();
+    let resolved_addr = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("~{sender}/dir/f1")),
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(resolved_addr, Addr::unchecked("f1addr"));
+
+    let subdir: Vec<PathInfo> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SubDir {
+                path: AndrAddr::from_string(format!("~{sender}")),
+                min: None,
+                max: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(subdir.len(), 1);
+    assert_eq!(subdir[0].name, "dir");
+}
+
+#[test]
+fn test_transfer_username() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let username = "u1";
+    let sender = "sender";
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    let msg = ExecuteMsg::RegisterUser {
+        username: username.to_string(),
+        address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::AddPath {
+        name: "f1".to_string(),
+        address: Addr::unchecked("f1addr"),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::TransferUsername {
+        address: Addr::unchecked("recipient"),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("no_username", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidUsername {
+            error: Some("Sender does not have a username".to_string())
+        }
+    );
+
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    // Nothing moves until the recipient accepts
+    assert_eq!(
+        USERS.load(deps.as_ref().storage, username).unwrap(),
+        Addr::unchecked(sender)
+    );
+
+    let msg = ExecuteMsg::AcceptUsername {
+        username: username.to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_the_recipient", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("recipient", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        USERS.load(deps.as_ref().storage, username).unwrap(),
+        Addr::unchecked("recipient")
+    );
+    assert_eq!(
+        ADDRESS_USERNAME
+            .load(deps.as_ref().storage, "recipient")
+            .unwrap(),
+        username
+    );
+    assert!(!ADDRESS_USERNAME.has(deps.as_ref().storage, sender));
+
+    let resolved_addr = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("~{username}/f1")),
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(resolved_addr, Addr::unchecked("f1addr"));
+
+    let err = execute(deps.as_mut(), env, mock_info("recipient", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidUsername {
+            error: Some(format!("No pending transfer for {username}"))
+        }
+    );
+}
+
+#[test]
+fn test_unregister_user() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let username = "u1";
+    let sender = "sender";
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    let msg = ExecuteMsg::RegisterUser {
+        username: username.to_string(),
+        address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::UnregisterUser {},
+    )
+    .unwrap();
+    assert!(!USERS.has(deps.as_ref().storage, username));
+    assert!(!ADDRESS_USERNAME.has(deps.as_ref().storage, sender));
+
+    let err = execute(deps.as_mut(), env, info, ExecuteMsg::UnregisterUser {}).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidUsername {
+            error: Some("Sender does not have a username".to_string())
+        }
+    );
+}
+
+#[test]
+fn test_update_symlink_max_depth() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate_contract(deps.as_mut(), env.clone(), owner.clone());
+
+    let depth: u8 =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::SymlinkMaxDepth {}).unwrap())
+            .unwrap();
+    assert_eq!(depth, 10);
+
+    let msg = ExecuteMsg::UpdateSymlinkMaxDepth { max_depth: 5 };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("attacker", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        ExecuteMsg::UpdateSymlinkMaxDepth { max_depth: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some("Symlink max depth must be between 1 and 50".to_string())
+        }
+    );
+
+    execute(deps.as_mut(), env.clone(), owner, msg).unwrap();
+    let depth: u8 =
+        from_json(query(deps.as_ref(), env, QueryMsg::SymlinkMaxDepth {}).unwrap()).unwrap();
+    assert_eq!(depth, 5);
+}
+
+#[test]
+fn test_pause() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate_contract(deps.as_mut(), env.clone(), owner.clone());
+
+    let msg = ExecuteMsg::Pause {
+        actions: Some(vec!["RegisterUser".to_string()]),
+        until: None,
+    };
+    execute(deps.as_mut(), env.clone(), owner.clone(), msg).unwrap();
+
+    let register_user = ExecuteMsg::RegisterUser {
+        username: "u1".to_string(),
+        address: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[]),
+        register_user.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Paused {});
+
+    execute(deps.as_mut(), env.clone(), owner, ExecuteMsg::Unpause {}).unwrap();
+    execute(deps.as_mut(), env, mock_info("sender", &[]), register_user).unwrap();
+}
@@ -154,6 +154,9 @@ fn prepare_validator_staking(
         name: "Validator Staking App".to_string(),
         owner: None,
         chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
     };
 
     app_contract.instantiate(&app_init_msg, None, None).unwrap();
@@ -0,0 +1,128 @@
+use andromeda_app::app;
+use andromeda_std::amp::AndrAddr;
+use andromeda_std::os::kernel;
+use andromeda_testing_e2e::kernel::KernelContract;
+use cosmwasm_std::to_json_binary;
+use cw_orch::environment::ChainKind;
+use cw_orch::environment::NetworkInfo;
+use cw_orch::prelude::*;
+use cw_orch_daemon::queriers::Node;
+use cw_orch_daemon::Daemon;
+
+const TESTNET_MNEMONIC: &str = "across left ignore gold echo argue track joy hire release captain enforce hotel wide flash hotel brisk joke midnight duck spare drop chronic stool";
+
+const OSMO_CHAIN_NAME: &str = "osmosis";
+
+pub const TERRA_NETWORK: NetworkInfo = NetworkInfo {
+    chain_name: "terra",
+    pub_address_prefix: "terra",
+    coin_type: 330u32,
+};
+
+pub const LOCAL_TERRA: ChainInfo = ChainInfo {
+    kind: ChainKind::Local,
+    chain_id: "localterraa-1",
+    gas_denom: "uluna",
+    gas_price: 0.15,
+    grpc_urls: &["http://localhost:20331"],
+    network_info: TERRA_NETWORK,
+    lcd_url: None,
+    fcd_url: None,
+};
+
+pub const OSMO_NETWORK: NetworkInfo = NetworkInfo {
+    chain_name: "osmosis",
+    pub_address_prefix: "osmo",
+    coin_type: 118u32,
+};
+
+pub const LOCAL_OSMO: ChainInfo = ChainInfo {
+    kind: ChainKind::Local,
+    chain_id: "localosmosisa-1",
+    gas_denom: "uosmo",
+    gas_price: 0.1,
+    grpc_urls: &["http://localhost:20321"],
+    network_info: OSMO_NETWORK,
+    lcd_url: None,
+    fcd_url: None,
+};
+
+#[test]
+fn test_cross_chain_create() {
+    let terra = Daemon::builder(LOCAL_TERRA)
+        .mnemonic(TESTNET_MNEMONIC)
+        .build()
+        .unwrap();
+    let osmo = Daemon::builder(LOCAL_OSMO)
+        .mnemonic(TESTNET_MNEMONIC)
+        .build()
+        .unwrap();
+    let owner = osmo.sender_addr().to_string();
+    // Kernels deployed on both chains with a direct channel assigned between them
+    let terra_kernel = std::env::var("TERRA_KERNEL_ADDRESS").expect("TERRA_KERNEL_ADDRESS not set");
+    let osmo_kernel = std::env::var("OSMO_KERNEL_ADDRESS").expect("OSMO_KERNEL_ADDRESS not set");
+
+    let kernel_contract = KernelContract::new(terra.clone());
+    kernel_contract.set_address(&Addr::unchecked(terra_kernel));
+
+    // An empty app is created on Osmosis through the Terra kernel
+    let app_init_msg = app::InstantiateMsg {
+        app_components: vec![],
+        kernel_address: osmo_kernel,
+        name: "Cross Chain App".to_string(),
+        owner: Some(owner.clone()),
+        chain_info: None,
+        allow_deprecated: None,
+        template: None,
+        app_is_component_admin: None,
+    };
+    let create_msg = kernel::ExecuteMsg::Create {
+        ado_type: "app-contract".to_string(),
+        msg: to_json_binary(&app_init_msg).unwrap(),
+        owner: Some(AndrAddr::from_string(owner.clone())),
+        chain: Some(OSMO_CHAIN_NAME.to_string()),
+        force: None,
+    };
+    kernel_contract.execute(&create_msg, None).unwrap();
+
+    // The relayer acknowledges the packet with the address of the new ADO
+    let node = Node::new(&terra);
+    let events = vec![
+        "wasm.method='ibc_create_ado_ack'".to_string(),
+        format!("wasm.owner='{owner}'"),
+    ];
+    let mut acks = vec![];
+    while acks.is_empty() {
+        println!("================================waiting for the create acknowledgement================================");
+        terra.wait_seconds(10).unwrap();
+        acks = terra
+            .rt_handle
+            .block_on(async {
+                node._find_some_tx_by_events(events.clone(), None, None)
+                    .await
+            })
+            .unwrap();
+    }
+
+    let ack_event = acks
+        .last()
+        .unwrap()
+        .get_events("wasm")
+        .into_iter()
+        .find(|event| {
+            event.get_first_attribute_value("method") == Some("ibc_create_ado_ack".to_string())
+        })
+        .unwrap();
+    assert_eq!(
+        ack_event.get_first_attribute_value("success"),
+        Some("true".to_string())
+    );
+    let ado_address = ack_event.get_first_attribute_value("ado_address").unwrap();
+
+    // The ADO exists on Osmosis and is owned by the requested owner
+    let ado_owner: andromeda_std::ado_base::ownership::ContractOwnerResponse = osmo
+        .wasm_querier()
+        .smart_query(ado_address, &app::QueryMsg::Owner {})
+        .unwrap();
+    assert_eq!(ado_owner.owner, owner);
+}
@@ -4,6 +4,7 @@ use andromeda_std::{
     common::reply::ReplyId,
     error::ContractError,
     os::{
+        adodb::{escape_json_string, TEMPLATE_OWNER_PARAM},
         aos_querier::AOSQuerier,
         vfs::{convert_component_name, ExecuteMsg as VFSExecuteMsg},
     },
@@ -11,7 +12,7 @@ use andromeda_std::{
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
     attr, ensure, instantiate2_address, to_json_binary, wasm_execute, Addr, Api, Binary,
-    CodeInfoResponse, Deps, Event, QuerierWrapper, SubMsg, WasmMsg,
+    CodeInfoResponse, Coin, Deps, Event, QuerierWrapper, SubMsg, WasmMsg,
 };
 use serde::Serialize;
 
@@ -37,7 +38,7 @@ pub struct CrossChainComponent {
 pub enum ComponentType {
     New(Binary),
     Symlink(AndrAddr),
-    #[serde(skip)]
+    /// A component instantiated by a mirror app on another chain, requires `chain_info` for the chain
     CrossChain(CrossChainComponent),
 }
 
@@ -78,6 +79,9 @@ pub struct AppComponent {
     pub name: String,
     pub ado_type: String,
     pub component_type: ComponentType,
+    /// Components that must be instantiated before this one, whose `${component}` address and `${component.data}`
+    /// instantiation data placeholders are substituted in the instantiate message
+    pub depends_on: Option<Vec<String>>,
 }
 
 impl AppComponent {
@@ -90,6 +94,7 @@ impl AppComponent {
             name: name.into(),
             ado_type: ado_type.into(),
             component_type: ComponentType::New(instantiate_msg),
+            depends_on: None,
         }
     }
 
@@ -102,9 +107,50 @@ impl AppComponent {
             ado_type: ado_type.into(),
             name: name.into(),
             component_type: ComponentType::Symlink(AndrAddr::from_string(symlink.into())),
+            depends_on: None,
         }
     }
 
+    pub fn with_depends_on(mut self, depends_on: Vec<String>) -> AppComponent {
+        self.depends_on = Some(depends_on);
+        self
+    }
+
+    /// Gets the components that must be instantiated before this one, as listed in `depends_on`
+    pub fn get_dependencies(&self) -> Vec<String> {
+        self.depends_on.clone().unwrap_or_default()
+    }
+
+    /// Substitutes the placeholders of the instantiate message with the given values, escaped as JSON string contents.
+    ///
+    /// Each `(key, value)` pair replaces the `${key}` placeholders. Errors if the message uses a `${dependency}` or
+    /// `${dependency.data}` placeholder of a dependency that has no value.
+    pub fn with_substitutions(
+        mut self,
+        substitutions: &[(String, String)],
+    ) -> Result<AppComponent, ContractError> {
+        if let ComponentType::New(msg) = &self.component_type {
+            let mut msg = String::from_utf8_lossy(msg.as_slice()).to_string();
+            for dependency in self.get_dependencies() {
+                for key in [dependency.clone(), format!("{dependency}.data")] {
+                    ensure!(
+                        !msg.contains(&format!("${{{key}}}"))
+                            || substitutions.iter().any(|(k, _)| k == &key),
+                        ContractError::InvalidComponentDependency {
+                            name: self.name.clone(),
+                            dependency: dependency.clone(),
+                        }
+                    );
+                }
+            }
+            for (key, value) in substitutions.iter() {
+                msg = msg.replace(&format!("${{{key}}}"), &escape_json_string(value)?);
+            }
+            self.component_type = ComponentType::New(Binary::from(msg.into_bytes()));
+        }
+        Ok(self)
+    }
+
     pub fn verify(&self, _deps: &Deps) -> Result<(), ContractError> {
         if self.name.is_empty() {
             panic!("name cannot be empty");
@@ -227,6 +273,7 @@ impl AppComponent {
                     component_type: ComponentType::Symlink(AndrAddr::from_string(format!(
                         "ibc://{chain}/home/{owner_addr}/{app_name}/{name}"
                     ))),
+                    depends_on: None,
                 };
                 new_component.generate_vfs_registration(
                     new_addr,
@@ -243,6 +290,7 @@ impl AppComponent {
     /// Generates an instantiation message for the component.
     ///
     /// Returns `None` for `Symlink` and `CrossChain` components.
+    /// Errors if the component's ADO version is deprecated, unless `allow_deprecated` is set.
     pub fn generate_instantiation_message(
         &self,
         querier: &QuerierWrapper,
@@ -250,9 +298,15 @@ impl AppComponent {
         parent_addr: &Addr,
         sender: &str,
         idx: u64,
+        allow_deprecated: bool,
     ) -> Result<Option<SubMsg>, ContractError> {
         if let ComponentType::New(instantiate_msg) = self.component_type.clone() {
-            let code_id = AOSQuerier::code_id_getter(querier, adodb_addr, &self.ado_type)?;
+            let code_id = AOSQuerier::non_deprecated_code_id_getter(
+                querier,
+                adodb_addr,
+                &self.ado_type,
+                allow_deprecated,
+            )?;
             let salt = self.get_salt(parent_addr.clone());
             let inst_msg = WasmMsg::Instantiate2 {
                 admin: Some(sender.to_string()),
@@ -294,6 +348,49 @@ impl AppComponent {
     }
 }
 
+/// Orders the components so that each component comes after the components it depends on.
+///
+/// Errors if a component depends on a component that is not a new component of the app, or if the dependencies are cyclic.
+pub fn order_by_dependencies(components: &[AppComponent]) -> Result<Vec<String>, ContractError> {
+    let mut remaining: Vec<(String, Vec<String>)> = vec![];
+    for component in components.iter() {
+        let dependencies = component.get_dependencies();
+        for dependency in dependencies.iter() {
+            let is_new_component = components.iter().any(|other| {
+                &other.name == dependency && matches!(other.component_type, ComponentType::New(..))
+            });
+            ensure!(
+                is_new_component,
+                ContractError::InvalidComponentDependency {
+                    name: component.name.clone(),
+                    dependency: dependency.clone(),
+                }
+            );
+        }
+        remaining.push((component.name.clone(), dependencies));
+    }
+
+    let mut order: Vec<String> = vec![];
+    while !remaining.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) = remaining
+            .into_iter()
+            .partition(|(_, dependencies)| dependencies.iter().all(|d| order.contains(d)));
+        ensure!(
+            !ready.is_empty(),
+            ContractError::CyclicComponentDependencies {
+                components: blocked
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            }
+        );
+        order.extend(ready.into_iter().map(|(name, _)| name));
+        remaining = blocked;
+    }
+    Ok(order)
+}
+
 #[cw_serde]
 pub struct ChainInfo {
     pub chain_name: String,
@@ -306,11 +403,97 @@ pub struct InstantiateMsg {
     pub app_components: Vec<AppComponent>,
     pub name: String,
     pub chain_info: Option<Vec<ChainInfo>>,
+    /// Allows components to be instantiated from deprecated ADO versions
+    pub allow_deprecated: Option<bool>,
+    /// An ADODB template whose components are added to the app
+    pub template: Option<TemplateRef>,
+    /// Instantiates components with the app as their admin so they can be migrated with `MigrateComponents`,
+    /// otherwise the account that created the app is their admin
+    pub app_is_component_admin: Option<bool>,
+}
+
+/// The prefix of the actions permissioning actors to send `ProxyMessages` to a component
+pub const PROXY_MESSAGES_ACTION: &str = "ProxyMessages";
+
+/// The action to permission for actors allowed to send `ProxyMessages` to the named component
+pub fn proxy_messages_action(name: &str) -> String {
+    format!("{PROXY_MESSAGES_ACTION}:{name}")
+}
+
+/// A message sent to an app component by `ProxyMessages`
+#[cw_serde]
+pub struct ComponentMessage {
+    pub name: String,
+    pub msg: Binary,
+    /// Funds sent with the message, taken from the funds sent to the app
+    pub funds: Option<Vec<Coin>>,
+}
+
+/// A reference to an app template published in the ADODB
+#[cw_serde]
+pub struct TemplateRef {
+    pub name: String,
+    /// Defaults to the latest version
+    pub version: Option<String>,
+    /// Values for the template parameters, `owner` defaults to the app owner
+    pub params: Vec<(String, String)>,
+}
+
+impl TemplateRef {
+    /// Renders the template into app components.
+    ///
+    /// `"./<component>"` references to other template components are substituted with their expected addresses.
+    pub fn resolve_components(
+        &self,
+        api: &dyn Api,
+        adodb_addr: &Addr,
+        querier: &QuerierWrapper,
+        parent_addr: Addr,
+        owner: &str,
+    ) -> Result<Vec<AppComponent>, ContractError> {
+        let template =
+            AOSQuerier::template_getter(querier, adodb_addr, &self.name, self.version.clone())?;
+        let mut params = self.params.clone();
+        if !params.iter().any(|(name, _)| name == TEMPLATE_OWNER_PARAM) {
+            params.push((TEMPLATE_OWNER_PARAM.to_string(), owner.to_string()));
+        }
+        let rendered = template.render(&params)?;
+
+        let mut addresses = vec![];
+        for component in rendered.iter() {
+            let app_component =
+                AppComponent::new(&component.name, &component.ado_type, Binary::default());
+            if let Some(new_addr) =
+                app_component.get_new_addr(api, adodb_addr, querier, parent_addr.clone())?
+            {
+                addresses.push((component.name.clone(), new_addr));
+            }
+        }
+
+        Ok(rendered
+            .into_iter()
+            .map(|component| {
+                let mut instantiate_msg = component.instantiate_msg;
+                for (name, new_addr) in addresses.iter() {
+                    instantiate_msg = instantiate_msg
+                        .replace(&format!("\"./{name}\""), &format!("\"{new_addr}\""));
+                }
+                AppComponent::new(
+                    component.name,
+                    component.ado_type,
+                    Binary::from(instantiate_msg.into_bytes()),
+                )
+            })
+            .collect())
+    }
 }
 
 #[andr_exec]
 #[cw_serde]
 pub enum ExecuteMsg {
+    /// Adds a new ADO instance or symlink to the app.
+    ///
+    /// Cross-chain components can only be declared when the app is instantiated, as their mirror apps are created then.
     AddAppComponent {
         component: AppComponent,
     },
@@ -322,12 +505,46 @@ pub enum ExecuteMsg {
         name: String,
         msg: Binary,
     },
+    /// Sends the messages to the app components in order, failing all of them if any fails.
+    ///
+    /// Restricted to the owner and actors permissioned for `proxy_messages_action` of each component.
+    /// Base ADO messages such as `ownership` cannot be proxied.
+    ProxyMessages {
+        msgs: Vec<ComponentMessage>,
+    },
     UpdateAddress {
         name: String,
         addr: String,
     },
+    /// Removes a component from the app along with its VFS path
+    RemoveAppComponent {
+        name: String,
+    },
+    /// Replaces a component with a new ADO instance or symlink registered under the same name.
+    ///
+    /// Cross-chain components can only be declared when the app is instantiated, as their mirror apps are created then.
+    ReplaceAppComponent {
+        name: String,
+        component: AppComponent,
+    },
+    /// Migrates each named component to the code of the given ADO version, resolved through the ADODB.
+    ///
+    /// The app must be the admin of each component, as it is for components it instantiated when created with `app_is_component_admin`.
+    MigrateComponents {
+        targets: Vec<(String, String)>,
+    },
     // Only available to the app contract itself
     AssignAppToComponents {},
+    /// Reports the address of the mirror app created on another chain and of its components, only available to the kernel
+    CrossChainCreateAck {
+        chain: String,
+        ado_type: String,
+        address: Option<String>,
+        /// The name and address of each component of the mirror app
+        #[serde(default)]
+        components: Vec<(String, String)>,
+        error: Option<String>,
+    },
 }
 
 #[andr_query]
@@ -340,7 +557,8 @@ pub enum QueryMsg {
     GetComponents {},
     #[returns(ComponentExistsResponse)]
     ComponentExists { name: String },
-    #[returns(Vec<AppComponent>)]
+    /// The address of each component, cross-chain components report the address of the mirror app on their chain once created
+    #[returns(Vec<ComponentAddress>)]
     GetAddressesWithNames {},
     #[returns(ConfigResponse)]
     Config {},
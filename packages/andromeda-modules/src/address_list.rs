@@ -1,6 +1,7 @@
 use andromeda_std::{
-    ado_base::permissioning::LocalPermission, amp::AndrAddr, andr_exec, andr_instantiate,
-    andr_query,
+    ado_base::{hooks::AndromedaHook, permissioning::LocalPermission},
+    amp::AndrAddr,
+    andr_exec, andr_instantiate, andr_query,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
@@ -37,6 +38,9 @@ pub enum QueryMsg {
     IncludesActor { actor: Addr },
     #[returns(ActorPermissionResponse)]
     ActorPermission { actor: Addr },
+    /// Module hook, `OnExecute` errors if the sender is not permissioned by the list
+    #[returns(Option<cosmwasm_std::Response>)]
+    AndrHook(AndromedaHook),
 }
 #[cw_serde]
 pub struct IsInclusiveResponse {
@@ -1,4 +1,7 @@
-use andromeda_std::{ado_base::rates::LocalRate, andr_exec, andr_instantiate, andr_query};
+use andromeda_std::{
+    ado_base::{hooks::AndromedaHook, rates::LocalRate},
+    andr_exec, andr_instantiate, andr_query,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
 #[andr_instantiate]
@@ -21,6 +24,9 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(RateResponse)]
     Rate { action: String },
+    /// Module hook, `OnFundsTransfer` deducts the rate for the action provided as the payload
+    #[returns(Option<andromeda_std::ado_base::hooks::OnFundsTransferResponse>)]
+    AndrHook(AndromedaHook),
 }
 
 #[cw_serde]
@@ -1,13 +1,26 @@
-use andromeda_std::{common::reply::ReplyId, os::economics::ExecuteMsg as EconomicsExecuteMsg};
+use andromeda_std::{
+    common::{reply::ReplyId, Funds},
+    os::economics::ExecuteMsg as EconomicsExecuteMsg,
+};
 use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, SubMsg, WasmMsg};
 
 pub fn generate_economics_message(payee: &str, action: &str) -> SubMsg {
+    generate_economics_message_with_funds(payee, action, vec![])
+}
+
+/// Generates the fee message for an action performed with the given funds attached
+pub fn generate_economics_message_with_funds(
+    payee: &str,
+    action: &str,
+    funds: Vec<Funds>,
+) -> SubMsg {
     SubMsg::reply_on_error(
         CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: "economics_contract".to_string(),
             msg: to_json_binary(&EconomicsExecuteMsg::PayFee {
                 payee: Addr::unchecked(payee),
                 action: action.to_string(),
+                funds: if funds.is_empty() { None } else { Some(funds) },
             })
             .unwrap(),
             funds: vec![],
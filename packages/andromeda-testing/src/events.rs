@@ -0,0 +1,38 @@
+use andromeda_std::common::response::AndrEvent;
+use cosmwasm_std::Event;
+
+/// Finds the standard ADO events with the given name, e.g. `"fee_paid"`.
+///
+/// Events emitted by contracts in a multi-test app are prefixed with `wasm-`, both forms are matched.
+pub fn find_andr_events<'a>(events: &'a [Event], name: &str) -> Vec<&'a Event> {
+    events
+        .iter()
+        .filter(|event| {
+            let ty = event.ty.strip_prefix("wasm-").unwrap_or(&event.ty);
+            ty.strip_prefix("andr_")
+                .and_then(|ty| ty.split_once('_'))
+                .is_some_and(|(_, event_name)| event_name == name)
+        })
+        .collect()
+}
+
+/// Asserts that the given events contain the expected standard ADO event.
+///
+/// Attributes added by the chain, such as `_contract_address`, are ignored.
+pub fn assert_andr_event(events: &[Event], expected: AndrEvent) {
+    let expected: Event = expected.into();
+    let found = events.iter().any(|event| {
+        let ty = event.ty.strip_prefix("wasm-").unwrap_or(&event.ty);
+        ty == expected.ty
+            && event
+                .attributes
+                .iter()
+                .filter(|attr| !attr.key.starts_with('_'))
+                .eq(expected.attributes.iter())
+    });
+    assert!(
+        found,
+        "expected event {:?} not found in events {:?}",
+        expected, events
+    );
+}
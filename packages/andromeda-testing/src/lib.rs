@@ -1,4 +1,5 @@
 pub mod economics_msg;
+pub mod events;
 // pub mod reply;
 // pub mod testing;
 #[cfg(not(target_arch = "wasm32"))]
@@ -10,6 +11,8 @@ pub mod ibc_registry;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod kernel;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod migration;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod mock_builder;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod vfs;
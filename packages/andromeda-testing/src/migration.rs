@@ -0,0 +1,40 @@
+use core::fmt;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::{AppResponse, Executor};
+use serde::Serialize;
+
+use crate::mock::MockApp;
+
+/// Overrides the stored cw2 version of a contract, used to simulate a contract deployed at an older version
+pub fn set_stored_version(app: &mut MockApp, contract: &Addr, name: &str, version: &str) {
+    let mut storage = app.contract_storage_mut(contract);
+    cw2::set_contract_version(storage.as_mut(), name, version).unwrap();
+}
+
+/// Migrates a contract to the given code and asserts that the state returned by `query_state` is unchanged.
+///
+/// The sender must be the admin of the contract.
+pub fn assert_migration_preserves_state<M, T>(
+    app: &mut MockApp,
+    sender: Addr,
+    contract: &Addr,
+    new_code_id: u64,
+    msg: &M,
+    query_state: impl Fn(&MockApp) -> T,
+) -> AppResponse
+where
+    M: Serialize,
+    T: PartialEq + fmt::Debug,
+{
+    let state_before = query_state(app);
+    let res = app
+        .migrate_contract(sender, contract.clone(), msg, new_code_id)
+        .unwrap();
+    let state_after = query_state(app);
+    assert_eq!(
+        state_before, state_after,
+        "State of {contract} changed during migration"
+    );
+    res
+}
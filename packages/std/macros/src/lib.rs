@@ -52,6 +52,16 @@ pub fn andr_exec(_args: TokenStream, input: TokenStream) -> TokenStream {
                     address: String,
                 },
                 Permissioning(::andromeda_std::ado_base::permissioning::PermissioningMessage),
+                Pause {
+                    actions: Option<Vec<String>>,
+                    until: Option<::andromeda_std::common::expiration::Expiry>,
+                },
+                Unpause {},
+                Batch {
+                    msgs: Vec<::cosmwasm_std::Binary>,
+                    atomic: bool,
+                    funds: Option<Vec<Vec<::cosmwasm_std::Coin>>>,
+                },
             }
         }
         .into(),
@@ -69,6 +79,27 @@ pub fn andr_exec(_args: TokenStream, input: TokenStream) -> TokenStream {
             .into(),
         )
     }
+    #[cfg(feature = "modules")]
+    {
+        merged = merge_variants(
+            merged,
+            quote! {
+                enum Right {
+                    RegisterModule {
+                        module: ::andromeda_std::ado_base::modules::Module,
+                    },
+                    DeregisterModule {
+                        module_idx: ::cosmwasm_std::Uint64,
+                    },
+                    AlterModule {
+                        module_idx: ::cosmwasm_std::Uint64,
+                        module: ::andromeda_std::ado_base::modules::Module,
+                    },
+                }
+            }
+            .into(),
+        )
+    }
     let input = parse_macro_input!(merged);
     TokenStream::from(andr_exec_derive(input).into_token_stream())
 }
@@ -147,10 +178,22 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
                 Version {},
                 #[returns(andromeda_std::ado_base::version::ADOBaseVersionResponse)]
                 ADOBaseVersion {},
+                #[returns(::andromeda_std::common::migration::MigrationPlanResponse)]
+                MigrationPlan { from_version: Option<String> },
                 #[returns(Vec<::andromeda_std::ado_base::permissioning::PermissionInfo>)]
                 Permissions { actor: String, limit: Option<u32>, start_after: Option<String> },
                 #[returns(Vec<String>)]
                 PermissionedActions { },
+                #[returns(Vec<::andromeda_std::ado_base::permissioning::RoleInfo>)]
+                Roles { start_after: Option<String>, limit: Option<u32> },
+                #[returns(Vec<String>)]
+                RoleMembers { role: String, start_after: Option<String>, limit: Option<u32> },
+                #[returns(Vec<::andromeda_std::ado_base::permissioning::RolePermissionInfo>)]
+                RolePermissions { action: String },
+                #[returns(::andromeda_std::ado_base::pause::PauseStatusResponse)]
+                PauseStatus {},
+                #[returns(::andromeda_std::ado_base::ownership::PendingAdminActionsResponse)]
+                PendingAdminActions { start_after: Option<u64>, limit: Option<u32> },
             }
         }
         .into(),
@@ -161,7 +204,7 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
             merged,
             quote! {
                 enum Right {
-                    #[returns(Option<::andromeda_std::ado_base::rates::Rate>)]
+                    #[returns(Option<Vec<::andromeda_std::ado_base::rates::Rate>>)]
                     Rates {action: String},
                     #[returns(::andromeda_std::ado_base::rates::AllRatesResponse)]
                     AllRates {}
@@ -170,5 +213,20 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
             .into(),
         )
     }
+    #[cfg(feature = "modules")]
+    {
+        merged = merge_variants(
+            merged,
+            quote! {
+                enum Right {
+                    #[returns(::andromeda_std::ado_base::modules::Module)]
+                    Module { id: ::cosmwasm_std::Uint64 },
+                    #[returns(Vec<String>)]
+                    ModuleIds {}
+                }
+            }
+            .into(),
+        )
+    }
     merged
 }
@@ -0,0 +1,51 @@
+use crate::common::Funds;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Coin, Event, SubMsg};
+
+/// Wrapper used by an ADO to query its registered modules
+#[cw_serde]
+pub enum HookMsg {
+    AndrHook(AndromedaHook),
+}
+
+/// Hooks an ADO sends to its registered modules.
+///
+/// Modules return `None` for any hook they do not support.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AndromedaHook {
+    /// Sent before an action is performed, a module may error to reject the action
+    #[returns(Option<cosmwasm_std::Response>)]
+    OnExecute { sender: String, payload: Binary },
+    /// Sent when funds are transferred, the module returns any messages to send and the funds left over
+    #[returns(Option<OnFundsTransferResponse>)]
+    OnFundsTransfer {
+        sender: String,
+        payload: Binary,
+        amount: Funds,
+    },
+    /// Sent when a token is transferred, a module may error to reject the transfer
+    #[returns(Option<cosmwasm_std::Response>)]
+    OnTokenTransfer {
+        token_id: String,
+        sender: String,
+        recipient: String,
+    },
+}
+
+#[cw_serde]
+pub struct OnFundsTransferResponse {
+    pub msgs: Vec<SubMsg>,
+    pub events: Vec<Event>,
+    pub leftover_funds: Funds,
+}
+
+impl Default for OnFundsTransferResponse {
+    fn default() -> Self {
+        Self {
+            msgs: Vec::new(),
+            events: Vec::new(),
+            leftover_funds: Funds::Native(Coin::default()),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 pub mod ado_type;
 pub mod app_contract;
 pub mod block_height;
+pub mod hooks;
 pub mod kernel_address;
 pub mod modules;
 pub mod ownership;
+pub mod pause;
 pub mod permissioning;
 #[cfg(feature = "rates")]
 pub mod rates;
@@ -11,8 +13,12 @@ pub mod version;
 
 pub mod withdraw;
 use crate::amp::{messages::AMPPkt, AndrAddr};
+use crate::common::expiration::Expiry;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+#[cfg(feature = "modules")]
+use cosmwasm_std::Uint64;
+use cosmwasm_std::{Addr, Binary, Coin};
+use strum_macros::AsRefStr;
 
 use self::ownership::OwnershipMessage;
 use self::permissioning::PermissioningMessage;
@@ -30,6 +36,7 @@ pub struct InstantiateMsg {
 pub struct MigrateMsg {}
 
 #[cw_serde]
+#[derive(AsRefStr)]
 pub enum AndromedaMsg {
     Ownership(OwnershipMessage),
     UpdateAppContract {
@@ -43,6 +50,37 @@ pub enum AndromedaMsg {
     #[serde(rename = "amp_receive")]
     AMPReceive(AMPPkt),
     Permissioning(PermissioningMessage),
+    /// Halts the given actions, or every action if none are provided, until the given expiry or an `Unpause`
+    ///
+    /// Executable by the owner or members of the `guardian` role
+    Pause {
+        actions: Option<Vec<String>>,
+        until: Option<Expiry>,
+    },
+    /// Lifts the current pause, only executable by the owner
+    Unpause {},
+    /// Executes each message through the ADO's own message handler, reporting the result of each by index
+    ///
+    /// `funds` optionally lists the funds each message is sent with by index, the funds attached to the batch must equal their sum.
+    /// If `atomic` is set any failing message fails the batch, otherwise failed messages are reverted, skipped and their funds refunded
+    Batch {
+        msgs: Vec<Binary>,
+        atomic: bool,
+        funds: Option<Vec<Vec<Coin>>>,
+    },
+    #[cfg(feature = "modules")]
+    RegisterModule {
+        module: self::modules::Module,
+    },
+    #[cfg(feature = "modules")]
+    DeregisterModule {
+        module_idx: Uint64,
+    },
+    #[cfg(feature = "modules")]
+    AlterModule {
+        module_idx: Uint64,
+        module: self::modules::Module,
+    },
 }
 
 #[cw_serde]
@@ -64,6 +102,10 @@ pub enum AndromedaQuery {
     Version {},
     #[returns(self::version::ADOBaseVersionResponse)]
     ADOBaseVersion {},
+    /// The migration steps that would run when upgrading from `from_version`, defaults to the stored version.
+    /// Only answered by contracts that declare a migration registry.
+    #[returns(crate::common::migration::MigrationPlanResponse)]
+    MigrationPlan { from_version: Option<String> },
     #[returns(self::app_contract::AppContractResponse)]
     AppContract {},
     #[returns(Vec<self::permissioning::PermissionInfo>)]
@@ -74,12 +116,40 @@ pub enum AndromedaQuery {
     },
     #[returns(Vec<self::permissioning::PermissionedActionsResponse>)]
     PermissionedActions {},
+    #[returns(Vec<self::permissioning::RoleInfo>)]
+    Roles {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<String>)]
+    RoleMembers {
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<self::permissioning::RolePermissionInfo>)]
+    RolePermissions { action: String },
+    #[returns(self::pause::PauseStatusResponse)]
+    PauseStatus {},
+    #[returns(self::ownership::PendingAdminActionsResponse)]
+    PendingAdminActions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 
     #[cfg(feature = "rates")]
-    #[returns(Option<self::rates::Rate>)]
+    #[returns(Option<Vec<self::rates::Rate>>)]
     Rates { action: String },
 
     #[cfg(feature = "rates")]
     #[returns(self::rates::AllRatesResponse)]
     AllRates {},
+
+    #[cfg(feature = "modules")]
+    #[returns(self::modules::Module)]
+    Module { id: Uint64 },
+
+    #[cfg(feature = "modules")]
+    #[returns(Vec<String>)]
+    ModuleIds {},
 }
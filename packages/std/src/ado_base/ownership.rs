@@ -1,7 +1,9 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint64};
 
-use crate::common::{expiration::Expiry, MillisecondsExpiration};
+use crate::common::{expiration::Expiry, MillisecondsDuration, MillisecondsExpiration};
+
+use super::AndromedaMsg;
 
 #[cw_serde]
 pub struct ContractOwnerResponse {
@@ -28,4 +30,33 @@ pub enum OwnershipMessage {
     RevokeOwnershipOffer,
     AcceptOwnership,
     Disown,
+    /// Enables the admin timelock with the given delay, or disables it if no delay is provided.
+    ///
+    /// While enabled, admin messages sent by the owner are queued and can only be executed once the delay has passed.
+    /// Changes to the timelock are themselves subject to the current timelock.
+    SetAdminTimelock {
+        delay: Option<MillisecondsDuration>,
+    },
+    /// Executes a queued admin action whose delay has passed. **Only executable by the contract owner.**
+    ExecuteAdminAction {
+        id: Uint64,
+    },
+    /// Cancels a queued admin action. **Executable by the contract owner or members of the guardian role.**
+    CancelAdminAction {
+        id: Uint64,
+    },
+}
+
+#[cw_serde]
+pub struct PendingAdminAction {
+    pub id: Uint64,
+    pub msg: AndromedaMsg,
+    pub executable_at: MillisecondsExpiration,
+}
+
+#[cw_serde]
+pub struct PendingAdminActionsResponse {
+    /// The current admin timelock delay, `None` if the timelock is disabled
+    pub delay: Option<MillisecondsDuration>,
+    pub actions: Vec<PendingAdminAction>,
 }
@@ -0,0 +1,68 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::BlockInfo;
+
+use crate::common::MillisecondsExpiration;
+
+/// The reserved permissioning role whose members may pause the ADO alongside the owner
+pub const GUARDIAN_ROLE: &str = "guardian";
+
+/// Actions that remain callable while an ADO is paused
+pub const PAUSE_EXEMPT_ACTIONS: [&str; 2] = ["Pause", "Unpause"];
+
+#[cw_serde]
+pub struct PauseInfo {
+    /// The paused actions, every action is paused if not provided
+    pub actions: Option<Vec<String>>,
+    /// The time at which the pause lifts, the pause lasts until `Unpause` if not provided
+    pub until: Option<MillisecondsExpiration>,
+}
+
+impl PauseInfo {
+    /// Whether the pause is still in effect
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        match self.until {
+            Some(until) => !until.is_expired(block),
+            None => true,
+        }
+    }
+
+    /// Combines the pause with another, pausing the actions of both until the later of the two lifts
+    pub fn widen(self, other: PauseInfo) -> PauseInfo {
+        let actions = match (self.actions, other.actions) {
+            (Some(mut actions), Some(other_actions)) => {
+                for action in other_actions {
+                    if !actions.contains(&action) {
+                        actions.push(action);
+                    }
+                }
+                Some(actions)
+            }
+            _ => None,
+        };
+        let until = match (self.until, other.until) {
+            (Some(until), Some(other_until)) if other_until > until => Some(other_until),
+            (Some(until), Some(_)) => Some(until),
+            _ => None,
+        };
+        PauseInfo { actions, until }
+    }
+
+    /// Whether the given action is halted by the pause
+    pub fn is_paused(&self, block: &BlockInfo, action: &str) -> bool {
+        if PAUSE_EXEMPT_ACTIONS.contains(&action) || !self.is_active(block) {
+            return false;
+        }
+        match &self.actions {
+            Some(actions) => actions.iter().any(|paused_action| paused_action == action),
+            None => true,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub paused: bool,
+    /// The paused actions, every action is paused if not provided
+    pub actions: Option<Vec<String>>,
+    pub until: Option<MillisecondsExpiration>,
+}
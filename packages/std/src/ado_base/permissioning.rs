@@ -26,6 +26,31 @@ pub enum PermissioningMessage {
     DisableActionPermissioning {
         action: String,
     },
+    /// Creates a named role
+    ///
+    /// Role membership is delegated to the given address list ADO if provided, otherwise members are managed via `GrantRole`/`RevokeRole`
+    CreateRole {
+        role: String,
+        address_list: Option<AndrAddr>,
+    },
+    GrantRole {
+        role: String,
+        actors: Vec<AndrAddr>,
+    },
+    RevokeRole {
+        role: String,
+        actors: Vec<AndrAddr>,
+    },
+    /// Sets the permission for the given action for all members of a role
+    SetRolePermission {
+        role: String,
+        action: String,
+        permission: LocalPermission,
+    },
+    RemoveRolePermission {
+        role: String,
+        action: String,
+    },
 }
 
 #[cw_serde]
@@ -35,6 +60,20 @@ pub struct PermissionInfo {
     pub actor: String,
 }
 
+#[cw_serde]
+pub struct RoleInfo {
+    pub role: String,
+    /// The address list ADO that role membership is delegated to, if any
+    pub address_list: Option<AndrAddr>,
+}
+
+#[cw_serde]
+pub struct RolePermissionInfo {
+    pub permission: LocalPermission,
+    pub action: String,
+    pub role: String,
+}
+
 #[cw_serde]
 pub struct PermissionedActionsResponse {
     pub actions: Vec<String>,
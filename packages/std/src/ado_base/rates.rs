@@ -1,12 +1,16 @@
 use crate::{
     ado_contract::ADOContract,
     amp::{AndrAddr, Recipient},
-    common::{deduct_funds, Funds},
+    common::{
+        denom::{Asset, AssetAmount},
+        response::AndrEvent,
+        Funds,
+    },
     error::ContractError,
     os::{adodb::ADOVersion, aos_querier::AOSQuerier},
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{ensure, has_coins, Coin, Decimal, Deps, Event, Fraction, SubMsg};
+use cosmwasm_std::{ensure, has_coins, Coin, Decimal, Deps, Event, Fraction, SubMsg, Uint128};
 use cw20::Cw20Coin;
 
 #[cw_serde]
@@ -16,6 +20,15 @@ pub struct RatesResponse {
     pub leftover_funds: Funds,
 }
 
+/// The result of applying rates to payments made in one or more assets
+#[cw_serde]
+pub struct AssetRatesResponse {
+    pub msgs: Vec<SubMsg>,
+    pub events: Vec<Event>,
+    /// The funds left over for each payment, in the order the payments were provided
+    pub leftover_funds: Vec<AssetAmount>,
+}
+
 impl Default for RatesResponse {
     fn default() -> Self {
         Self {
@@ -28,8 +41,12 @@ impl Default for RatesResponse {
 
 #[cw_serde]
 pub enum RatesMessage {
+    /// Replaces all rates for the action with the given rate
     SetRate { action: String, rate: Rate },
-    RemoveRate { action: String },
+    /// Adds a rate to the end of the action's rates
+    AddRate { action: String, rate: Rate },
+    /// Removes the rate at the given index for the action, or all of the action's rates if no index is provided
+    RemoveRate { action: String, index: Option<u32> },
 }
 
 #[cw_serde]
@@ -60,6 +77,12 @@ impl LocalRateType {
     pub fn is_additive(&self) -> bool {
         self == &LocalRateType::Additive
     }
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocalRateType::Additive => "additive",
+            LocalRateType::Deductive => "deductive",
+        }
+    }
     pub fn create_event(&self) -> Event {
         if self.is_additive() {
             Event::new("tax")
@@ -75,6 +98,10 @@ pub enum LocalRateValue {
     Percent(PercentRate),
     // Flat fee
     Flat(Coin),
+    // Flat fee in a native or CW20 asset
+    FlatAsset(AssetAmount),
+    // Percent fee determined by the highest tier threshold the payment reaches
+    Tiered(Vec<RateTier>),
 }
 impl LocalRateValue {
     pub fn validate(&self) -> Result<(), ContractError> {
@@ -83,6 +110,12 @@ impl LocalRateValue {
             LocalRateValue::Flat(coin) => {
                 ensure!(!coin.amount.is_zero(), ContractError::InvalidRate {});
             }
+            LocalRateValue::FlatAsset(asset_amount) => {
+                ensure!(
+                    !asset_amount.amount.is_zero(),
+                    ContractError::InvalidRate {}
+                );
+            }
             // If it's a percentage, make sure it's greater than zero and less than or equal to 1 of type decimal (which represents 100%)
             LocalRateValue::Percent(percent_rate) => {
                 ensure!(
@@ -90,72 +123,190 @@ impl LocalRateValue {
                     ContractError::InvalidRate {}
                 );
             }
+            // Tiers must start at zero with strictly increasing thresholds, each percentage must be at most 1
+            LocalRateValue::Tiered(tiers) => {
+                ensure!(
+                    tiers.first().is_some_and(|tier| tier.threshold.is_zero()),
+                    ContractError::InvalidRate {}
+                );
+                ensure!(
+                    tiers
+                        .windows(2)
+                        .all(|pair| pair[0].threshold < pair[1].threshold),
+                    ContractError::InvalidRate {}
+                );
+                ensure!(
+                    tiers.iter().all(|tier| tier.percent <= Decimal::one()),
+                    ContractError::InvalidRate {}
+                );
+            }
         }
         Ok(())
     }
     pub fn is_flat(&self) -> bool {
         match self {
-            LocalRateValue::Percent(_) => false,
-            LocalRateValue::Flat(_) => true,
+            LocalRateValue::Percent(_) | LocalRateValue::Tiered(_) => false,
+            LocalRateValue::Flat(_) | LocalRateValue::FlatAsset(_) => true,
         }
     }
 }
 
+/// A percentage applied to payments of at least `threshold`
+#[cw_serde]
+pub struct RateTier {
+    pub threshold: Uint128,
+    pub percent: Decimal,
+}
+
 #[cw_serde]
 pub struct LocalRate {
     pub rate_type: LocalRateType,
     pub recipients: Vec<Recipient>,
     pub value: LocalRateValue,
     pub description: Option<String>,
+    /// Weights used to split a single fee among the recipients, one per recipient.
+    ///
+    /// If not provided each recipient is charged the full fee.
+    pub weights: Option<Vec<Uint128>>,
 }
 // Created this because of the very complex return value warning.
 type LocalRateResponse = (Vec<SubMsg>, Vec<Event>, Vec<Coin>);
+type LocalAssetRateResponse = (Vec<SubMsg>, Vec<Event>, Vec<AssetAmount>);
 
 impl LocalRate {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        self.value.validate()?;
+        if let Some(weights) = &self.weights {
+            ensure!(
+                !weights.is_empty()
+                    && weights.len() == self.recipients.len()
+                    && weights.iter().all(|weight| !weight.is_zero()),
+                ContractError::InvalidRate {}
+            );
+        }
+        Ok(())
+    }
+
+    /// Gets the fee paid to each recipient.
+    ///
+    /// The fee is divided among the recipients by weight, with any remainder going to the first recipient.
+    /// If no weights are set each recipient receives the full fee.
+    pub fn recipient_fees(&self, fee: &Coin) -> Result<Vec<Coin>, ContractError> {
+        let weights = match &self.weights {
+            Some(weights) => weights,
+            None => return Ok(vec![fee.clone(); self.recipients.len()]),
+        };
+        let total_weight: Uint128 = weights.iter().sum();
+        ensure!(!total_weight.is_zero(), ContractError::InvalidRate {});
+
+        let mut amounts: Vec<Uint128> = weights
+            .iter()
+            .map(|weight| fee.amount.multiply_ratio(*weight, total_weight))
+            .collect();
+        let distributed: Uint128 = amounts.iter().sum();
+        if let Some(first) = amounts.first_mut() {
+            *first += fee.amount - distributed;
+        }
+        Ok(amounts
+            .into_iter()
+            .map(|amount| Coin::new(amount.u128(), fee.denom.clone()))
+            .collect())
+    }
+
+    /// Applies the rate to a single payment, `is_native` determines whether `coin.denom` is a native denom or a CW20 address
     pub fn generate_response(
         &self,
         deps: Deps,
         coin: Coin,
         is_native: bool,
     ) -> Result<LocalRateResponse, ContractError> {
+        let asset = if is_native {
+            Asset::NativeToken(coin.denom)
+        } else {
+            Asset::Cw20Token(AndrAddr::from_string(coin.denom))
+        };
+        let (msgs, events, leftover_funds) =
+            self.generate_asset_response(deps, vec![AssetAmount::new(asset, coin.amount)])?;
+        let leftover_funds = leftover_funds
+            .iter()
+            .map(|funds| Coin::new(funds.amount.u128(), funds.denom()))
+            .collect();
+        Ok((msgs, events, leftover_funds))
+    }
+
+    /// Applies the rate to payments made in one or more assets.
+    ///
+    /// Percentage rates are charged on every payment, flat rates are charged once from the payment in the rate's asset.
+    /// The leftover funds are returned in the same order as the payments.
+    pub fn generate_asset_response(
+        &self,
+        deps: Deps,
+        payments: Vec<AssetAmount>,
+    ) -> Result<LocalAssetRateResponse, ContractError> {
         let mut msgs: Vec<SubMsg> = vec![];
-        let mut events: Vec<Event> = vec![];
-        let mut leftover_funds = vec![coin.clone()];
+        let mut rate_events: Vec<Event> = vec![];
         // Tax event if the rate type is additive, or Royalty event if the rate type is deductive.
         let mut event = self.rate_type.create_event();
 
         if let Some(desc) = &self.description {
             event = event.add_attribute("description", desc);
         }
-        let fee = calculate_fee(self.value.clone(), &coin)?;
-        for receiver in self.recipients.iter() {
-            // If the rate type is deductive
-            if !self.rate_type.is_additive() {
-                deduct_funds(&mut leftover_funds, &fee)?;
-                event = event.add_attribute("deducted", fee.to_string());
-            }
-            event = event.add_attribute(
-                "payment",
-                PaymentAttribute {
-                    receiver: receiver.get_addr(),
-                    amount: fee.clone(),
+        let fees = calculate_asset_fees(&deps, &self.value, &payments)?;
+        let mut leftover_funds = payments;
+        for fee in fees {
+            let fee_coin = Coin::new(fee.amount.u128(), fee.denom());
+            let recipient_fees = self.recipient_fees(&fee_coin)?;
+            for (receiver, recipient_fee) in self.recipients.iter().zip(recipient_fees) {
+                // Nothing to pay for a zero fee
+                if recipient_fee.amount.is_zero() {
+                    continue;
                 }
-                .to_string(),
-            );
-            let msg = if is_native {
-                receiver.generate_direct_msg(&deps, vec![fee.clone()])?
-            } else {
-                receiver.generate_msg_cw20(
-                    &deps,
-                    Cw20Coin {
-                        amount: fee.amount,
-                        address: fee.denom.to_string(),
-                    },
-                )?
-            };
-            msgs.push(msg);
-        }
-        events.push(event);
+                // If the rate type is deductive
+                if !self.rate_type.is_additive() {
+                    let leftover = leftover_funds
+                        .iter_mut()
+                        .find(|funds| funds.asset == fee.asset)
+                        .ok_or(ContractError::InsufficientFunds {})?;
+                    leftover.amount = leftover
+                        .amount
+                        .checked_sub(recipient_fee.amount)
+                        .map_err(|_| ContractError::InsufficientFunds {})?;
+                    event = event.add_attribute("deducted", recipient_fee.to_string());
+                }
+                event = event.add_attribute(
+                    "payment",
+                    PaymentAttribute {
+                        receiver: receiver.get_addr(),
+                        amount: recipient_fee.clone(),
+                    }
+                    .to_string(),
+                );
+                rate_events.push(
+                    AndrEvent::RateApplied {
+                        rate_type: self.rate_type.as_str().to_string(),
+                        recipient: receiver.get_addr(),
+                        asset: fee.asset.to_string(),
+                        amount: recipient_fee.amount,
+                        deducted: !self.rate_type.is_additive(),
+                    }
+                    .into(),
+                );
+                let msg = if fee.asset.is_native() {
+                    receiver.generate_direct_msg(&deps, vec![recipient_fee])?
+                } else {
+                    receiver.generate_msg_cw20(
+                        &deps,
+                        Cw20Coin {
+                            amount: recipient_fee.amount,
+                            address: recipient_fee.denom,
+                        },
+                    )?
+                };
+                msgs.push(msg);
+            }
+        }
+        let mut events = vec![event];
+        events.extend(rate_events);
         Ok((msgs, events, leftover_funds))
     }
 }
@@ -190,8 +341,8 @@ impl Rate {
                 }
             }
             Rate::Local(local_rate) => {
-                // Validate the local rate value
-                local_rate.value.validate()?;
+                // Validate the local rate value and recipient weights
+                local_rate.validate()?;
                 Ok(())
             }
         }
@@ -212,11 +363,16 @@ pub struct PercentRate {
 /// Calculates a fee amount given a `Rate` and payment amount.
 ///
 /// ## Arguments
+/// * `deps` - Used to resolve the address of CW20 rate assets
 /// * `fee_rate` - The `Rate` of the fee to be paid
 /// * `payment` - The amount used to calculate the fee
 ///
 /// Returns the fee amount in a `Coin` struct.
-pub fn calculate_fee(fee_rate: LocalRateValue, payment: &Coin) -> Result<Coin, ContractError> {
+pub fn calculate_fee(
+    deps: &Deps,
+    fee_rate: LocalRateValue,
+    payment: &Coin,
+) -> Result<Coin, ContractError> {
     match fee_rate {
         LocalRateValue::Flat(rate) => {
             ensure!(
@@ -225,6 +381,20 @@ pub fn calculate_fee(fee_rate: LocalRateValue, payment: &Coin) -> Result<Coin, C
             );
             Ok(Coin::new(rate.amount.u128(), rate.denom))
         }
+        LocalRateValue::FlatAsset(rate) => {
+            let denom = rate.asset.get_denom(deps)?;
+            ensure!(
+                denom == payment.denom,
+                ContractError::RateAssetMismatch {
+                    rate_asset: rate.asset.to_string()
+                }
+            );
+            ensure!(
+                payment.amount >= rate.amount,
+                ContractError::InsufficientFunds {}
+            );
+            Ok(Coin::new(rate.amount.u128(), denom))
+        }
         LocalRateValue::Percent(percent_rate) => {
             // [COM-03] Make sure that fee_rate between 0 and 100.
             ensure!(
@@ -232,21 +402,98 @@ pub fn calculate_fee(fee_rate: LocalRateValue, payment: &Coin) -> Result<Coin, C
                 percent_rate.percent <= Decimal::one() && !percent_rate.percent.is_zero(),
                 ContractError::InvalidRate {}
             );
-            let mut fee_amount = payment.amount * percent_rate.percent;
-
-            // Always round any remainder up and prioritise the fee receiver.
-            // Inverse of percent will always exist.
-            let reversed_fee = fee_amount * percent_rate.percent.inv().unwrap();
-            if payment.amount > reversed_fee {
-                // [COM-1] Added checked add to fee_amount rather than direct increment
-                fee_amount = fee_amount.checked_add(1u128.into())?;
-            }
-            Ok(Coin::new(fee_amount.u128(), payment.denom.clone()))
+            calculate_percent_fee(percent_rate.percent, payment)
+        }
+        LocalRateValue::Tiered(tiers) => {
+            let tier = tiers
+                .iter()
+                .rev()
+                .find(|tier| tier.threshold <= payment.amount)
+                .ok_or(ContractError::InvalidRate {})?;
+            ensure!(
+                tier.percent <= Decimal::one(),
+                ContractError::InvalidRate {}
+            );
+            calculate_percent_fee(tier.percent, payment)
         } // Rate::External(_) => Err(ContractError::UnexpectedExternalRate {}),
     }
 }
 
+/// Calculates the fees due on payments made in one or more assets given a `LocalRateValue`.
+///
+/// Flat rates are charged once from the payment in the same asset, erroring if no payment matches the rate's asset.
+/// Percentage rates are charged on each payment.
+///
+/// Returns the fees as a vector of `AssetAmount` in the assets they are paid in.
+pub fn calculate_asset_fees(
+    deps: &Deps,
+    fee_rate: &LocalRateValue,
+    payments: &[AssetAmount],
+) -> Result<Vec<AssetAmount>, ContractError> {
+    let flat_fee = match fee_rate {
+        // Flat coin rates are matched by denom alone, which is also the address for CW20 payments
+        LocalRateValue::Flat(rate) => Some((
+            payments
+                .iter()
+                .find(|payment| payment.denom() == rate.denom),
+            rate.amount,
+            Asset::NativeToken(rate.denom.clone()),
+        )),
+        LocalRateValue::FlatAsset(rate) => {
+            let denom = rate.asset.get_denom(deps)?;
+            Some((
+                payments.iter().find(|payment| {
+                    payment.asset.is_native() == rate.asset.is_native() && payment.denom() == denom
+                }),
+                rate.amount,
+                rate.asset.clone(),
+            ))
+        }
+        LocalRateValue::Percent(_) | LocalRateValue::Tiered(_) => None,
+    };
+    match flat_fee {
+        Some((Some(payment), amount, _)) => {
+            ensure!(
+                payment.amount >= amount,
+                ContractError::InsufficientFunds {}
+            );
+            Ok(vec![AssetAmount::new(payment.asset.clone(), amount)])
+        }
+        Some((None, _, rate_asset)) => Err(ContractError::RateAssetMismatch {
+            rate_asset: rate_asset.to_string(),
+        }),
+        None => payments
+            .iter()
+            .map(|payment| {
+                let fee = calculate_fee(
+                    deps,
+                    fee_rate.clone(),
+                    &Coin::new(payment.amount.u128(), payment.denom()),
+                )?;
+                Ok(AssetAmount::new(payment.asset.clone(), fee.amount))
+            })
+            .collect(),
+    }
+}
+
+fn calculate_percent_fee(percent: Decimal, payment: &Coin) -> Result<Coin, ContractError> {
+    if percent.is_zero() {
+        return Ok(Coin::new(0, payment.denom.clone()));
+    }
+    let mut fee_amount = payment.amount * percent;
+
+    // Always round any remainder up and prioritise the fee receiver.
+    // Inverse of percent will always exist.
+    let reversed_fee = fee_amount * percent.inv().unwrap();
+    if payment.amount > reversed_fee {
+        // [COM-1] Added checked add to fee_amount rather than direct increment
+        fee_amount = fee_amount.checked_add(1u128.into())?;
+    }
+    Ok(Coin::new(fee_amount.u128(), payment.denom.clone()))
+}
+
 #[cw_serde]
 pub struct AllRatesResponse {
-    pub all_rates: Vec<(String, Rate)>,
+    /// Each action with its rates in the order they are applied
+    pub all_rates: Vec<(String, Vec<Rate>)>,
 }
@@ -0,0 +1,553 @@
+use std::{cmp::Ordering, collections::BTreeMap, iter::Peekable, ops::Bound};
+
+use crate::{
+    ado_base::AndromedaMsg,
+    ado_contract::{execute::ExecuteContextFunction, ADOContract},
+    common::context::ExecuteContext,
+    error::ContractError,
+};
+use cosmwasm_std::{
+    ensure, from_json, BankMsg, Binary, Coin, DepsMut, Event, MessageInfo, Order, Record, Response,
+    StdError, Storage, SubMsg, Uint128,
+};
+use serde::de::DeserializeOwned;
+
+impl<'a> ADOContract<'a> {
+    /// Executes each message of a batch with the provided handler as if it were sent individually by the sender.
+    ///
+    /// Each message is sent with its share of `funds` by index, which must add up to the funds attached to the batch.
+    /// The result of each message is reported in a `batch_result` event with its index.
+    /// If `atomic` is set any failing message fails the batch, otherwise the changes made by a failing message are discarded,
+    /// its funds are refunded to the sender and execution continues.
+    pub fn execute_batch<M: DeserializeOwned, E>(
+        &self,
+        mut ctx: ExecuteContext,
+        msgs: Vec<Binary>,
+        atomic: bool,
+        funds: Option<Vec<Vec<Coin>>>,
+        handler: ExecuteContextFunction<M, E>,
+    ) -> Result<Response, E>
+    where
+        E: From<ContractError> + From<StdError> + ToString,
+    {
+        let funds = funds.unwrap_or_else(|| vec![vec![]; msgs.len()]);
+        ensure!(
+            funds.len() == msgs.len(),
+            ContractError::InvalidFunds {
+                msg: "The funds of a batch must be listed for each of its messages".to_string(),
+            }
+        );
+        ensure!(
+            sum_funds(funds.iter().flatten())? == sum_funds(ctx.info.funds.iter())?,
+            ContractError::InvalidFunds {
+                msg: "The funds attached to a batch must equal the funds of its messages"
+                    .to_string(),
+            }
+        );
+        let mut res = Response::new()
+            .add_attribute("method", "batch")
+            .add_attribute("atomic", atomic.to_string());
+        for (index, (msg, funds)) in msgs.into_iter().zip(funds).enumerate() {
+            let index = index as u32;
+            // Batches and AMP packets are only handled by the contract entry point
+            if let Ok(AndromedaMsg::Batch { .. } | AndromedaMsg::AMPReceive(_)) =
+                from_json::<AndromedaMsg>(&msg)
+            {
+                return Err(ContractError::InvalidBatchMessage {
+                    index,
+                    msg: "Batch and AMP messages cannot be batched".to_string(),
+                }
+                .into());
+            }
+            let msg: M = from_json(&msg).map_err(|err| ContractError::InvalidBatchMessage {
+                index,
+                msg: err.to_string(),
+            })?;
+            let info = MessageInfo {
+                sender: ctx.info.sender.clone(),
+                funds: funds.clone(),
+            };
+
+            let result = if atomic {
+                let inner_ctx = ExecuteContext {
+                    deps: ctx.deps.branch(),
+                    info,
+                    env: ctx.env.clone(),
+                    amp_ctx: ctx.amp_ctx.clone(),
+                };
+                let inner_res =
+                    handler(inner_ctx, msg).map_err(|err| ContractError::BatchMessageFailed {
+                        index,
+                        error: err.to_string(),
+                    })?;
+                Ok(inner_res)
+            } else {
+                let mut cache = StorageCache::new(ctx.deps.storage);
+                let inner_ctx = ExecuteContext {
+                    deps: DepsMut {
+                        storage: &mut cache,
+                        api: ctx.deps.api,
+                        querier: ctx.deps.querier,
+                    },
+                    info,
+                    env: ctx.env.clone(),
+                    amp_ctx: ctx.amp_ctx.clone(),
+                };
+                let result = handler(inner_ctx, msg);
+                // Only keep the changes of successful messages
+                if result.is_ok() {
+                    let writes = cache.into_writes();
+                    for (key, value) in writes {
+                        match value {
+                            Some(value) => ctx.deps.storage.set(&key, &value),
+                            None => ctx.deps.storage.remove(&key),
+                        }
+                    }
+                }
+                result
+            };
+
+            let event = Event::new("batch_result").add_attribute("index", index.to_string());
+            res = match result {
+                Ok(inner_res) => res
+                    .add_submessages(inner_res.messages)
+                    .add_attributes(inner_res.attributes)
+                    .add_events(inner_res.events)
+                    .add_event(event.add_attribute("result", "success")),
+                Err(err) => {
+                    let res = res.add_event(
+                        event
+                            .add_attribute("result", "failure")
+                            .add_attribute("error", err.to_string()),
+                    );
+                    // Refund the funds sent with the failed message
+                    if funds.is_empty() {
+                        res
+                    } else {
+                        res.add_submessage(SubMsg::new(BankMsg::Send {
+                            to_address: ctx.info.sender.to_string(),
+                            amount: funds,
+                        }))
+                    }
+                }
+            };
+        }
+        Ok(res)
+    }
+}
+
+/// Sums the given coins by denom
+fn sum_funds<'a>(
+    funds: impl Iterator<Item = &'a Coin>,
+) -> Result<BTreeMap<&'a str, Uint128>, ContractError> {
+    let mut sums: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in funds.filter(|coin| !coin.amount.is_zero()) {
+        let sum = sums.entry(coin.denom.as_str()).or_default();
+        *sum = sum.checked_add(coin.amount)?;
+    }
+    Ok(sums)
+}
+
+/// Buffers writes made to the underlying storage so they can be discarded
+struct StorageCache<'a> {
+    storage: &'a dyn Storage,
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> StorageCache<'a> {
+    fn new(storage: &'a dyn Storage) -> Self {
+        Self {
+            storage,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the buffered writes, with `None` for removed keys
+    fn into_writes(self) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+        self.writes
+    }
+}
+
+impl<'a> Storage for StorageCache<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.storage.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Box::new(std::iter::empty());
+            }
+        }
+        let bounds = (
+            start.map_or(Bound::Unbounded, |start| Bound::Included(start.to_vec())),
+            end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+        );
+        let writes = self.writes.range(bounds);
+        let writes: BufferedWrites<'b> = match order {
+            Order::Ascending => Box::new(writes),
+            Order::Descending => Box::new(writes.rev()),
+        };
+        Box::new(MergedRange {
+            storage: self.storage.range(start, end, order).peekable(),
+            writes: writes.peekable(),
+            order,
+        })
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+/// A range of the buffered writes, a `None` value marking a removed key
+type BufferedWrites<'b> = Box<dyn Iterator<Item = (&'b Vec<u8>, &'b Option<Vec<u8>>)> + 'b>;
+
+/// Lazily merges a range of the underlying storage with the buffered writes in the same range,
+/// the buffered writes taking precedence
+struct MergedRange<'b> {
+    storage: Peekable<Box<dyn Iterator<Item = Record> + 'b>>,
+    writes: Peekable<BufferedWrites<'b>>,
+    order: Order,
+}
+
+impl<'b> Iterator for MergedRange<'b> {
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        loop {
+            let ordering = match (self.storage.peek(), self.writes.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((key, _)), Some((write_key, _))) => {
+                    let ordering = key.cmp(write_key);
+                    match self.order {
+                        Order::Ascending => ordering,
+                        Order::Descending => ordering.reverse(),
+                    }
+                }
+            };
+            match ordering {
+                Ordering::Less => return self.storage.next(),
+                // The buffered write replaces the stored value
+                Ordering::Equal => {
+                    self.storage.next();
+                }
+                Ordering::Greater => {}
+            }
+            if let Some((key, Some(value))) = self.writes.next() {
+                return Some((key.clone(), value.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amp::messages::{AMPMsg, AMPPkt};
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{
+        coin,
+        testing::{mock_dependencies, mock_env, mock_info, MockStorage},
+        to_json_binary, Addr,
+    };
+    use cw_storage_plus::Item;
+    use strum_macros::AsRefStr;
+
+    const COUNT: Item<u32> = Item::new("count");
+
+    #[cw_serde]
+    #[derive(AsRefStr)]
+    enum TestMsg {
+        #[serde(rename = "amp_receive")]
+        AMPReceive(AMPPkt),
+        Increment {},
+        Fail {},
+        Batch {
+            msgs: Vec<Binary>,
+            atomic: bool,
+            funds: Option<Vec<Vec<Coin>>>,
+        },
+    }
+
+    /// Increments the count before handling the message so failed messages leave changes to revert
+    fn handle_execute(ctx: ExecuteContext, msg: TestMsg) -> Result<Response, ContractError> {
+        let count = COUNT.may_load(ctx.deps.storage)?.unwrap_or_default() + 1;
+        COUNT.save(ctx.deps.storage, &count)?;
+        match msg {
+            TestMsg::Increment {} => Ok(Response::new()
+                .add_attribute("count", count.to_string())
+                .add_attribute("funds", format!("{:?}", ctx.info.funds))),
+            TestMsg::Fail {} => Err(ContractError::Unauthorized {}),
+            _ => Err(ContractError::NotImplemented { msg: None }),
+        }
+    }
+
+    fn batch_msg(msg: &TestMsg) -> Binary {
+        to_json_binary(msg).unwrap()
+    }
+
+    fn batch_result(index: u32, result: &str) -> Event {
+        Event::new("batch_result")
+            .add_attribute("index", index.to_string())
+            .add_attribute("result", result)
+    }
+
+    #[test]
+    fn test_execute_batch() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let increment = batch_msg(&TestMsg::Increment {});
+        let fail = batch_msg(&TestMsg::Fail {});
+
+        // Failed messages are reverted when not atomic
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+        let res = contract
+            .execute_batch(
+                ctx,
+                vec![increment.clone(), fail.clone(), increment.clone()],
+                false,
+                None,
+                handle_execute,
+            )
+            .unwrap();
+        assert_eq!(COUNT.load(deps.as_ref().storage).unwrap(), 2);
+        assert_eq!(
+            res.events,
+            vec![
+                batch_result(0, "success"),
+                batch_result(1, "failure").add_attribute("error", "Unauthorized"),
+                batch_result(2, "success"),
+            ]
+        );
+
+        // Any failure fails an atomic batch
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+        let err = contract
+            .execute_batch(
+                ctx,
+                vec![increment.clone(), fail],
+                true,
+                None,
+                handle_execute,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::BatchMessageFailed {
+                index: 1,
+                error: "Unauthorized".to_string()
+            }
+        );
+
+        // Batches cannot be nested
+        let nested = to_json_binary(&AndromedaMsg::Batch {
+            msgs: vec![increment],
+            atomic: true,
+            funds: None,
+        })
+        .unwrap();
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+        let err = contract
+            .execute_batch(ctx, vec![nested], false, None, handle_execute)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidBatchMessage {
+                index: 0,
+                msg: "Batch and AMP messages cannot be batched".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_execute_batch_funds() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let msgs = vec![
+            batch_msg(&TestMsg::Increment {}),
+            batch_msg(&TestMsg::Fail {}),
+            batch_msg(&TestMsg::Increment {}),
+        ];
+        let funds = vec![
+            vec![coin(60, "uandr")],
+            vec![coin(40, "uandr"), coin(10, "uusd")],
+            vec![],
+        ];
+
+        // The funds of every message must be listed
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+        let err = contract
+            .execute_batch(
+                ctx,
+                msgs.clone(),
+                false,
+                Some(funds[..2].to_vec()),
+                handle_execute,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidFunds {
+                msg: "The funds of a batch must be listed for each of its messages".to_string()
+            }
+        );
+
+        // The funds of the messages must match the funds attached
+        let ctx = ExecuteContext::new(
+            deps.as_mut(),
+            mock_info("sender", &[coin(100, "uandr")]),
+            mock_env(),
+        );
+        let err = contract
+            .execute_batch(
+                ctx,
+                msgs.clone(),
+                false,
+                Some(funds.clone()),
+                handle_execute,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidFunds {
+                msg: "The funds attached to a batch must equal the funds of its messages"
+                    .to_string()
+            }
+        );
+
+        // Each message is sent with its own funds and failed messages are refunded
+        let ctx = ExecuteContext::new(
+            deps.as_mut(),
+            mock_info("sender", &[coin(10, "uusd"), coin(100, "uandr")]),
+            mock_env(),
+        );
+        let res = contract
+            .execute_batch(ctx, msgs, false, Some(funds), handle_execute)
+            .unwrap();
+        let funds: Vec<String> = res
+            .attributes
+            .iter()
+            .filter(|attr| attr.key == "funds")
+            .map(|attr| attr.value.clone())
+            .collect();
+        assert_eq!(
+            funds,
+            vec![
+                format!("{:?}", vec![coin(60, "uandr")]),
+                format!("{:?}", Vec::<Coin>::new())
+            ]
+        );
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "sender".to_string(),
+                amount: vec![coin(40, "uandr"), coin(10, "uusd")],
+            })]
+        );
+    }
+
+    #[test]
+    fn test_execute_entry_batch() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .kernel_address
+            .save(deps.as_mut().storage, &Addr::unchecked("kernel"))
+            .unwrap();
+        let batch = TestMsg::Batch {
+            msgs: vec![
+                batch_msg(&TestMsg::Increment {}),
+                batch_msg(&TestMsg::Increment {}),
+            ],
+            atomic: true,
+            funds: None,
+        };
+
+        // Batches sent directly are executed message by message
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+        let res = contract
+            .execute_entry(ctx, batch.clone(), handle_execute)
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![batch_result(0, "success"), batch_result(1, "success")]
+        );
+        assert_eq!(COUNT.load(deps.as_ref().storage).unwrap(), 2);
+
+        // Batches relayed by the kernel are executed message by message
+        let packet = AMPPkt::new(
+            "origin",
+            "origin",
+            vec![AMPMsg::new(
+                "contract",
+                to_json_binary(&batch).unwrap(),
+                None,
+            )],
+        );
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("kernel", &[]), mock_env());
+        let res = contract
+            .execute_entry(ctx, TestMsg::AMPReceive(packet), handle_execute)
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![batch_result(0, "success"), batch_result(1, "success")]
+        );
+        assert_eq!(COUNT.load(deps.as_ref().storage).unwrap(), 4);
+
+        // Other messages are passed to the handler
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+        contract
+            .execute_entry(ctx, TestMsg::Increment {}, handle_execute)
+            .unwrap();
+        assert_eq!(COUNT.load(deps.as_ref().storage).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_storage_cache_range() {
+        let mut storage = MockStorage::new();
+        for key in [b"a", b"b", b"c", b"d"] {
+            storage.set(key, key);
+        }
+        let mut cache = StorageCache::new(&storage);
+        cache.set(b"b", b"B");
+        cache.remove(b"c");
+        cache.set(b"e", b"e");
+        cache.set(b"0", b"0");
+
+        let keys = |order| -> Vec<(Vec<u8>, Vec<u8>)> {
+            cache.range(Some(b"a"), Some(b"e"), order).collect()
+        };
+        let expected = vec![
+            (b"a".to_vec(), b"a".to_vec()),
+            (b"b".to_vec(), b"B".to_vec()),
+            (b"d".to_vec(), b"d".to_vec()),
+        ];
+        assert_eq!(keys(Order::Ascending), expected);
+        assert_eq!(
+            keys(Order::Descending),
+            expected.into_iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(cache.range(None, None, Order::Ascending).count(), 5);
+        assert_eq!(
+            cache
+                .range(Some(b"e"), Some(b"a"), Order::Ascending)
+                .count(),
+            0
+        );
+    }
+}
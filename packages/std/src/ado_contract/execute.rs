@@ -2,7 +2,9 @@ use crate::ado_contract::ADOContract;
 use crate::amp::addresses::AndrAddr;
 use crate::amp::messages::AMPPkt;
 use crate::common::context::ExecuteContext;
+use crate::common::migration::MigrationRegistry;
 use crate::common::reply::ReplyId;
+use crate::common::Funds;
 use crate::error::from_semver;
 use crate::os::{aos_querier::AOSQuerier, economics::ExecuteMsg as EconomicsExecuteMsg};
 use crate::{
@@ -18,7 +20,7 @@ use semver::Version;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-type ExecuteContextFunction<M, E> = fn(ExecuteContext, M) -> Result<Response, E>;
+pub(crate) type ExecuteContextFunction<M, E> = fn(ExecuteContext, M) -> Result<Response, E>;
 
 impl<'a> ADOContract<'a> {
     pub fn instantiate(
@@ -85,38 +87,118 @@ impl<'a> ADOContract<'a> {
     }
 
     /// Handles execution of ADO specific messages.
+    ///
+    /// Errors with `ContractError::Paused` if the message is currently paused.
+    /// Admin messages sent by the owner are queued instead if the admin timelock is enabled.
     pub fn execute(
         &self,
-        ctx: ExecuteContext,
+        mut ctx: ExecuteContext,
         msg: impl Serialize,
     ) -> Result<Response, ContractError> {
         let msg = to_json_binary(&msg)?;
         match from_json::<AndromedaMsg>(&msg) {
-            Ok(msg) => match msg {
-                AndromedaMsg::Ownership(msg) => {
-                    self.execute_ownership(ctx.deps, ctx.env, ctx.info, msg)
-                }
-                AndromedaMsg::UpdateAppContract { address } => {
-                    self.execute_update_app_contract(ctx.deps, ctx.info, address, None)
-                }
-                #[cfg(feature = "rates")]
-                AndromedaMsg::Rates(rates_message) => self.execute_rates(ctx, rates_message),
-                AndromedaMsg::UpdateKernelAddress { address } => {
-                    self.update_kernel_address(ctx.deps, ctx.info, address)
+            Ok(msg) => {
+                self.ensure_not_paused(ctx.deps.storage, &ctx.env, msg.as_ref())?;
+                if let Some(res) =
+                    self.queue_admin_action(&mut ctx.deps, &ctx.env, &ctx.info, &msg)?
+                {
+                    return Ok(res);
                 }
-                AndromedaMsg::Permissioning(msg) => self.execute_permissioning(ctx, msg),
-                AndromedaMsg::AMPReceive(_) => panic!("AMP Receive should be handled separately"),
-            },
+                self.execute_andromeda_msg(ctx, msg)
+            }
             _ => Err(ContractError::NotImplemented { msg: None }),
         }
     }
 
+    /// Dispatches the given ADO message, bypassing the admin timelock
+    pub(crate) fn execute_andromeda_msg(
+        &self,
+        ctx: ExecuteContext,
+        msg: AndromedaMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            AndromedaMsg::Ownership(msg) => self.handle_ownership(ctx.deps, ctx.env, ctx.info, msg),
+            AndromedaMsg::UpdateAppContract { address } => {
+                self.execute_update_app_contract(ctx.deps, ctx.info, address, None)
+            }
+            #[cfg(feature = "rates")]
+            AndromedaMsg::Rates(rates_message) => self.execute_rates(ctx, rates_message),
+            AndromedaMsg::UpdateKernelAddress { address } => {
+                self.update_kernel_address(ctx.deps, ctx.info, address)
+            }
+            AndromedaMsg::Permissioning(msg) => self.execute_permissioning(ctx, msg),
+            AndromedaMsg::Pause { actions, until } => self.execute_pause(ctx, actions, until),
+            AndromedaMsg::Unpause {} => self.execute_unpause(ctx),
+            #[cfg(feature = "modules")]
+            AndromedaMsg::RegisterModule { module } => {
+                self.validate_module_address(&ctx.deps.as_ref(), &module)?;
+                self.execute_register_module(
+                    ctx.deps.storage,
+                    ctx.info.sender.as_str(),
+                    module,
+                    true,
+                )
+            }
+            #[cfg(feature = "modules")]
+            AndromedaMsg::DeregisterModule { module_idx } => {
+                self.execute_deregister_module(ctx.deps, ctx.info, module_idx)
+            }
+            #[cfg(feature = "modules")]
+            AndromedaMsg::AlterModule { module_idx, module } => {
+                self.validate_module_address(&ctx.deps.as_ref(), &module)?;
+                self.execute_alter_module(ctx.deps, ctx.info, module_idx, module)
+            }
+            AndromedaMsg::AMPReceive(_) => panic!("AMP Receive should be handled separately"),
+            AndromedaMsg::Batch { .. } => Err(ContractError::NotImplemented {
+                msg: Some("Batch messages must be handled by the contract entry point".to_string()),
+            }),
+        }
+    }
+
     pub fn migrate(
         &self,
         deps: DepsMut,
         contract_name: &str,
         contract_version: &str,
     ) -> Result<Response, ContractError> {
+        let contract_name =
+            self.validate_migration(deps.as_ref(), contract_name, contract_version)?;
+        set_contract_version(deps.storage, contract_name, contract_version)?;
+        Ok(Response::default())
+    }
+
+    /// Migrates the contract, running every step of the registry between the stored version and the new version.
+    pub fn migrate_with_registry(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        contract_name: &str,
+        contract_version: &str,
+        registry: &MigrationRegistry,
+    ) -> Result<Response, ContractError> {
+        let contract_name =
+            self.validate_migration(deps.as_ref(), contract_name, contract_version)?;
+        let stored = get_contract_version(deps.storage)?;
+        let steps = registry.run(deps.branch(), env, &stored.version, contract_version)?;
+        set_contract_version(deps.storage, contract_name, contract_version)?;
+        Ok(Response::new()
+            .add_attribute("action", "migrate")
+            .add_attribute("from_version", stored.version)
+            .add_attribute("to_version", contract_version)
+            .add_attributes(
+                steps
+                    .into_iter()
+                    .map(|step| attr("migration_step", step.to_string())),
+            ))
+    }
+
+    /// Ensures the stored contract matches the new contract and is an older version, returning the contract name to store
+    fn validate_migration<'b>(
+        &self,
+        deps: Deps,
+        contract_name: &'b str,
+        contract_version: &str,
+    ) -> Result<&'b str, ContractError> {
         // New version
         let version: Version = contract_version.parse().map_err(from_semver)?;
 
@@ -144,10 +226,9 @@ impl<'a> ADOContract<'a> {
                 previous_contract: stored.version,
             }
         );
-
-        set_contract_version(deps.storage, contract_name, contract_version)?;
-        Ok(Response::default())
+        Ok(contract_name)
     }
+
     /// Validates all provided `AndrAddr` addresses.
     ///
     /// Requires the VFS address to be set if any address is a VFS path.
@@ -217,9 +298,37 @@ impl<'a> ADOContract<'a> {
         AOSQuerier::adodb_address_getter(querier, &kernel_address)
     }
 
+    /// Handles a message received by a contract's execute entry point.
+    ///
+    /// AMP packets and batches are unpacked and their messages passed to the provided handler,
+    /// any other message is passed to the handler directly.
+    pub fn execute_entry<M, E>(
+        &self,
+        ctx: ExecuteContext,
+        msg: M,
+        handler: ExecuteContextFunction<M, E>,
+    ) -> Result<Response, E>
+    where
+        M: AsRef<str> + Serialize + DeserializeOwned,
+        E: From<ContractError> + From<StdError> + ToString,
+    {
+        if !matches!(msg.as_ref(), "AMPReceive" | "Batch") {
+            return handler(ctx, msg);
+        }
+        match from_json::<AndromedaMsg>(to_json_binary(&msg)?)? {
+            AndromedaMsg::AMPReceive(packet) => self.execute_amp_receive(ctx, packet, handler),
+            AndromedaMsg::Batch {
+                msgs,
+                atomic,
+                funds,
+            } => self.execute_batch(ctx, msgs, atomic, funds, handler),
+            _ => handler(ctx, msg),
+        }
+    }
+
     /// Handles receiving and verifies an AMPPkt from the Kernel before executing the appropriate messages.
     ///
-    /// Calls the provided handler with the AMP packet attached within the context.
+    /// Calls the provided handler with the AMP packet attached within the context, batches are executed message by message.
     pub fn execute_amp_receive<M: DeserializeOwned, E>(
         &self,
         ctx: ExecuteContext,
@@ -227,12 +336,20 @@ impl<'a> ADOContract<'a> {
         handler: ExecuteContextFunction<M, E>,
     ) -> Result<Response, E>
     where
-        E: From<ContractError> + From<StdError>,
+        E: From<ContractError> + From<StdError> + ToString,
     {
         packet.verify_origin(&ctx.info, &ctx.deps.as_ref())?;
         let ctx = ctx.with_ctx(packet.clone());
         let msg_opt = packet.messages.pop();
         if let Some(msg_opt) = msg_opt {
+            if let Ok(AndromedaMsg::Batch {
+                msgs,
+                atomic,
+                funds,
+            }) = from_json::<AndromedaMsg>(&msg_opt.message)
+            {
+                return self.execute_batch(ctx, msgs, atomic, funds, handler);
+            }
             let msg: M = from_json(msg_opt.message)?;
             let response = handler(ctx, msg)?;
             Ok(response)
@@ -253,17 +370,29 @@ impl<'a> ADOContract<'a> {
     ///
     /// If any of the above cannot pay the fee the remainder is paid by the next in the list until no remainder remains.
     /// If there is still a remainder after all 3 payments then the fee cannot be paid and the message will error.
+    ///
+    /// The funds attached to the action are forwarded so that percentage based fees can be calculated.
     pub fn pay_fee(
         &self,
         storage: &dyn Storage,
         querier: &QuerierWrapper,
         action: String,
         payee: Addr,
+        funds: &[Funds],
     ) -> Result<SubMsg, ContractError> {
         let kernel_address = self.get_kernel_address(storage)?;
         let economics_contract_address =
             AOSQuerier::kernel_address_getter(querier, &kernel_address, "economics")?;
-        let economics_msg = EconomicsExecuteMsg::PayFee { action, payee };
+        let funds = if funds.is_empty() {
+            None
+        } else {
+            Some(funds.to_vec())
+        };
+        let economics_msg = EconomicsExecuteMsg::PayFee {
+            action,
+            payee,
+            funds,
+        };
         let msg = SubMsg::reply_on_error(
             CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: economics_contract_address.to_string(),
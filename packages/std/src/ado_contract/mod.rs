@@ -1,7 +1,11 @@
 pub mod app;
+mod batch;
 mod execute;
+#[cfg(feature = "modules")]
+pub mod modules;
 
 mod ownership;
+pub mod pause;
 
 pub mod permissioning;
 mod query;
@@ -0,0 +1,63 @@
+use crate::{ado_base::modules::Module, ado_contract::ADOContract, error::ContractError};
+use cosmwasm_std::{ensure, DepsMut, MessageInfo, Response, Storage, Uint64};
+
+impl<'a> ADOContract<'a> {
+    /// Registers the given module, the sender must be the owner or an operator.
+    ///
+    /// If `should_validate` is set all registered modules are validated after registration.
+    pub fn execute_register_module(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &str,
+        module: Module,
+        should_validate: bool,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_owner_or_operator(storage, sender)?,
+            ContractError::Unauthorized {}
+        );
+        let idx = self.register_module(storage, &module)?;
+        if should_validate {
+            self.validate_modules(&self.load_modules(storage)?)?;
+        }
+        Ok(Response::default()
+            .add_attribute("action", "register_module")
+            .add_attribute("module_idx", idx.to_string()))
+    }
+
+    /// Alters the module at the given index, the sender must be the owner or an operator.
+    pub fn execute_alter_module(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        module_idx: Uint64,
+        module: Module,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_owner_or_operator(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.alter_module(deps.storage, module_idx, &module)?;
+        self.validate_modules(&self.load_modules(deps.storage)?)?;
+        Ok(Response::default()
+            .add_attribute("action", "alter_module")
+            .add_attribute("module_idx", module_idx))
+    }
+
+    /// Deregisters the module at the given index, the sender must be the owner or an operator.
+    pub fn execute_deregister_module(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        module_idx: Uint64,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_owner_or_operator(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.deregister_module(deps.storage, module_idx)?;
+        Ok(Response::default()
+            .add_attribute("action", "deregister_module")
+            .add_attribute("module_idx", module_idx))
+    }
+}
@@ -1,5 +1,3 @@
-use std::convert::TryInto;
-
 use crate::{
     ado_base::hooks::{AndromedaHook, HookMsg, OnFundsTransferResponse},
     ado_contract::state::ADOContract,
@@ -11,7 +9,7 @@ use cosmwasm_std::{
 use cw_storage_plus::Bound;
 use serde::de::DeserializeOwned;
 
-use crate::os::kernel::QueryMsg as KernelQueryMsg;
+use crate::os::kernel::{QueryMsg as KernelQueryMsg, VerifyAddressResponse};
 use crate::{ado_base::modules::Module, error::ContractError};
 
 pub mod execute;
@@ -49,9 +47,9 @@ impl<'a> ADOContract<'a> {
             address: addr.to_string(),
         };
         let kernel_addr = self.get_kernel_address(deps.storage)?;
-        let res: bool = deps.querier.query_wasm_smart(kernel_addr, &query)?;
+        let res: VerifyAddressResponse = deps.querier.query_wasm_smart(kernel_addr, &query)?;
         ensure!(
-            res,
+            res.verify_address,
             ContractError::InvalidModule {
                 msg: Some(format!(
                     "Module {} is not a valid ADO",
@@ -62,10 +60,12 @@ impl<'a> ADOContract<'a> {
         Ok(())
     }
 
+    /// Registers the given modules, intended for use when instantiating an ADO
     pub fn register_modules(
         &self,
         sender: &str,
         storage: &mut dyn Storage,
+        modules: Option<Vec<Module>>,
     ) -> Result<Response, ContractError> {
         let mut resp = Response::new();
         let modules = modules.unwrap_or_default();
@@ -146,15 +146,13 @@ impl<'a> ADOContract<'a> {
 
     /// Loads all registered modules in Vector form
     pub(crate) fn load_modules(&self, storage: &dyn Storage) -> Result<Vec<Module>, ContractError> {
-        // if !self.module_idx.may_load(storage)?.is_some() {
-        //     return Ok(Vec::new());
-        // }
         let module_idx = self.module_idx.may_load(storage)?.unwrap_or(1);
+        let module_count = usize::try_from(module_idx).map_err(|_| ContractError::Overflow {})?;
         let min = Some(Bound::inclusive("1"));
         let modules: Vec<Module> = self
             .module_info
             .range(storage, min, None, Order::Ascending)
-            .take(module_idx.try_into().unwrap())
+            .take(module_count)
             .flatten()
             .map(|(_vec, module)| module)
             .collect();
@@ -164,17 +162,19 @@ impl<'a> ADOContract<'a> {
 
     /// Loads all registered module addresses in Vector form
     fn load_module_addresses(&self, deps: &Deps) -> Result<Vec<String>, ContractError> {
+        let modules = self.load_modules(deps.storage)?;
+        if modules.is_empty() {
+            return Ok(vec![]);
+        }
         let vfs_address = self.get_vfs_address(deps.storage, &deps.querier)?;
-        let module_addresses: Vec<String> = self
-            .load_modules(deps.storage)?
+        let module_addresses = modules
             .into_iter()
             .map(|m| {
-                m.address
-                    .get_raw_address_from_vfs(deps, vfs_address.clone())
-                    .unwrap()
-                    .to_string()
+                Ok(m.address
+                    .get_raw_address_from_vfs(deps, vfs_address.clone())?
+                    .to_string())
             })
-            .collect();
+            .collect::<Result<Vec<String>, ContractError>>()?;
 
         Ok(module_addresses)
     }
@@ -208,8 +208,11 @@ impl<'a> ADOContract<'a> {
         let mut msgs: Vec<SubMsg> = Vec::new();
         let mut events: Vec<Event> = Vec::new();
 
-        let vfs_address = self.get_vfs_address(deps.storage, &deps.querier)?;
         let modules: Vec<Module> = self.load_modules(deps.storage)?;
+        if modules.is_empty() {
+            return Ok((msgs, events, remainder));
+        }
+        let vfs_address = self.get_vfs_address(deps.storage, &deps.querier)?;
         for module in modules {
             let module_address = module
                 .address
@@ -0,0 +1,25 @@
+use crate::{ado_base::modules::Module, ado_contract::ADOContract, error::ContractError};
+use cosmwasm_std::{Deps, Order, Uint64};
+use cw_storage_plus::Bound;
+
+impl<'a> ADOContract<'a> {
+    /// Queries the module registered at the given index
+    pub fn query_module(&self, deps: Deps, id: Uint64) -> Result<Module, ContractError> {
+        let id = id.to_string();
+        Ok(self.module_info.load(deps.storage, &id)?)
+    }
+
+    /// Queries the indices of all registered modules
+    pub fn query_module_ids(&self, deps: Deps) -> Result<Vec<String>, ContractError> {
+        let module_idx = self.module_idx.may_load(deps.storage)?.unwrap_or(1);
+        let module_count = usize::try_from(module_idx).map_err(|_| ContractError::Overflow {})?;
+        let min = Some(Bound::inclusive("1"));
+        let module_ids: Vec<String> = self
+            .module_info
+            .keys(deps.storage, min, None, Order::Ascending)
+            .take(module_count)
+            .flatten()
+            .collect();
+        Ok(module_ids)
+    }
+}
@@ -1,19 +1,74 @@
+use crate::common::context::ExecuteContext;
 use crate::common::expiration::Expiry;
-use crate::common::MillisecondsExpiration;
+use crate::common::response::AndrEvent;
+use crate::common::{Milliseconds, MillisecondsDuration, MillisecondsExpiration};
 use crate::error::ContractError;
 use crate::{
-    ado_base::ownership::{ContractPotentialOwnerResponse, OwnershipMessage},
+    ado_base::{
+        ownership::{
+            ContractPotentialOwnerResponse, OwnershipMessage, PendingAdminAction,
+            PendingAdminActionsResponse,
+        },
+        pause::GUARDIAN_ROLE,
+        AndromedaMsg,
+    },
     ado_contract::ADOContract,
 };
-use cosmwasm_std::{attr, ensure, Addr, DepsMut, Env, MessageInfo, Response, Storage};
-use cw_storage_plus::Item;
+use cosmwasm_std::{
+    attr, ensure, Addr, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Uint64,
+};
+use cw_storage_plus::{Bound, Item, Map};
 
 const POTENTIAL_OWNER: Item<Addr> = Item::new("andr_potential_owner");
 const POTENTIAL_OWNER_EXPIRATION: Item<MillisecondsExpiration> =
     Item::new("andr_potential_owner_expiration");
+const ADMIN_TIMELOCK: Item<MillisecondsDuration> = Item::new("andr_admin_timelock");
+const ADMIN_ACTION_IDX: Item<u64> = Item::new("andr_admin_action_idx");
+const PENDING_ADMIN_ACTIONS: Map<u64, PendingAdminAction> = Map::new("andr_pending_admin_actions");
+
+const MAX_QUERY_LIMIT: u32 = 50;
+const DEFAULT_QUERY_LIMIT: u32 = 25;
+
+/// Whether the given message is an admin message subject to the admin timelock
+fn is_admin_action(msg: &AndromedaMsg) -> bool {
+    match msg {
+        AndromedaMsg::Ownership(msg) => matches!(
+            msg,
+            OwnershipMessage::UpdateOwner { .. }
+                | OwnershipMessage::Disown
+                | OwnershipMessage::SetAdminTimelock { .. }
+        ),
+        AndromedaMsg::UpdateAppContract { .. }
+        | AndromedaMsg::UpdateKernelAddress { .. }
+        | AndromedaMsg::Permissioning(_) => true,
+        #[cfg(feature = "rates")]
+        AndromedaMsg::Rates(_) => true,
+        #[cfg(feature = "modules")]
+        AndromedaMsg::RegisterModule { .. }
+        | AndromedaMsg::DeregisterModule { .. }
+        | AndromedaMsg::AlterModule { .. } => true,
+        _ => false,
+    }
+}
 
 impl<'a> ADOContract<'a> {
+    /// Handles ownership messages, queueing admin messages sent by the owner if the admin timelock is enabled
     pub fn execute_ownership(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: OwnershipMessage,
+    ) -> Result<Response, ContractError> {
+        let andromeda_msg = AndromedaMsg::Ownership(msg.clone());
+        if let Some(res) = self.queue_admin_action(&mut deps, &env, &info, &andromeda_msg)? {
+            return Ok(res);
+        }
+        self.handle_ownership(deps, env, info, msg)
+    }
+
+    /// Handles ownership messages, bypassing the admin timelock
+    pub(crate) fn handle_ownership(
         &self,
         deps: DepsMut,
         env: Env,
@@ -28,9 +83,145 @@ impl<'a> ADOContract<'a> {
             OwnershipMessage::RevokeOwnershipOffer => self.revoke_ownership_offer(deps, info),
             OwnershipMessage::AcceptOwnership => self.accept_ownership(deps, env, info),
             OwnershipMessage::Disown => self.disown(deps, info),
+            OwnershipMessage::SetAdminTimelock { delay } => {
+                self.set_admin_timelock(deps, info, delay)
+            }
+            OwnershipMessage::ExecuteAdminAction { id } => {
+                self.execute_admin_action(deps, env, info, id)
+            }
+            OwnershipMessage::CancelAdminAction { id } => {
+                self.cancel_admin_action(deps, env, info, id)
+            }
         }
     }
 
+    /// Queues the given message if it is an admin message sent by the owner while the admin timelock is enabled.
+    ///
+    /// Returns `None` if the message should be executed immediately.
+    pub(crate) fn queue_admin_action(
+        &self,
+        deps: &mut DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        msg: &AndromedaMsg,
+    ) -> Result<Option<Response>, ContractError> {
+        let delay = match ADMIN_TIMELOCK.may_load(deps.storage)? {
+            Some(delay) => delay,
+            None => return Ok(None),
+        };
+        if !is_admin_action(msg) || !self.is_contract_owner(deps.storage, info.sender.as_str())? {
+            return Ok(None);
+        }
+
+        let id = ADMIN_ACTION_IDX.may_load(deps.storage)?.unwrap_or(0) + 1;
+        ADMIN_ACTION_IDX.save(deps.storage, &id)?;
+        let executable_at =
+            Milliseconds::from_nanos(env.block.time.nanos()).plus_milliseconds(delay);
+        PENDING_ADMIN_ACTIONS.save(
+            deps.storage,
+            id,
+            &PendingAdminAction {
+                id: Uint64::new(id),
+                msg: msg.clone(),
+                executable_at,
+            },
+        )?;
+
+        Ok(Some(Response::new().add_attributes(vec![
+            attr("action", "queue_admin_action"),
+            attr("id", id.to_string()),
+            attr("executable_at", executable_at.to_string()),
+        ])))
+    }
+
+    /// Sets or removes the admin timelock delay. **Only executable by the contract owner.**
+    pub fn set_admin_timelock(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        delay: Option<MillisecondsDuration>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        match delay {
+            Some(delay) => {
+                ensure!(
+                    !delay.is_zero(),
+                    ContractError::InvalidParameter {
+                        error: Some("Admin timelock delay cannot be zero".to_string())
+                    }
+                );
+                ADMIN_TIMELOCK.save(deps.storage, &delay)?;
+            }
+            None => ADMIN_TIMELOCK.remove(deps.storage),
+        }
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "set_admin_timelock"),
+            attr(
+                "delay",
+                delay.map_or("none".to_string(), |delay| delay.to_string()),
+            ),
+        ]))
+    }
+
+    /// Executes a queued admin action once its delay has passed. **Only executable by the contract owner.**
+    pub fn execute_admin_action(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: Uint64,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let action = PENDING_ADMIN_ACTIONS
+            .may_load(deps.storage, id.u64())?
+            .ok_or(ContractError::AdminActionNotFound { id: id.u64() })?;
+        ensure!(
+            action.executable_at.is_expired(&env.block),
+            ContractError::AdminActionTimelocked {
+                id: id.u64(),
+                executable_at: action.executable_at,
+            }
+        );
+        PENDING_ADMIN_ACTIONS.remove(deps.storage, id.u64());
+
+        let res = self.execute_andromeda_msg(ExecuteContext::new(deps, info, env), action.msg)?;
+        Ok(res.add_attributes(vec![
+            attr("action", "execute_admin_action"),
+            attr("id", id.to_string()),
+        ]))
+    }
+
+    /// Cancels a queued admin action. **Only executable by the contract owner or members of the guardian role.**
+    pub fn cancel_admin_action(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: Uint64,
+    ) -> Result<Response, ContractError> {
+        let sender = info.sender.as_str();
+        ensure!(
+            self.is_contract_owner(deps.storage, sender)?
+                || self.is_role_member(deps.as_ref(), &env, GUARDIAN_ROLE, sender)?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(
+            PENDING_ADMIN_ACTIONS.has(deps.storage, id.u64()),
+            ContractError::AdminActionNotFound { id: id.u64() }
+        );
+        PENDING_ADMIN_ACTIONS.remove(deps.storage, id.u64());
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "cancel_admin_action"),
+            attr("id", id.to_string()),
+        ]))
+    }
+
     /// Updates the current contract owner. **Only executable by the current contract owner.**
     pub fn update_owner(
         &self,
@@ -96,13 +287,22 @@ impl<'a> ADOContract<'a> {
             ensure!(!exp.is_expired(&env.block), ContractError::Unauthorized {});
         }
 
+        let previous_owner = self.owner.load(deps.storage)?;
         self.owner.save(deps.storage, &new_owner_addr)?;
         POTENTIAL_OWNER.remove(deps.storage);
         POTENTIAL_OWNER_EXPIRATION.remove(deps.storage);
-        Ok(Response::new().add_attributes(vec![
-            attr("action", "accept_ownership"),
-            attr("value", new_owner_addr.to_string()),
-        ]))
+        Ok(Response::new()
+            .add_attributes(vec![
+                attr("action", "accept_ownership"),
+                attr("value", new_owner_addr.to_string()),
+            ])
+            .add_event(
+                AndrEvent::OwnershipChanged {
+                    previous_owner: previous_owner.to_string(),
+                    new_owner: Some(new_owner_addr.to_string()),
+                }
+                .into(),
+            ))
     }
 
     /// Disowns the contract. **Only executable by the current contract owner.**
@@ -112,7 +312,15 @@ impl<'a> ADOContract<'a> {
             ContractError::Unauthorized {}
         );
         self.owner.save(deps.storage, &Addr::unchecked("null"))?;
-        Ok(Response::new().add_attributes(vec![attr("action", "disown")]))
+        Ok(Response::new()
+            .add_attributes(vec![attr("action", "disown")])
+            .add_event(
+                AndrEvent::OwnershipChanged {
+                    previous_owner: info.sender.to_string(),
+                    new_owner: None,
+                }
+                .into(),
+            ))
     }
 
     /// Helper function to query if a given address is the current contract owner.
@@ -157,21 +365,46 @@ impl<'a> ADOContract<'a> {
             expiration,
         })
     }
+
+    pub fn query_pending_admin_actions(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<PendingAdminActionsResponse, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let actions = PENDING_ADMIN_ACTIONS
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|action| action.map(|(_, action)| action))
+            .collect::<StdResult<Vec<PendingAdminAction>>>()?;
+        Ok(PendingAdminActionsResponse {
+            delay: ADMIN_TIMELOCK.may_load(storage)?,
+            actions,
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, DepsMut,
+        Addr, DepsMut, Uint64,
     };
 
     use crate::{
+        ado_base::{ownership::OwnershipMessage, AndromedaMsg},
         ado_contract::{
             ownership::{POTENTIAL_OWNER, POTENTIAL_OWNER_EXPIRATION},
             ADOContract,
         },
-        common::MillisecondsExpiration,
+        common::{context::ExecuteContext, Milliseconds, MillisecondsExpiration},
+        error::ContractError,
     };
 
     fn init(deps: DepsMut, owner: impl Into<String>) {
@@ -285,4 +518,120 @@ mod test {
         let saved_owner = contract.owner.load(deps.as_ref().storage).unwrap();
         assert_eq!(saved_owner, Addr::unchecked("null"));
     }
+
+    #[test]
+    fn test_admin_timelock() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let contract = ADOContract::default();
+        init(deps.as_mut(), "owner");
+        contract
+            .kernel_address
+            .save(deps.as_mut().storage, &Addr::unchecked("kernel"))
+            .unwrap();
+
+        // Enabling the timelock takes effect immediately
+        contract
+            .execute_ownership(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                OwnershipMessage::SetAdminTimelock {
+                    delay: Some(Milliseconds::from_seconds(100)),
+                },
+            )
+            .unwrap();
+
+        let msg = AndromedaMsg::UpdateKernelAddress {
+            address: Addr::unchecked("new_kernel"),
+        };
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract.execute(ctx, msg.clone()).unwrap();
+        let kernel_address = contract.get_kernel_address(deps.as_ref().storage).unwrap();
+        assert_eq!(kernel_address, Addr::unchecked("kernel"));
+
+        let pending = contract
+            .query_pending_admin_actions(deps.as_ref().storage, None, None)
+            .unwrap();
+        assert_eq!(pending.delay, Some(Milliseconds::from_seconds(100)));
+        assert_eq!(pending.actions.len(), 1);
+        assert_eq!(pending.actions[0].msg, msg);
+        let executable_at = pending.actions[0].executable_at;
+
+        let execute_msg = OwnershipMessage::ExecuteAdminAction { id: Uint64::one() };
+        let err = contract
+            .execute_ownership(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                execute_msg.clone(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AdminActionTimelocked {
+                id: 1,
+                executable_at
+            }
+        );
+
+        env.block.time = env.block.time.plus_seconds(100);
+        contract
+            .execute_ownership(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                execute_msg,
+            )
+            .unwrap();
+        let kernel_address = contract.get_kernel_address(deps.as_ref().storage).unwrap();
+        assert_eq!(kernel_address, Addr::unchecked("new_kernel"));
+
+        // Ownership changes are queued as well and can be cancelled during the window
+        contract
+            .execute_ownership(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                OwnershipMessage::Disown,
+            )
+            .unwrap();
+        assert!(contract
+            .is_contract_owner(deps.as_ref().storage, "owner")
+            .unwrap());
+
+        let cancel_msg = OwnershipMessage::CancelAdminAction { id: Uint64::new(2) };
+        let err = contract
+            .execute_ownership(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("attacker", &[]),
+                cancel_msg.clone(),
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        contract
+            .execute_ownership(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                cancel_msg,
+            )
+            .unwrap();
+
+        let pending = contract
+            .query_pending_admin_actions(deps.as_ref().storage, None, None)
+            .unwrap();
+        assert!(pending.actions.is_empty());
+
+        let err = contract
+            .execute_ownership(
+                deps.as_mut(),
+                env,
+                mock_info("owner", &[]),
+                OwnershipMessage::ExecuteAdminAction { id: Uint64::new(2) },
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::AdminActionNotFound { id: 2 });
+    }
 }
@@ -0,0 +1,369 @@
+use crate::{
+    ado_base::pause::{PauseInfo, PauseStatusResponse, GUARDIAN_ROLE},
+    ado_contract::ADOContract,
+    common::{context::ExecuteContext, expiration::Expiry},
+    error::ContractError,
+};
+use cosmwasm_std::{attr, ensure, Deps, Env, Response, Storage};
+use cw_storage_plus::Item;
+
+const PAUSE_INFO: Item<PauseInfo> = Item::new("andr_pause_info");
+
+impl<'a> ADOContract<'a> {
+    /// Pauses the given actions, or every action if none are provided.
+    /// **Only executable by the contract owner or members of the guardian role.**
+    ///
+    /// The owner replaces any existing pause, guardians may only widen or extend an active pause.
+    pub fn execute_pause(
+        &self,
+        ctx: ExecuteContext,
+        actions: Option<Vec<String>>,
+        until: Option<Expiry>,
+    ) -> Result<Response, ContractError> {
+        let ExecuteContext {
+            deps, info, env, ..
+        } = ctx;
+        let sender = info.sender.as_str();
+        let is_owner = self.is_contract_owner(deps.storage, sender)?;
+        ensure!(
+            is_owner || self.is_role_member(deps.as_ref(), &env, GUARDIAN_ROLE, sender)?,
+            ContractError::Unauthorized {}
+        );
+        if let Some(actions) = &actions {
+            ensure!(
+                !actions.is_empty(),
+                ContractError::InvalidParameter {
+                    error: Some("No actions provided to pause".to_string())
+                }
+            );
+        }
+        let until = until.map(|expiry| expiry.get_time(&env.block));
+        if let Some(until) = until {
+            ensure!(
+                !until.is_expired(&env.block),
+                ContractError::InvalidExpiration {}
+            );
+        }
+
+        let mut pause_info = PauseInfo { actions, until };
+        if !is_owner {
+            if let Some(current) = PAUSE_INFO
+                .may_load(deps.storage)?
+                .filter(|current| current.is_active(&env.block))
+            {
+                pause_info = current.widen(pause_info);
+            }
+        }
+        PAUSE_INFO.save(deps.storage, &pause_info)?;
+
+        let PauseInfo { actions, until } = pause_info;
+        let mut attributes = vec![
+            attr("action", "pause"),
+            attr(
+                "actions",
+                actions.map_or("all".to_string(), |actions| actions.join(", ")),
+            ),
+        ];
+        if let Some(until) = until {
+            attributes.push(attr("until", until.to_string()));
+        }
+        Ok(Response::new().add_attributes(attributes))
+    }
+
+    /// Lifts the current pause. **Only executable by the contract owner.**
+    pub fn execute_unpause(&self, ctx: ExecuteContext) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        PAUSE_INFO.remove(ctx.deps.storage);
+        Ok(Response::new().add_attributes(vec![attr("action", "unpause")]))
+    }
+
+    /// Errors with `ContractError::Paused` if the given action is currently paused
+    pub fn ensure_not_paused(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        if let Some(pause_info) = PAUSE_INFO.may_load(storage)? {
+            ensure!(
+                !pause_info.is_paused(&env.block, action),
+                ContractError::Paused {}
+            );
+        }
+        Ok(())
+    }
+
+    pub fn query_pause_status(
+        &self,
+        deps: Deps,
+        env: Env,
+    ) -> Result<PauseStatusResponse, ContractError> {
+        let response = match PAUSE_INFO.may_load(deps.storage)? {
+            Some(pause_info) if pause_info.is_active(&env.block) => PauseStatusResponse {
+                paused: true,
+                actions: pause_info.actions,
+                until: pause_info.until,
+            },
+            _ => PauseStatusResponse {
+                paused: false,
+                actions: None,
+                until: None,
+            },
+        };
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr,
+    };
+
+    use crate::{
+        ado_base::{permissioning::PermissioningMessage, AndromedaMsg},
+        ado_contract::permissioning::is_context_permissioned,
+        amp::AndrAddr,
+        common::Milliseconds,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_pause() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let pause = AndromedaMsg::Pause {
+            actions: None,
+            until: None,
+        };
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("guardian", &[]), env.clone());
+        let err = contract.execute(ctx, pause.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // Members of the guardian role may pause
+        for msg in [
+            PermissioningMessage::CreateRole {
+                role: GUARDIAN_ROLE.to_string(),
+                address_list: None,
+            },
+            PermissioningMessage::GrantRole {
+                role: GUARDIAN_ROLE.to_string(),
+                actors: vec![AndrAddr::from_string("guardian")],
+            },
+        ] {
+            let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+            contract
+                .execute(ctx, AndromedaMsg::Permissioning(msg))
+                .unwrap();
+        }
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("guardian", &[]), env.clone());
+        contract.execute(ctx, pause).unwrap();
+
+        let info = mock_info("user", &[]);
+        let err =
+            is_context_permissioned(&mut deps.as_mut(), &info, &env, &None, "action").unwrap_err();
+        assert_eq!(err, ContractError::Paused {});
+        // The owner is paused as well
+        let err = is_context_permissioned(
+            &mut deps.as_mut(),
+            &mock_info("owner", &[]),
+            &env,
+            &None,
+            "action",
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Paused {});
+        assert!(
+            is_context_permissioned(&mut deps.as_mut(), &info, &env, &None, "Unpause").unwrap()
+        );
+        // Base ADO messages are paused as well
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        let err = contract
+            .execute(
+                ctx,
+                AndromedaMsg::UpdateAppContract {
+                    address: "app_contract".to_string(),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::Paused {});
+
+        let status = contract
+            .query_pause_status(deps.as_ref(), env.clone())
+            .unwrap();
+        assert_eq!(
+            status,
+            PauseStatusResponse {
+                paused: true,
+                actions: None,
+                until: None,
+            }
+        );
+
+        // Only the owner may unpause
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("guardian", &[]), env.clone());
+        let err = contract.execute(ctx, AndromedaMsg::Unpause {}).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract.execute(ctx, AndromedaMsg::Unpause {}).unwrap();
+
+        assert!(is_context_permissioned(&mut deps.as_mut(), &info, &env, &None, "action").unwrap());
+        let status = contract.query_pause_status(deps.as_ref(), env).unwrap();
+        assert!(!status.paused);
+    }
+
+    #[test]
+    fn test_pause_actions_until() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        let err = contract
+            .execute(
+                ctx,
+                AndromedaMsg::Pause {
+                    actions: None,
+                    until: Some(Expiry::AtTime(Milliseconds::zero())),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::InvalidExpiration {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute(
+                ctx,
+                AndromedaMsg::Pause {
+                    actions: Some(vec!["action".to_string()]),
+                    until: Some(Expiry::FromNow(Milliseconds::from_seconds(60))),
+                },
+            )
+            .unwrap();
+
+        let info = mock_info("user", &[]);
+        let err = contract
+            .ensure_not_paused(deps.as_ref().storage, &env, "action")
+            .unwrap_err();
+        assert_eq!(err, ContractError::Paused {});
+        assert!(
+            is_context_permissioned(&mut deps.as_mut(), &info, &env, &None, "other_action")
+                .unwrap()
+        );
+
+        // The pause lifts once expired
+        env.block.time = env.block.time.plus_seconds(60);
+        assert!(is_context_permissioned(&mut deps.as_mut(), &info, &env, &None, "action").unwrap());
+        let status = contract.query_pause_status(deps.as_ref(), env).unwrap();
+        assert!(!status.paused);
+    }
+
+    #[test]
+    fn test_guardian_cannot_weaken_pause() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        for msg in [
+            PermissioningMessage::CreateRole {
+                role: GUARDIAN_ROLE.to_string(),
+                address_list: None,
+            },
+            PermissioningMessage::GrantRole {
+                role: GUARDIAN_ROLE.to_string(),
+                actors: vec![AndrAddr::from_string("guardian")],
+            },
+        ] {
+            let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+            contract
+                .execute(ctx, AndromedaMsg::Permissioning(msg))
+                .unwrap();
+        }
+
+        // The owner pauses every action indefinitely
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute(
+                ctx,
+                AndromedaMsg::Pause {
+                    actions: None,
+                    until: None,
+                },
+            )
+            .unwrap();
+
+        // A narrower, shorter pause from a guardian leaves the owner's pause in place
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("guardian", &[]), env.clone());
+        contract
+            .execute(
+                ctx,
+                AndromedaMsg::Pause {
+                    actions: Some(vec!["x".to_string()]),
+                    until: Some(Expiry::FromNow(Milliseconds(1))),
+                },
+            )
+            .unwrap();
+        let status = contract
+            .query_pause_status(deps.as_ref(), env.clone())
+            .unwrap();
+        assert_eq!(
+            status,
+            PauseStatusResponse {
+                paused: true,
+                actions: None,
+                until: None,
+            }
+        );
+
+        // Guardians may widen and extend a pause
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute(
+                ctx,
+                AndromedaMsg::Pause {
+                    actions: Some(vec!["action".to_string()]),
+                    until: Some(Expiry::FromNow(Milliseconds::from_seconds(60))),
+                },
+            )
+            .unwrap();
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("guardian", &[]), env.clone());
+        contract
+            .execute(
+                ctx,
+                AndromedaMsg::Pause {
+                    actions: Some(vec!["other_action".to_string()]),
+                    until: Some(Expiry::FromNow(Milliseconds::from_seconds(30))),
+                },
+            )
+            .unwrap();
+        let status = contract
+            .query_pause_status(deps.as_ref(), env.clone())
+            .unwrap();
+        assert_eq!(
+            status,
+            PauseStatusResponse {
+                paused: true,
+                actions: Some(vec!["action".to_string(), "other_action".to_string()]),
+                until: Some(Expiry::FromNow(Milliseconds::from_seconds(60)).get_time(&env.block)),
+            }
+        );
+    }
+}
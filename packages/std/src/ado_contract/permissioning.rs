@@ -1,12 +1,12 @@
-use crate::ado_base::permissioning::LocalPermission;
+use crate::ado_base::permissioning::{LocalPermission, RoleInfo, RolePermissionInfo};
 use crate::os::aos_querier::AOSQuerier;
 use crate::{
     ado_base::permissioning::{Permission, PermissionInfo, PermissioningMessage},
     amp::{messages::AMPPkt, AndrAddr},
-    common::{context::ExecuteContext, OrderBy},
+    common::{context::ExecuteContext, response::AndrEvent, OrderBy},
     error::ContractError,
 };
-use cosmwasm_std::{ensure, Deps, DepsMut, Env, MessageInfo, Order, Response, Storage};
+use cosmwasm_std::{ensure, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 
 use super::ADOContract;
@@ -65,10 +65,29 @@ impl<'a> ADOContract<'a> {
             PermissioningMessage::DisableActionPermissioning { action } => {
                 self.execute_disable_action_permission(ctx, action)
             }
+            PermissioningMessage::CreateRole { role, address_list } => {
+                self.execute_create_role(ctx, role, address_list)
+            }
+            PermissioningMessage::GrantRole { role, actors } => {
+                self.execute_grant_role(ctx, role, actors)
+            }
+            PermissioningMessage::RevokeRole { role, actors } => {
+                self.execute_revoke_role(ctx, role, actors)
+            }
+            PermissioningMessage::SetRolePermission {
+                role,
+                action,
+                permission,
+            } => self.execute_set_role_permission(ctx, role, action, permission),
+            PermissioningMessage::RemoveRolePermission { role, action } => {
+                self.execute_remove_role_permission(ctx, role, action)
+            }
         }
     }
     /// Determines if the provided actor is authorised to perform the given action
     ///
+    /// A permission set directly for the actor takes precedence over any permissions granted through the actor's roles
+    ///
     /// Returns an error if the given action is not permissioned for the given actor
     pub fn is_permissioned(
         &self,
@@ -135,7 +154,17 @@ impl<'a> ADOContract<'a> {
                 Ok(())
             }
             None => {
-                ensure!(!permissioned_action, ContractError::Unauthorized {});
+                let role_permissioned = self.is_role_permissioned(
+                    deps,
+                    &env,
+                    &action_string,
+                    &actor_string,
+                    permissioned_action,
+                )?;
+                ensure!(
+                    role_permissioned.unwrap_or(!permissioned_action),
+                    ContractError::Unauthorized {}
+                );
                 Ok(())
             }
         }
@@ -199,7 +228,96 @@ impl<'a> ADOContract<'a> {
                 }
                 Ok(())
             }
-            None => Err(ContractError::Unauthorized {}),
+            None => {
+                let role_permissioned =
+                    self.is_role_permissioned(deps, &env, &action_string, &actor_string, true)?;
+                ensure!(
+                    role_permissioned.unwrap_or(false),
+                    ContractError::Unauthorized {}
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Determines if the provided actor is authorised to perform the given action through its roles
+    ///
+    /// Returns `None` if the actor is not a member of any role with a permission for the action.
+    /// A blacklisting by any of the actor's roles takes precedence, otherwise the first role permitting the action is used.
+    fn is_role_permissioned(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        action: &str,
+        actor: &str,
+        strict: bool,
+    ) -> Result<Option<bool>, ContractError> {
+        let role_permissions = self
+            .role_permissions
+            .prefix(action)
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(String, LocalPermission)>>>()?;
+
+        let mut is_member = false;
+        let mut permitting_role = None;
+        for (role, permission) in role_permissions {
+            if !self.is_role_member(deps.as_ref(), env, &role, actor)? {
+                continue;
+            }
+            is_member = true;
+            if !permission.is_permissioned(env, strict) {
+                if let LocalPermission::Blacklisted(_) = permission {
+                    return Ok(Some(false));
+                }
+            } else if permitting_role.is_none() {
+                permitting_role = Some((role, permission));
+            }
+        }
+
+        if !is_member {
+            return Ok(None);
+        }
+        match permitting_role {
+            Some((role, mut permission)) => {
+                // Consume a use for a limited permission, only if the action is permissioned
+                if let LocalPermission::Limited { .. } = permission {
+                    if strict {
+                        permission.consume_use()?;
+                        self.role_permissions.save(
+                            deps.storage,
+                            (action, role.as_str()),
+                            &permission,
+                        )?;
+                    }
+                }
+                Ok(Some(true))
+            }
+            None => Ok(Some(false)),
+        }
+    }
+
+    /// Determines if the provided actor is a member of the given role
+    ///
+    /// If role membership is delegated to an address list, the actor must be permissioned in the address list
+    pub fn is_role_member(
+        &self,
+        deps: Deps,
+        env: &Env,
+        role: &str,
+        actor: &str,
+    ) -> Result<bool, ContractError> {
+        let role_info = match self.roles.may_load(deps.storage, role)? {
+            Some(role_info) => role_info,
+            None => return Ok(false),
+        };
+        match role_info.address_list {
+            Some(address_list) => {
+                let addr = address_list.get_raw_address(&deps)?;
+                Ok(AOSQuerier::get_permission(&deps.querier, &addr, actor)
+                    .map(|permission| permission.is_permissioned(env, true))
+                    .unwrap_or(false))
+            }
+            None => Ok(self.role_members.has(deps.storage, (role, actor))),
         }
     }
 
@@ -294,12 +412,23 @@ impl<'a> ADOContract<'a> {
             .collect::<Vec<_>>()
             .join(", ");
 
-        Ok(Response::default().add_attributes(vec![
-            ("action", "set_permission"),
-            ("actors", &actor_strs),
-            ("action", action.as_str()),
-            ("permission", permission.to_string().as_str()),
-        ]))
+        let events = actor_addrs.iter().map(|actor_addr| {
+            AndrEvent::PermissionChanged {
+                action: action.clone(),
+                actor: actor_addr.to_string(),
+                permission: Some(permission.to_string()),
+            }
+            .into()
+        });
+
+        Ok(Response::default()
+            .add_attributes(vec![
+                ("action", "set_permission"),
+                ("actors", &actor_strs),
+                ("action", action.as_str()),
+                ("permission", permission.to_string().as_str()),
+            ])
+            .add_events(events))
     }
 
     /// Execute handler for setting permission
@@ -330,11 +459,22 @@ impl<'a> ADOContract<'a> {
             .collect::<Vec<_>>()
             .join(", ");
 
-        Ok(Response::default().add_attributes(vec![
-            ("action", "remove_permission"),
-            ("actors", &actor_strs),
-            ("action", action.as_str()),
-        ]))
+        let events = actor_addrs.iter().map(|actor_addr| {
+            AndrEvent::PermissionChanged {
+                action: action.clone(),
+                actor: actor_addr.to_string(),
+                permission: None,
+            }
+            .into()
+        });
+
+        Ok(Response::default()
+            .add_attributes(vec![
+                ("action", "remove_permission"),
+                ("actors", &actor_strs),
+                ("action", action.as_str()),
+            ])
+            .add_events(events))
     }
 
     /// Enables permissioning for a given action
@@ -387,6 +527,190 @@ impl<'a> ADOContract<'a> {
         ]))
     }
 
+    fn load_role(&self, store: &dyn Storage, role: &str) -> Result<RoleInfo, ContractError> {
+        self.roles
+            .may_load(store, role)?
+            .ok_or(ContractError::RoleNotFound {
+                role: role.to_string(),
+            })
+    }
+
+    /// Execute handler for creating a role
+    pub fn execute_create_role(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        address_list: Option<AndrAddr>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(
+            !role.is_empty(),
+            ContractError::InvalidParameter {
+                error: Some("Role name cannot be empty".to_string())
+            }
+        );
+        ensure!(
+            !self.roles.has(ctx.deps.storage, &role),
+            ContractError::RoleAlreadyExists { role }
+        );
+        if let Some(address_list) = &address_list {
+            address_list.get_raw_address(&ctx.deps.as_ref())?;
+        }
+
+        self.roles.save(
+            ctx.deps.storage,
+            &role,
+            &RoleInfo {
+                role: role.clone(),
+                address_list: address_list.clone(),
+            },
+        )?;
+
+        let mut res = Response::default()
+            .add_attributes(vec![("action", "create_role"), ("role", role.as_str())]);
+        if let Some(address_list) = address_list {
+            res = res.add_attribute("address_list", address_list.to_string());
+        }
+        Ok(res)
+    }
+
+    /// Execute handler for granting a locally managed role to the given actors
+    pub fn execute_grant_role(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        actors: Vec<AndrAddr>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(!actors.is_empty(), ContractError::NoActorsProvided {});
+        let role_info = self.load_role(ctx.deps.storage, &role)?;
+        ensure!(
+            role_info.address_list.is_none(),
+            ContractError::RoleMembershipDelegated { role }
+        );
+
+        let mut actor_addrs = Vec::new();
+        for actor in actors {
+            let actor_addr = actor.get_raw_address(&ctx.deps.as_ref())?;
+            self.role_members.save(
+                ctx.deps.storage,
+                (role.as_str(), actor_addr.as_str()),
+                &true,
+            )?;
+            actor_addrs.push(actor_addr);
+        }
+
+        let actor_strs = actor_addrs
+            .iter()
+            .map(|addr| addr.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "grant_role"),
+            ("role", role.as_str()),
+            ("actors", &actor_strs),
+        ]))
+    }
+
+    /// Execute handler for revoking a locally managed role from the given actors
+    pub fn execute_revoke_role(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        actors: Vec<AndrAddr>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(!actors.is_empty(), ContractError::NoActorsProvided {});
+        let role_info = self.load_role(ctx.deps.storage, &role)?;
+        ensure!(
+            role_info.address_list.is_none(),
+            ContractError::RoleMembershipDelegated { role }
+        );
+
+        let mut actor_addrs = Vec::new();
+        for actor in actors {
+            let actor_addr = actor.get_raw_address(&ctx.deps.as_ref())?;
+            self.role_members
+                .remove(ctx.deps.storage, (role.as_str(), actor_addr.as_str()));
+            actor_addrs.push(actor_addr);
+        }
+
+        let actor_strs = actor_addrs
+            .iter()
+            .map(|addr| addr.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "revoke_role"),
+            ("role", role.as_str()),
+            ("actors", &actor_strs),
+        ]))
+    }
+
+    /// Execute handler for setting the permission of a role for an action
+    ///
+    /// **Whitelisted/Limited permissions will only work for permissioned actions**
+    pub fn execute_set_role_permission(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        action: impl Into<String>,
+        permission: LocalPermission,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.load_role(ctx.deps.storage, &role)?;
+        let action = action.into();
+        self.role_permissions.save(
+            ctx.deps.storage,
+            (action.as_str(), role.as_str()),
+            &permission,
+        )?;
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "set_role_permission"),
+            ("role", role.as_str()),
+            ("action", action.as_str()),
+            ("permission", permission.to_string().as_str()),
+        ]))
+    }
+
+    /// Execute handler for removing the permission of a role for an action
+    pub fn execute_remove_role_permission(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        action: impl Into<String>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.load_role(ctx.deps.storage, &role)?;
+        let action = action.into();
+        self.role_permissions
+            .remove(ctx.deps.storage, (action.as_str(), role.as_str()));
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "remove_role_permission"),
+            ("role", role.as_str()),
+            ("action", action.as_str()),
+        ]))
+    }
+
     /// Queries all permissions for a given actor
     pub fn query_permissions(
         &self,
@@ -453,6 +777,72 @@ impl<'a> ADOContract<'a> {
 
         Ok(actors)
     }
+
+    pub fn query_roles(
+        &self,
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<RoleInfo>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let roles = self
+            .roles
+            .range(
+                deps.storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|r| r.map(|(_, role_info)| role_info))
+            .collect::<StdResult<Vec<RoleInfo>>>()?;
+        Ok(roles)
+    }
+
+    /// Queries the locally managed members of a role
+    pub fn query_role_members(
+        &self,
+        deps: Deps,
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<String>, ContractError> {
+        self.load_role(deps.storage, &role)?;
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let members = self
+            .role_members
+            .prefix(&role)
+            .keys(
+                deps.storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<String>>>()?;
+        Ok(members)
+    }
+
+    /// Queries all role permissions for a given action
+    pub fn query_role_permissions(
+        &self,
+        deps: Deps,
+        action: String,
+    ) -> Result<Vec<RolePermissionInfo>, ContractError> {
+        let permissions = self
+            .role_permissions
+            .prefix(&action)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|r| {
+                r.map(|(role, permission)| RolePermissionInfo {
+                    permission,
+                    action: action.clone(),
+                    role,
+                })
+            })
+            .collect::<StdResult<Vec<RolePermissionInfo>>>()?;
+        Ok(permissions)
+    }
 }
 
 /// Checks if the provided context is authorised to perform the provided action.
@@ -460,6 +850,8 @@ impl<'a> ADOContract<'a> {
 /// Two scenarios exist:
 /// - The context does not contain any AMP context and the **sender** is the actor
 /// - The context contains AMP context and the **previous sender** or **origin** are considered the actor
+///
+/// Errors with `ContractError::Paused` if the action is currently paused.
 pub fn is_context_permissioned(
     deps: &mut DepsMut,
     info: &MessageInfo,
@@ -468,10 +860,11 @@ pub fn is_context_permissioned(
     action: impl Into<String>,
 ) -> Result<bool, ContractError> {
     let contract = ADOContract::default();
+    let action: String = action.into();
+    contract.ensure_not_paused(deps.storage, env, &action)?;
 
     match ctx {
         Some(amp_ctx) => {
-            let action: String = action.into();
             let is_origin_permissioned = contract.is_permissioned(
                 deps.branch(),
                 env.clone(),
@@ -500,6 +893,8 @@ pub fn is_context_permissioned(
 /// Two scenarios exist:
 /// - The context does not contain any AMP context and the **sender** is the actor
 /// - The context contains AMP context and the **previous sender** or **origin** are considered the actor
+///
+/// Errors with `ContractError::Paused` if the action is currently paused.
 pub fn is_context_permissioned_strict(
     mut deps: DepsMut,
     info: &MessageInfo,
@@ -508,10 +903,11 @@ pub fn is_context_permissioned_strict(
     action: impl Into<String>,
 ) -> Result<bool, ContractError> {
     let contract = ADOContract::default();
+    let action: String = action.into();
+    contract.ensure_not_paused(deps.storage, env, &action)?;
 
     match ctx {
         Some(amp_ctx) => {
-            let action: String = action.into();
             let is_origin_permissioned = contract.is_permissioned_strict(
                 deps.branch(),
                 env.clone(),
@@ -538,14 +934,17 @@ pub fn is_context_permissioned_strict(
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_env, mock_info},
-        Addr,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockStorage},
+        Addr, OwnedDeps, Querier,
     };
 
     use crate::{
         ado_base::AndromedaMsg,
         amp::messages::AMPPkt,
         common::{expiration::Expiry, MillisecondsExpiration},
+        testing::mock_querier::{
+            mock_dependencies_custom, MOCK_ADDRESS_LIST_CONTRACT, MOCK_ADDRESS_LIST_MEMBER,
+        },
     };
 
     use super::*;
@@ -1197,4 +1596,337 @@ mod tests {
         assert_eq!(actors.len(), 1);
         assert_eq!(actors[0], actor);
     }
+
+    fn execute_as(
+        deps: &mut OwnedDeps<MockStorage, MockApi, impl Querier>,
+        sender: &str,
+        msg: PermissioningMessage,
+    ) -> Result<Response, ContractError> {
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info(sender, &[]), mock_env());
+        ADOContract::default().execute(ctx, AndromedaMsg::Permissioning(msg))
+    }
+
+    #[test]
+    fn test_role_permissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let action = "action";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(action, deps.as_mut().storage)
+            .unwrap();
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::CreateRole {
+                role: "operators".to_string(),
+                address_list: None,
+            },
+        )
+        .unwrap();
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::GrantRole {
+                role: "operators".to_string(),
+                actors: vec![
+                    AndrAddr::from_string("actor_one"),
+                    AndrAddr::from_string("actor_two"),
+                ],
+            },
+        )
+        .unwrap();
+
+        // Members without a role permission are not authorised
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, "actor_one");
+        assert!(res.is_err());
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::SetRolePermission {
+                role: "operators".to_string(),
+                action: action.to_string(),
+                permission: LocalPermission::Whitelisted(None),
+            },
+        )
+        .unwrap();
+
+        for actor in ["actor_one", "actor_two"] {
+            let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
+            assert!(res.is_ok());
+            let res = contract.is_permissioned_strict(deps.as_mut(), env.clone(), action, actor);
+            assert!(res.is_ok());
+        }
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, "non_member");
+        assert!(res.is_err());
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::RevokeRole {
+                role: "operators".to_string(),
+                actors: vec![AndrAddr::from_string("actor_two")],
+            },
+        )
+        .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, "actor_two");
+        assert!(res.is_err());
+
+        // Direct permissions take precedence over roles
+        let permission = Permission::Local(LocalPermission::Blacklisted(None));
+        ADOContract::set_permission(deps.as_mut().storage, action, "actor_one", permission)
+            .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, "actor_one");
+        assert!(res.is_err());
+        ADOContract::remove_permission(deps.as_mut().storage, action, "actor_one").unwrap();
+
+        // A blacklisting role takes precedence over other roles
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::CreateRole {
+                role: "banned".to_string(),
+                address_list: None,
+            },
+        )
+        .unwrap();
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::SetRolePermission {
+                role: "banned".to_string(),
+                action: action.to_string(),
+                permission: LocalPermission::Blacklisted(None),
+            },
+        )
+        .unwrap();
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::GrantRole {
+                role: "banned".to_string(),
+                actors: vec![AndrAddr::from_string("actor_one")],
+            },
+        )
+        .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, "actor_one");
+        assert!(res.is_err());
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::RemoveRolePermission {
+                role: "banned".to_string(),
+                action: action.to_string(),
+            },
+        )
+        .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env, action, "actor_one");
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_role_permissions_limited() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let action = "action";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::CreateRole {
+                role: "minters".to_string(),
+                address_list: None,
+            },
+        )
+        .unwrap();
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::GrantRole {
+                role: "minters".to_string(),
+                actors: vec![
+                    AndrAddr::from_string("actor_one"),
+                    AndrAddr::from_string("actor_two"),
+                ],
+            },
+        )
+        .unwrap();
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::SetRolePermission {
+                role: "minters".to_string(),
+                action: action.to_string(),
+                permission: LocalPermission::limited(None, 1),
+            },
+        )
+        .unwrap();
+
+        // Uses are shared between all members of the role
+        let res = contract.is_permissioned_strict(deps.as_mut(), env.clone(), action, "actor_one");
+        assert!(res.is_ok());
+        let res = contract.is_permissioned_strict(deps.as_mut(), env, action, "actor_two");
+        assert!(res.is_err());
+
+        let permissions = contract
+            .query_role_permissions(deps.as_ref(), action.to_string())
+            .unwrap();
+        assert_eq!(
+            permissions,
+            vec![RolePermissionInfo {
+                permission: LocalPermission::limited(None, 0),
+                action: action.to_string(),
+                role: "minters".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_role_address_list() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let action = "action";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(action, deps.as_mut().storage)
+            .unwrap();
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::CreateRole {
+                role: "listed".to_string(),
+                address_list: Some(AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT)),
+            },
+        )
+        .unwrap();
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::SetRolePermission {
+                role: "listed".to_string(),
+                action: action.to_string(),
+                permission: LocalPermission::Whitelisted(None),
+            },
+        )
+        .unwrap();
+
+        let res =
+            contract.is_permissioned(deps.as_mut(), env.clone(), action, MOCK_ADDRESS_LIST_MEMBER);
+        assert!(res.is_ok());
+        let res = contract.is_permissioned(deps.as_mut(), env, action, "non_member");
+        assert!(res.is_err());
+
+        // Membership cannot be managed locally for delegated roles
+        let err = execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::GrantRole {
+                role: "listed".to_string(),
+                actors: vec![AndrAddr::from_string("non_member")],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RoleMembershipDelegated {
+                role: "listed".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_role_management() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let create_role = PermissioningMessage::CreateRole {
+            role: "operators".to_string(),
+            address_list: None,
+        };
+        let err = execute_as(&mut deps, "attacker", create_role.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        execute_as(&mut deps, "owner", create_role.clone()).unwrap();
+        let err = execute_as(&mut deps, "owner", create_role).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RoleAlreadyExists {
+                role: "operators".to_string()
+            }
+        );
+
+        let err = execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::GrantRole {
+                role: "unknown".to_string(),
+                actors: vec![AndrAddr::from_string("actor")],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RoleNotFound {
+                role: "unknown".to_string()
+            }
+        );
+
+        execute_as(
+            &mut deps,
+            "owner",
+            PermissioningMessage::GrantRole {
+                role: "operators".to_string(),
+                actors: vec![
+                    AndrAddr::from_string("actor_one"),
+                    AndrAddr::from_string("actor_two"),
+                ],
+            },
+        )
+        .unwrap();
+
+        let roles = contract.query_roles(deps.as_ref(), None, None).unwrap();
+        assert_eq!(
+            roles,
+            vec![RoleInfo {
+                role: "operators".to_string(),
+                address_list: None,
+            }]
+        );
+
+        let members = contract
+            .query_role_members(deps.as_ref(), "operators".to_string(), None, None)
+            .unwrap();
+        assert_eq!(members, vec!["actor_one", "actor_two"]);
+
+        let members = contract
+            .query_role_members(
+                deps.as_ref(),
+                "operators".to_string(),
+                Some("actor_one".to_string()),
+                None,
+            )
+            .unwrap();
+        assert_eq!(members, vec!["actor_two"]);
+    }
 }
@@ -9,7 +9,10 @@ use crate::{
         version::VersionResponse,
         AndromedaQuery,
     },
-    common::encode_binary,
+    common::{
+        encode_binary,
+        migration::{MigrationPlanResponse, MigrationRegistry},
+    },
     error::ContractError,
 };
 use cosmwasm_std::{from_json, to_json_binary, Binary, Deps, Env};
@@ -21,7 +24,7 @@ impl<'a> ADOContract<'a> {
     pub fn query(
         &self,
         deps: Deps,
-        _env: Env,
+        env: Env,
         msg: impl Serialize,
     ) -> Result<Binary, ContractError> {
         let msg = to_json_binary(&msg)?;
@@ -41,6 +44,12 @@ impl<'a> ADOContract<'a> {
                 }
                 AndromedaQuery::Version {} => encode_binary(&self.query_version(deps)?),
                 AndromedaQuery::ADOBaseVersion {} => encode_binary(&self.query_ado_base_version()?),
+                AndromedaQuery::MigrationPlan { .. } => Err(ContractError::NotImplemented {
+                    msg: Some(
+                        "Migration plans must be queried from the contract's migration registry"
+                            .to_string(),
+                    ),
+                }),
                 AndromedaQuery::OwnershipRequest {} => {
                     encode_binary(&self.ownership_request(deps.storage)?)
                 }
@@ -55,12 +64,32 @@ impl<'a> ADOContract<'a> {
                 AndromedaQuery::PermissionedActions {} => {
                     encode_binary(&self.query_permissioned_actions(deps)?)
                 }
+                AndromedaQuery::Roles { start_after, limit } => {
+                    encode_binary(&self.query_roles(deps, start_after, limit)?)
+                }
+                AndromedaQuery::RoleMembers {
+                    role,
+                    start_after,
+                    limit,
+                } => encode_binary(&self.query_role_members(deps, role, start_after, limit)?),
+                AndromedaQuery::RolePermissions { action } => {
+                    encode_binary(&self.query_role_permissions(deps, action)?)
+                }
+                AndromedaQuery::PauseStatus {} => {
+                    encode_binary(&self.query_pause_status(deps, env)?)
+                }
                 #[cfg(feature = "rates")]
                 AndromedaQuery::Rates { action } => encode_binary(&self.get_rates(deps, action)?),
 
                 #[cfg(feature = "rates")]
                 AndromedaQuery::AllRates {} => encode_binary(&self.get_all_rates(deps)?),
 
+                #[cfg(feature = "modules")]
+                AndromedaQuery::Module { id } => encode_binary(&self.query_module(deps, id)?),
+
+                #[cfg(feature = "modules")]
+                AndromedaQuery::ModuleIds {} => encode_binary(&self.query_module_ids(deps)?),
+
                 _ => Err(ContractError::UnsupportedOperation {}),
             },
             Err(_) => Err(ContractError::UnsupportedOperation {}),
@@ -120,4 +149,19 @@ impl<'a> ADOContract<'a> {
             version: ado_base_version.to_string(),
         })
     }
+
+    /// The steps of the registry that would run when migrating from `from_version`, or the stored version, to `to_version`
+    pub fn query_migration_plan(
+        &self,
+        deps: Deps,
+        registry: &MigrationRegistry,
+        from_version: Option<String>,
+        to_version: &str,
+    ) -> Result<MigrationPlanResponse, ContractError> {
+        let from_version = match from_version {
+            Some(from_version) => from_version,
+            None => get_contract_version(deps.storage)?.version,
+        };
+        registry.plan(&from_version, to_version)
+    }
 }
@@ -1,20 +1,65 @@
-use crate::ado_base::rates::{AllRatesResponse, Rate, RatesMessage, RatesResponse};
+use crate::ado_base::rates::{
+    AllRatesResponse, AssetRatesResponse, Rate, RatesMessage, RatesResponse,
+};
 use crate::amp::Recipient;
-use crate::common::{context::ExecuteContext, Funds};
+use crate::common::{context::ExecuteContext, denom::AssetAmount, Funds};
 use crate::error::ContractError;
 use crate::os::aos_querier::AOSQuerier;
-use cosmwasm_std::{coin as create_coin, ensure, Coin, Deps, Response, Storage};
-use cw20::Cw20Coin;
+use cosmwasm_std::{ensure, Deps, DepsMut, Env, Order, Response, Storage};
 use cw_storage_plus::Map;
+use serde::{Deserialize, Serialize};
 
 use super::ADOContract;
 
-pub fn rates<'a>() -> Map<'a, &'a str, Rate> {
+pub fn rates<'a>() -> Map<'a, &'a str, Vec<Rate>> {
     Map::new("rates")
 }
 
+/// The rates stored for an action, ADOs that predate multiple rates per action store a single `Rate`
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum StoredRates {
+    List(Vec<Rate>),
+    Single(Rate),
+}
+
+impl From<StoredRates> for Vec<Rate> {
+    fn from(stored: StoredRates) -> Self {
+        match stored {
+            StoredRates::List(rates) => rates,
+            StoredRates::Single(rate) => vec![rate],
+        }
+    }
+}
+
+/// Read only view of `rates` accepting both storage formats
+fn stored_rates<'a>() -> Map<'a, &'a str, StoredRates> {
+    Map::new("rates")
+}
+
+/// Loads the rates for the given action, reading a legacy single `Rate` as a list of one
+fn load_rates(storage: &dyn Storage, action: &str) -> Result<Option<Vec<Rate>>, ContractError> {
+    Ok(stored_rates().may_load(storage, action)?.map(Vec::from))
+}
+
+/// Migration step converting rates stored as a single `Rate` per action to a list of rates
+pub fn migrate_rates_to_lists(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let legacy = stored_rates()
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((action, StoredRates::Single(rate))) => Some(Ok((action, rate))),
+            Ok((_, StoredRates::List(_))) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<Result<Vec<(String, Rate)>, _>>()?;
+    for (action, rate) in legacy {
+        rates().save(deps.storage, &action, &vec![rate])?;
+    }
+    Ok(())
+}
+
 impl<'a> ADOContract<'a> {
-    /// Sets rates
+    /// Sets rates, replacing any rates already stored for the action
     pub fn set_rates(
         &self,
         store: &mut dyn Storage,
@@ -22,7 +67,20 @@ impl<'a> ADOContract<'a> {
         rate: Rate,
     ) -> Result<(), ContractError> {
         let action: String = action.into();
-        self.rates.save(store, &action, &rate)?;
+        self.rates.save(store, &action, &vec![rate])?;
+        Ok(())
+    }
+    /// Adds a rate to be applied after any rates already stored for the action
+    pub fn add_rate(
+        &self,
+        store: &mut dyn Storage,
+        action: impl Into<String>,
+        rate: Rate,
+    ) -> Result<(), ContractError> {
+        let action: String = action.into();
+        let mut rates = load_rates(store, &action)?.unwrap_or_default();
+        rates.push(rate);
+        self.rates.save(store, &action, &rates)?;
         Ok(())
     }
     pub fn execute_rates(
@@ -32,27 +90,26 @@ impl<'a> ADOContract<'a> {
     ) -> Result<Response, ContractError> {
         match rates_message {
             RatesMessage::SetRate { action, rate } => self.execute_set_rates(ctx, action, rate),
-            RatesMessage::RemoveRate { action } => self.execute_remove_rates(ctx, action),
+            RatesMessage::AddRate { action, rate } => self.execute_add_rate(ctx, action, rate),
+            RatesMessage::RemoveRate { action, index } => match index {
+                Some(index) => self.execute_remove_rate(ctx, action, index),
+                None => self.execute_remove_rates(ctx, action),
+            },
         }
     }
-    pub fn execute_set_rates(
-        &self,
-        ctx: ExecuteContext,
-        action: impl Into<String>,
-        mut rate: Rate,
-    ) -> Result<Response, ContractError> {
+    /// Validates the given rate, defaulting its recipients to the sender if none are provided
+    fn prepare_rate(&self, ctx: &ExecuteContext, mut rate: Rate) -> Result<Rate, ContractError> {
         ensure!(
             Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
             ContractError::Unauthorized {}
         );
-        let action: String = action.into();
         // Validate rates
         rate.validate_rate(ctx.deps.as_ref())?;
 
         let rate = match rate {
             Rate::Local(ref mut local_rate) => {
                 if local_rate.recipients.is_empty() {
-                    local_rate.recipients = vec![Recipient::new(ctx.info.sender, None)];
+                    local_rate.recipients = vec![Recipient::new(ctx.info.sender.clone(), None)];
                     Rate::Local(local_rate.clone())
                 } else {
                     rate
@@ -60,10 +117,33 @@ impl<'a> ADOContract<'a> {
             }
             Rate::Contract(_) => rate,
         };
+        Ok(rate)
+    }
+    pub fn execute_set_rates(
+        &self,
+        ctx: ExecuteContext,
+        action: impl Into<String>,
+        rate: Rate,
+    ) -> Result<Response, ContractError> {
+        let action: String = action.into();
+        let rate = self.prepare_rate(&ctx, rate)?;
         self.set_rates(ctx.deps.storage, action, rate)?;
 
         Ok(Response::default().add_attributes(vec![("action", "set_rates")]))
     }
+    pub fn execute_add_rate(
+        &self,
+        ctx: ExecuteContext,
+        action: impl Into<String>,
+        rate: Rate,
+    ) -> Result<Response, ContractError> {
+        let action: String = action.into();
+        let rate = self.prepare_rate(&ctx, rate)?;
+        self.add_rate(ctx.deps.storage, action.clone(), rate)?;
+
+        Ok(Response::default()
+            .add_attributes(vec![("action", "add_rate"), ("rate_action", &action)]))
+    }
     pub fn remove_rates(
         &self,
         store: &mut dyn Storage,
@@ -73,6 +153,46 @@ impl<'a> ADOContract<'a> {
         self.rates.remove(store, &action);
         Ok(())
     }
+    /// Removes the rate at the given index, removing the action entirely if no rates remain
+    pub fn remove_rate(
+        &self,
+        store: &mut dyn Storage,
+        action: impl Into<String>,
+        index: u32,
+    ) -> Result<(), ContractError> {
+        let action: String = action.into();
+        let mut rates = load_rates(store, &action)?.unwrap_or_default();
+        ensure!(
+            (index as usize) < rates.len(),
+            ContractError::InvalidRate {}
+        );
+        rates.remove(index as usize);
+        if rates.is_empty() {
+            self.rates.remove(store, &action);
+        } else {
+            self.rates.save(store, &action, &rates)?;
+        }
+        Ok(())
+    }
+    pub fn execute_remove_rate(
+        &self,
+        ctx: ExecuteContext,
+        action: impl Into<String>,
+        index: u32,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let action: String = action.into();
+        self.remove_rate(ctx.deps.storage, action.clone(), index)?;
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "remove_rate".to_string()),
+            ("removed_action", action),
+            ("index", index.to_string()),
+        ]))
+    }
     pub fn execute_remove_rates(
         &self,
         ctx: ExecuteContext,
@@ -95,21 +215,21 @@ impl<'a> ADOContract<'a> {
         &self,
         deps: Deps,
         action: impl Into<String>,
-    ) -> Result<Option<Rate>, ContractError> {
+    ) -> Result<Option<Vec<Rate>>, ContractError> {
         let action: String = action.into();
-        Ok(rates().may_load(deps.storage, &action)?)
+        load_rates(deps.storage, &action)
     }
 
     pub fn get_all_rates(&self, deps: Deps) -> Result<AllRatesResponse, ContractError> {
         // Initialize a vector to hold all rates
-        let mut all_rates: Vec<(String, Rate)> = Vec::new();
+        let mut all_rates: Vec<(String, Vec<Rate>)> = Vec::new();
 
         // Iterate over all keys and load the corresponding rate
-        rates()
+        stored_rates()
             .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
             .for_each(|item| {
-                if let Ok((action, rate)) = item {
-                    all_rates.push((action, rate));
+                if let Ok((action, rates)) = item {
+                    all_rates.push((action, rates.into()));
                 }
             });
 
@@ -117,61 +237,73 @@ impl<'a> ADOContract<'a> {
     }
 
     pub fn query_deducted_funds(
-        self,
+        &self,
         deps: Deps,
         action: impl Into<String>,
         funds: Funds,
     ) -> Result<Option<RatesResponse>, ContractError> {
+        let res = self.query_deducted_assets(deps, action, vec![AssetAmount::from(funds)])?;
+        Ok(res.map(|res| RatesResponse {
+            msgs: res.msgs,
+            events: res.events,
+            leftover_funds: res.leftover_funds[0].clone().into(),
+        }))
+    }
+
+    /// Applies the rates for the given action to payments made in one or more assets.
+    ///
+    /// Each rate is calculated on the original payments and applied in order.
+    pub fn query_deducted_assets(
+        &self,
+        deps: Deps,
+        action: impl Into<String>,
+        payments: Vec<AssetAmount>,
+    ) -> Result<Option<AssetRatesResponse>, ContractError> {
         let action: String = action.into();
-        let rate = self.rates.may_load(deps.storage, &action)?;
-        match rate {
-            Some(rate) => {
-                let (coin, is_native): (Coin, bool) = match funds {
-                    Funds::Native(coin) => {
-                        ensure!(
-                            !coin.amount.is_zero(),
-                            ContractError::InvalidFunds {
-                                msg: "Zero amounts are prohibited".to_string()
-                            }
-                        );
-                        (coin, true)
-                    }
-                    Funds::Cw20(cw20_coin) => {
-                        ensure!(
-                            !cw20_coin.amount.is_zero(),
-                            ContractError::InvalidFunds {
-                                msg: "Zero amounts are prohibited".to_string()
-                            }
-                        );
-                        (
-                            create_coin(cw20_coin.amount.u128(), cw20_coin.address),
-                            false,
-                        )
+        let rates = load_rates(deps.storage, &action)?;
+        match rates {
+            Some(rates) => {
+                ensure!(
+                    !payments.is_empty()
+                        && payments.iter().all(|payment| !payment.amount.is_zero()),
+                    ContractError::InvalidFunds {
+                        msg: "Zero amounts are prohibited".to_string()
                     }
-                };
-                let (msgs, events, leftover_funds) = match rate {
-                    Rate::Local(local_rate) => {
-                        local_rate.generate_response(deps, coin.clone(), is_native)?
+                );
+                let mut msgs = vec![];
+                let mut events = vec![];
+                let mut leftover_funds = payments.clone();
+                for rate in rates {
+                    let (rate_msgs, rate_events, rate_leftover_funds) = match rate {
+                        Rate::Local(local_rate) => {
+                            local_rate.generate_asset_response(deps, payments.clone())?
+                        }
+                        Rate::Contract(rates_address) => {
+                            // Query rates contract
+                            let addr = rates_address.get_raw_address(&deps)?;
+                            let rate = AOSQuerier::get_rate(&deps.querier, &addr, &action)?;
+                            rate.generate_asset_response(deps, payments.clone())?
+                        }
+                    };
+                    for ((leftover, payment), rate_leftover) in leftover_funds
+                        .iter_mut()
+                        .zip(payments.iter())
+                        .zip(rate_leftover_funds)
+                    {
+                        let deducted = payment.amount.checked_sub(rate_leftover.amount)?;
+                        leftover.amount = leftover
+                            .amount
+                            .checked_sub(deducted)
+                            .map_err(|_| ContractError::InsufficientFunds {})?;
                     }
-                    Rate::Contract(rates_address) => {
-                        // Query rates contract
-                        let addr = rates_address.get_raw_address(&deps)?;
-                        let rate = AOSQuerier::get_rate(&deps.querier, &addr, &action)?;
-                        rate.generate_response(deps, coin.clone(), is_native)?
-                    }
-                };
+                    msgs.extend(rate_msgs);
+                    events.extend(rate_events);
+                }
 
-                Ok(Some(RatesResponse {
+                Ok(Some(AssetRatesResponse {
                     msgs,
-                    leftover_funds: if is_native {
-                        Funds::Native(leftover_funds[0].clone())
-                    } else {
-                        Funds::Cw20(Cw20Coin {
-                            amount: leftover_funds[0].amount,
-                            address: coin.denom,
-                        })
-                    },
                     events,
+                    leftover_funds,
                 }))
             }
             None => Ok(None),
@@ -186,12 +318,14 @@ mod tests {
     use cosmwasm_std::{
         coin,
         testing::{mock_dependencies, mock_env},
-        Addr,
+        wasm_execute, Addr, BankMsg, CosmosMsg, Decimal, SubMsg, Uint128,
     };
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
 
     use crate::{
-        ado_base::rates::{LocalRate, LocalRateType, LocalRateValue},
+        ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, RateTier},
         amp::{AndrAddr, Recipient},
+        common::denom::Asset,
     };
 
     use super::*;
@@ -214,6 +348,7 @@ mod tests {
             }],
             value: LocalRateValue::Flat(coin(100_u128, "uandr")),
             description: None,
+            weights: None,
         });
 
         let action = "deposit";
@@ -226,13 +361,13 @@ mod tests {
             .load(&deps.storage, action)
             .unwrap();
 
-        assert_eq!(rate, expected_rate);
+        assert_eq!(rate, vec![expected_rate.clone()]);
 
         // get rates
         let rate = ADOContract::default()
             .get_rates(deps.as_ref(), action)
             .unwrap();
-        assert_eq!(expected_rate, rate.unwrap());
+        assert_eq!(vec![expected_rate], rate.unwrap());
 
         // remove rates
         ADOContract::remove_rates(&contract, &mut deps.storage, action).unwrap();
@@ -241,4 +376,361 @@ mod tests {
             .unwrap();
         assert!(rate.is_none());
     }
+
+    #[test]
+    fn test_migrate_rates_to_lists() {
+        let mut deps = mock_dependencies();
+        let rate = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("recipient")],
+            value: LocalRateValue::Flat(coin(100_u128, "uandr")),
+            description: None,
+            weights: None,
+        });
+        let legacy_rates: Map<&str, Rate> = Map::new("rates");
+        legacy_rates
+            .save(deps.as_mut().storage, "deposit", &rate)
+            .unwrap();
+        rates()
+            .save(deps.as_mut().storage, "withdraw", &vec![rate.clone()])
+            .unwrap();
+
+        migrate_rates_to_lists(deps.as_mut(), &mock_env()).unwrap();
+        for action in ["deposit", "withdraw"] {
+            assert_eq!(
+                rates().load(deps.as_ref().storage, action).unwrap(),
+                vec![rate.clone()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_legacy_single_rate() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let rate = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("recipient")],
+            value: LocalRateValue::Flat(coin(10_u128, "uandr")),
+            description: None,
+            weights: None,
+        });
+        let legacy_rates: Map<&str, Rate> = Map::new("rates");
+        legacy_rates
+            .save(deps.as_mut().storage, "buy", &rate)
+            .unwrap();
+
+        // Rates stored by ADOs that have not been migrated are read as a list of one
+        assert_eq!(
+            contract.get_rates(deps.as_ref(), "buy").unwrap(),
+            Some(vec![rate.clone()])
+        );
+        assert_eq!(
+            contract.get_all_rates(deps.as_ref()).unwrap().all_rates,
+            vec![("buy".to_string(), vec![rate.clone()])]
+        );
+        let res = contract
+            .query_deducted_funds(deps.as_ref(), "buy", Funds::Native(coin(100, "uandr")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.leftover_funds, Funds::Native(coin(90, "uandr")));
+
+        // Adding a rate converts the stored rate to a list
+        contract
+            .add_rate(deps.as_mut().storage, "buy", rate.clone())
+            .unwrap();
+        assert_eq!(
+            rates().load(deps.as_ref().storage, "buy").unwrap(),
+            vec![rate.clone(), rate]
+        );
+    }
+
+    fn bank_msg(to_address: &str, amount: u128) -> SubMsg {
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: to_address.to_string(),
+            amount: vec![coin(amount, "uandr")],
+        }))
+    }
+
+    #[test]
+    fn test_query_deducted_funds_weighted_recipients() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let rate = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![
+                Recipient::from_string("recipient_one"),
+                Recipient::from_string("recipient_two"),
+                Recipient::from_string("recipient_three"),
+            ],
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(5),
+            }),
+            description: None,
+            weights: Some(vec![Uint128::new(50), Uint128::new(25), Uint128::new(25)]),
+        });
+        rate.validate_rate(deps.as_ref()).unwrap();
+        contract.set_rates(&mut deps.storage, "buy", rate).unwrap();
+
+        // A single 5% fee is split 50/25/25, the remainder going to the first recipient
+        let res = ADOContract::default()
+            .query_deducted_funds(deps.as_ref(), "buy", Funds::Native(coin(1010, "uandr")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            res.msgs,
+            vec![
+                bank_msg("recipient_one", 27),
+                bank_msg("recipient_two", 12),
+                bank_msg("recipient_three", 12),
+            ]
+        );
+        assert_eq!(res.leftover_funds, Funds::Native(coin(959, "uandr")));
+    }
+
+    #[test]
+    fn test_validate_weighted_recipients() {
+        let deps = mock_dependencies();
+        let rate = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("recipient_one")],
+            value: LocalRateValue::Flat(coin(10, "uandr")),
+            description: None,
+            weights: Some(vec![Uint128::new(1), Uint128::new(1)]),
+        });
+        assert_eq!(
+            rate.validate_rate(deps.as_ref()).unwrap_err(),
+            ContractError::InvalidRate {}
+        );
+    }
+
+    #[test]
+    fn test_query_deducted_funds_tiered() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let tiers = vec![
+            RateTier {
+                threshold: Uint128::zero(),
+                percent: Decimal::percent(10),
+            },
+            RateTier {
+                threshold: Uint128::new(1000),
+                percent: Decimal::percent(5),
+            },
+        ];
+        let invalid_rate = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Additive,
+            recipients: vec![Recipient::from_string("recipient")],
+            value: LocalRateValue::Tiered(tiers.iter().rev().cloned().collect()),
+            description: None,
+            weights: None,
+        });
+        assert_eq!(
+            invalid_rate.validate_rate(deps.as_ref()).unwrap_err(),
+            ContractError::InvalidRate {}
+        );
+
+        let rate = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Additive,
+            recipients: vec![Recipient::from_string("recipient")],
+            value: LocalRateValue::Tiered(tiers),
+            description: None,
+            weights: None,
+        });
+        rate.validate_rate(deps.as_ref()).unwrap();
+        contract.set_rates(&mut deps.storage, "buy", rate).unwrap();
+
+        let res = ADOContract::default()
+            .query_deducted_funds(deps.as_ref(), "buy", Funds::Native(coin(500, "uandr")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.msgs, vec![bank_msg("recipient", 50)]);
+        assert_eq!(res.leftover_funds, Funds::Native(coin(500, "uandr")));
+
+        let res = ADOContract::default()
+            .query_deducted_funds(deps.as_ref(), "buy", Funds::Native(coin(2000, "uandr")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.msgs, vec![bank_msg("recipient", 100)]);
+    }
+
+    #[test]
+    fn test_query_deducted_funds_stacked() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let royalty = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("royalty_recipient")],
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(10),
+            }),
+            description: None,
+            weights: None,
+        });
+        let tax = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Additive,
+            recipients: vec![Recipient::from_string("tax_recipient")],
+            value: LocalRateValue::Flat(coin(20, "uandr")),
+            description: None,
+            weights: None,
+        });
+        let fee = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("fee_recipient")],
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(5),
+            }),
+            description: None,
+            weights: None,
+        });
+        contract
+            .set_rates(&mut deps.storage, "buy", royalty.clone())
+            .unwrap();
+        contract
+            .add_rate(&mut deps.storage, "buy", tax.clone())
+            .unwrap();
+        contract
+            .add_rate(&mut deps.storage, "buy", fee.clone())
+            .unwrap();
+
+        let all_rates = contract.get_all_rates(deps.as_ref()).unwrap();
+        assert_eq!(
+            all_rates.all_rates,
+            vec![("buy".to_string(), vec![royalty.clone(), tax.clone(), fee])]
+        );
+
+        // Each rate is applied to the original payment, in order
+        let res = ADOContract::default()
+            .query_deducted_funds(deps.as_ref(), "buy", Funds::Native(coin(1000, "uandr")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            res.msgs,
+            vec![
+                bank_msg("royalty_recipient", 100),
+                bank_msg("tax_recipient", 20),
+                bank_msg("fee_recipient", 50),
+            ]
+        );
+        // Each rate emits its legacy event followed by a standard event per payment
+        assert_eq!(res.events.len(), 6);
+        assert_eq!(res.leftover_funds, Funds::Native(coin(850, "uandr")));
+
+        // Removing by index keeps the remaining rates in order
+        contract.remove_rate(&mut deps.storage, "buy", 2).unwrap();
+        assert_eq!(
+            contract.get_rates(deps.as_ref(), "buy").unwrap(),
+            Some(vec![royalty, tax])
+        );
+        assert_eq!(
+            contract
+                .remove_rate(&mut deps.storage, "buy", 2)
+                .unwrap_err(),
+            ContractError::InvalidRate {}
+        );
+    }
+
+    #[test]
+    fn test_query_deducted_assets() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        let cw20_asset = Asset::Cw20Token(AndrAddr::from_string("cw20_contract"));
+        let cw20_fee = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("recipient")],
+            value: LocalRateValue::FlatAsset(AssetAmount::new(cw20_asset.clone(), 10u128)),
+            description: None,
+            weights: None,
+        });
+        cw20_fee.validate_rate(deps.as_ref()).unwrap();
+        contract
+            .set_rates(&mut deps.storage, "buy", cw20_fee)
+            .unwrap();
+
+        let res = ADOContract::default()
+            .query_deducted_funds(
+                deps.as_ref(),
+                "buy",
+                Funds::Cw20(Cw20Coin {
+                    address: "cw20_contract".to_string(),
+                    amount: Uint128::new(100),
+                }),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            res.msgs,
+            vec![SubMsg::new(
+                wasm_execute(
+                    "cw20_contract",
+                    &Cw20ExecuteMsg::Transfer {
+                        recipient: "recipient".to_string(),
+                        amount: Uint128::new(10),
+                    },
+                    vec![],
+                )
+                .unwrap()
+            )]
+        );
+        assert_eq!(
+            res.leftover_funds,
+            Funds::Cw20(Cw20Coin {
+                address: "cw20_contract".to_string(),
+                amount: Uint128::new(90),
+            })
+        );
+
+        // A flat rate in a different asset than the payment is rejected
+        let err = ADOContract::default()
+            .query_deducted_funds(deps.as_ref(), "buy", Funds::Native(coin(100, "uandr")))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RateAssetMismatch {
+                rate_asset: cw20_asset.to_string()
+            }
+        );
+
+        // Percentage rates apply to each asset of a multi-coin payment
+        let percent_fee = Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipients: vec![Recipient::from_string("recipient")],
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(10),
+            }),
+            description: None,
+            weights: None,
+        });
+        contract
+            .set_rates(&mut deps.storage, "buy", percent_fee)
+            .unwrap();
+        let res = ADOContract::default()
+            .query_deducted_assets(
+                deps.as_ref(),
+                "buy",
+                vec![
+                    AssetAmount::new(Asset::NativeToken("uandr".to_string()), 100u128),
+                    AssetAmount::new(Asset::NativeToken("uusd".to_string()), 50u128),
+                ],
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            res.msgs,
+            vec![
+                bank_msg("recipient", 10),
+                SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "recipient".to_string(),
+                    amount: vec![coin(5, "uusd")],
+                })),
+            ]
+        );
+        assert_eq!(
+            res.leftover_funds,
+            vec![
+                AssetAmount::new(Asset::NativeToken("uandr".to_string()), 90u128),
+                AssetAmount::new(Asset::NativeToken("uusd".to_string()), 45u128),
+            ]
+        );
+    }
 }
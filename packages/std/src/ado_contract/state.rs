@@ -1,3 +1,6 @@
+#[cfg(feature = "modules")]
+use crate::ado_base::modules::Module;
+use crate::ado_base::permissioning::{LocalPermission, RoleInfo};
 #[cfg(feature = "rates")]
 use crate::ado_base::rates::Rate;
 use cosmwasm_std::Addr;
@@ -11,9 +14,18 @@ pub struct ADOContract<'a> {
     pub(crate) app_contract: Item<'a, Addr>,
     pub(crate) kernel_address: Item<'a, Addr>,
     pub(crate) permissioned_actions: Map<'a, String, bool>,
+    pub(crate) roles: Map<'a, &'a str, RoleInfo>,
+    /// Mapping of (role, actor) for locally managed role members
+    pub(crate) role_members: Map<'a, (&'a str, &'a str), bool>,
+    /// Mapping of (action, role) to the role's permission for the action
+    pub(crate) role_permissions: Map<'a, (&'a str, &'a str), LocalPermission>,
+    #[cfg(feature = "modules")]
+    pub(crate) module_info: Map<'a, &'a str, Module>,
+    #[cfg(feature = "modules")]
+    pub(crate) module_idx: Item<'a, u64>,
     #[cfg(feature = "rates")]
-    /// Mapping of action to rate
-    pub rates: Map<'a, &'a str, Rate>,
+    /// Mapping of action to its rates, applied in order
+    pub rates: Map<'a, &'a str, Vec<Rate>>,
 }
 
 impl<'a> Default for ADOContract<'a> {
@@ -26,6 +38,13 @@ impl<'a> Default for ADOContract<'a> {
             app_contract: Item::new("app_contract"),
             kernel_address: Item::new("kernel_address"),
             permissioned_actions: Map::new("andr_permissioned_actions"),
+            roles: Map::new("andr_roles"),
+            role_members: Map::new("andr_role_members"),
+            role_permissions: Map::new("andr_role_permissions"),
+            #[cfg(feature = "modules")]
+            module_info: Map::new("andr_modules"),
+            #[cfg(feature = "modules")]
+            module_idx: Item::new("andr_module_idx"),
             #[cfg(feature = "rates")]
             rates: Map::new("rates"),
         }
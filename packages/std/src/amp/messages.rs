@@ -2,11 +2,11 @@ use crate::ado_contract::ADOContract;
 use crate::common::encode_binary;
 use crate::error::ContractError;
 use crate::os::aos_querier::AOSQuerier;
-use crate::os::kernel::ExecuteMsg as KernelExecuteMsg;
+use crate::os::kernel::{ExecuteMsg as KernelExecuteMsg, IbcHooksTransferLeg};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, wasm_execute, Addr, Binary, Coin, ContractInfoResponse, CosmosMsg, Deps, Empty,
-    MessageInfo, QueryRequest, ReplyOn, SubMsg, WasmMsg, WasmQuery,
+    to_json_binary, Addr, Binary, Coin, ContractInfoResponse, CosmosMsg, Deps, Empty, MessageInfo,
+    QueryRequest, ReplyOn, SubMsg, WasmMsg, WasmQuery,
 };
 
 use super::addresses::AndrAddr;
@@ -41,6 +41,8 @@ pub struct AMPMsgConfig {
     /// When the message should reply, defaults to Always
     pub reply_on: ReplyOn,
     /// Determines whether the operation should terminate or proceed upon a failed message
+    ///
+    /// Messages that proceed upon failure always reply, regardless of `reply_on`, so the kernel can record the failure
     pub exit_at_error: bool,
     /// An optional imposed gas limit for the message
     pub gas_limit: Option<u64>,
@@ -155,7 +157,11 @@ impl AMPMsg {
             id,
             reply_on: self.config.reply_on.clone(),
             gas_limit: self.config.gas_limit,
-            msg: CosmosMsg::Wasm(wasm_execute(addr, &self.message, self.funds.to_vec()).unwrap()),
+            msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: addr.into(),
+                msg: self.message.clone(),
+                funds: self.funds.to_vec(),
+            }),
         }
     }
 
@@ -184,6 +190,38 @@ impl AMPMsg {
         serde_json_wasm::to_string(&msg).unwrap()
     }
 
+    /// Generates an IBC Hooks memo delivering one leg of a multi-coin transfer to the kernel on the receiving chain
+    pub fn to_ibc_hooks_leg_memo(
+        &self,
+        contract_addr: String,
+        callback_addr: String,
+        leg: IbcHooksTransferLeg,
+    ) -> String {
+        #[derive(::serde::Serialize)]
+        struct IbcHooksWasmMsg<T: ::serde::Serialize> {
+            contract: String,
+            msg: T,
+        }
+        #[derive(::serde::Serialize)]
+        struct IbcHooksMsg<T: ::serde::Serialize> {
+            wasm: IbcHooksWasmMsg<T>,
+            ibc_callback: String,
+        }
+        let wasm_msg = IbcHooksWasmMsg {
+            contract: contract_addr,
+            msg: KernelExecuteMsg::ReceiveTransferLeg {
+                message: self.clone(),
+                leg,
+            },
+        };
+        let msg = IbcHooksMsg {
+            wasm: wasm_msg,
+            ibc_callback: callback_addr,
+        };
+
+        serde_json_wasm::to_string(&msg).unwrap()
+    }
+
     /// Adds an IBC recovery address to the message
     pub fn with_ibc_recovery(&self, recovery_addr: Option<AndrAddr>) -> AMPMsg {
         if let Some(ibc_config) = self.config.ibc_config.clone() {
@@ -412,7 +450,10 @@ impl AMPPkt {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_info},
+        Timestamp,
+    };
 
     use crate::testing::mock_querier::{mock_dependencies_custom, INVALID_CONTRACT};
 
@@ -549,4 +590,22 @@ mod tests {
         let memo = msg.to_ibc_hooks_memo(contract_addr.to_string(), "callback".to_string());
         assert_eq!(memo, "{\"wasm\":{\"contract\":\"contractaddr\",\"msg\":{\"amp_receive\":{\"messages\":[],\"ctx\":{\"origin\":\"origin\",\"origin_username\":null,\"previous_sender\":\"previoussender\",\"id\":0}}}},\"ibc_callback\":\"callback\"}".to_string());
     }
+
+    #[test]
+    fn test_to_ibc_hooks_leg_memo() {
+        let msg = AMPMsg::new("receiver", Binary::default(), None);
+        let leg = IbcHooksTransferLeg {
+            transfer_id: 1,
+            legs: 2,
+            source_chain: "andromeda".to_string(),
+            recovery_addr: "recovery".to_string(),
+            expiry: Timestamp::from_seconds(1),
+        };
+        let memo =
+            msg.to_ibc_hooks_leg_memo("contractaddr".to_string(), "callback".to_string(), leg);
+        assert!(memo.starts_with(
+            "{\"wasm\":{\"contract\":\"contractaddr\",\"msg\":{\"receive_transfer_leg\":{\"message\":{\"recipient\":\"receiver\""
+        ));
+        assert!(memo.ends_with("\"leg\":{\"transfer_id\":1,\"legs\":2,\"source_chain\":\"andromeda\",\"recovery_addr\":\"recovery\",\"expiry\":\"1000000000\"}}}},\"ibc_callback\":\"callback\"}"));
+    }
 }
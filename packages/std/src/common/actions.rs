@@ -1,9 +1,49 @@
+#[cfg(feature = "modules")]
+use crate::ado_base::hooks::AndromedaHook;
 use crate::{
     ado_contract::{permissioning::is_context_permissioned, ADOContract},
     amp::messages::AMPPkt,
+    common::{context::ExecuteContext, Funds},
     error::ContractError,
 };
-use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, from_json, to_json_binary, DepsMut, Env, MessageInfo, Response};
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use serde::Serialize;
+
+/// The CW20 `Receive` variant shared by the execute messages of ADOs accepting CW20 tokens
+#[cw_serde]
+enum Cw20ReceiveExecuteMsg {
+    Receive(Cw20ReceiveMsg),
+}
+
+/// Calls the action of the given execute message.
+///
+/// The tokens received through a CW20 `Receive` message are used to calculate percentage based fees, otherwise the attached native funds are.
+pub fn call_msg_action<M: AsRef<str> + Serialize>(
+    ctx: &mut ExecuteContext,
+    msg: &M,
+) -> Result<Response, ContractError> {
+    if msg.as_ref() == "Receive" {
+        if let Ok(Cw20ReceiveExecuteMsg::Receive(receive_msg)) = from_json(to_json_binary(msg)?) {
+            return call_cw20_action(
+                &mut ctx.deps,
+                &ctx.info,
+                &ctx.env,
+                &ctx.amp_ctx,
+                msg.as_ref(),
+                &receive_msg,
+            );
+        }
+    }
+    call_action(
+        &mut ctx.deps,
+        &ctx.info,
+        &ctx.env,
+        &ctx.amp_ctx,
+        msg.as_ref(),
+    )
+}
 
 pub fn call_action(
     deps: &mut DepsMut,
@@ -11,6 +51,34 @@ pub fn call_action(
     env: &Env,
     amp_ctx: &Option<AMPPkt>,
     action: &str,
+) -> Result<Response, ContractError> {
+    let funds: Vec<Funds> = info.funds.iter().cloned().map(Funds::Native).collect();
+    call_action_with_funds(deps, info, env, amp_ctx, action, &funds)
+}
+
+/// Calls an action received through a CW20 `Receive` message, the received tokens are used to calculate percentage based fees
+pub fn call_cw20_action(
+    deps: &mut DepsMut,
+    info: &MessageInfo,
+    env: &Env,
+    amp_ctx: &Option<AMPPkt>,
+    action: &str,
+    receive_msg: &Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let funds = vec![Funds::Cw20(Cw20Coin {
+        address: info.sender.to_string(),
+        amount: receive_msg.amount,
+    })];
+    call_action_with_funds(deps, info, env, amp_ctx, action, &funds)
+}
+
+fn call_action_with_funds(
+    deps: &mut DepsMut,
+    info: &MessageInfo,
+    env: &Env,
+    amp_ctx: &Option<AMPPkt>,
+    action: &str,
+    funds: &[Funds],
 ) -> Result<Response, ContractError> {
     ensure!(
         is_context_permissioned(deps, info, env, amp_ctx, action)?,
@@ -23,8 +91,23 @@ pub fn call_action(
         info.sender.clone()
     };
 
-    let fee_msg =
-        ADOContract::default().pay_fee(deps.storage, &deps.querier, action.to_owned(), payee)?;
+    // Registered modules may reject the action by erroring
+    #[cfg(feature = "modules")]
+    ADOContract::default().module_hook::<Response>(
+        &deps.as_ref(),
+        AndromedaHook::OnExecute {
+            sender: payee.to_string(),
+            payload: to_json_binary(action)?,
+        },
+    )?;
+
+    let fee_msg = ADOContract::default().pay_fee(
+        deps.storage,
+        &deps.querier,
+        action.to_owned(),
+        payee,
+        funds,
+    )?;
 
     Ok(Response::default().add_submessage(fee_msg))
 }
@@ -1,12 +1,12 @@
 use std::fmt::{Display, Formatter, Result as StdResult};
 
-use crate::{ado_contract::ADOContract, amp::AndrAddr, error::ContractError};
+use crate::{ado_contract::ADOContract, amp::AndrAddr, common::Funds, error::ContractError};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
     coin, ensure, to_json_binary, wasm_execute, BankMsg, Deps, DepsMut, Env, QueryRequest, SubMsg,
     Uint128, WasmQuery,
 };
-use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
 pub const SEND_CW20_ACTION: &str = "SEND_CW20";
 pub const SEND_NFT_ACTION: &str = "SEND_NFT";
 
@@ -60,6 +60,18 @@ impl Asset {
             }
         }
     }
+    pub fn is_native(&self) -> bool {
+        matches!(self, Asset::NativeToken(_))
+    }
+
+    /// Gets the denom of a native asset or the raw address of a CW20 asset
+    pub fn get_denom(&self, deps: &Deps) -> Result<String, ContractError> {
+        match self {
+            Asset::NativeToken(denom) => Ok(denom.clone()),
+            Asset::Cw20Token(addr) => Ok(addr.get_raw_address(deps)?.to_string()),
+        }
+    }
+
     pub fn transfer(
         &self,
         deps: &Deps,
@@ -100,6 +112,54 @@ impl Asset {
     }
 }
 
+/// An amount of a native or CW20 asset
+#[cw_serde]
+pub struct AssetAmount {
+    pub asset: Asset,
+    pub amount: Uint128,
+}
+
+impl AssetAmount {
+    pub fn new(asset: Asset, amount: impl Into<Uint128>) -> Self {
+        Self {
+            asset,
+            amount: amount.into(),
+        }
+    }
+
+    /// Gets the native denom or CW20 address of the asset without resolving it
+    pub fn denom(&self) -> String {
+        match &self.asset {
+            Asset::NativeToken(denom) => denom.clone(),
+            Asset::Cw20Token(addr) => addr.to_string(),
+        }
+    }
+}
+
+impl From<Funds> for AssetAmount {
+    fn from(funds: Funds) -> Self {
+        match funds {
+            Funds::Native(coin) => AssetAmount::new(Asset::NativeToken(coin.denom), coin.amount),
+            Funds::Cw20(cw20_coin) => AssetAmount::new(
+                Asset::Cw20Token(AndrAddr::from_string(cw20_coin.address)),
+                cw20_coin.amount,
+            ),
+        }
+    }
+}
+
+impl From<AssetAmount> for Funds {
+    fn from(asset_amount: AssetAmount) -> Self {
+        match asset_amount.asset {
+            Asset::NativeToken(denom) => Funds::Native(coin(asset_amount.amount.u128(), denom)),
+            Asset::Cw20Token(addr) => Funds::Cw20(Cw20Coin {
+                address: addr.to_string(),
+                amount: asset_amount.amount,
+            }),
+        }
+    }
+}
+
 pub fn validate_denom(deps: Deps, denom: String) -> Result<(), ContractError> {
     let potential_supply = deps.querier.query_supply(denom.clone())?;
     let non_empty_denom = !denom.is_empty();
@@ -1,8 +1,153 @@
-use cosmwasm_std::{ensure, Deps};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Deps, DepsMut, Env};
 use cw2::get_contract_version;
 use semver::Version;
 
 use crate::error::{from_semver, ContractError};
+
+/// A state migration run when upgrading a contract
+pub type MigrationFn = fn(DepsMut, &Env) -> Result<(), ContractError>;
+
+/// A state migration between two contract versions
+#[derive(Clone)]
+pub struct MigrationStep {
+    pub from_version: &'static str,
+    pub to_version: &'static str,
+    pub migrate: MigrationFn,
+}
+
+impl MigrationStep {
+    /// Whether the step has to run when migrating from `from` to `to`.
+    ///
+    /// A step runs when the stored state predates its target version, so a contract stored at a patch
+    /// release after the step's `from_version` still runs it.
+    fn is_between(&self, from: &Version, to: &Version) -> Result<bool, ContractError> {
+        let step_to: Version = self.to_version.parse().map_err(from_semver)?;
+        Ok(from < &step_to && &step_to <= to)
+    }
+
+    fn info(&self) -> MigrationStepInfo {
+        MigrationStepInfo {
+            from_version: self.from_version.to_string(),
+            to_version: self.to_version.to_string(),
+        }
+    }
+}
+
+/// The ordered state migrations of a contract.
+///
+/// Each step migrates the state stored by `from_version` to the state expected by `to_version`.
+/// When migrating, every step between the stored contract version and the new version is run in order.
+#[derive(Clone, Default)]
+pub struct MigrationRegistry {
+    steps: Vec<MigrationStep>,
+}
+
+impl MigrationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_step(
+        mut self,
+        from_version: &'static str,
+        to_version: &'static str,
+        migrate: MigrationFn,
+    ) -> Self {
+        self.steps.push(MigrationStep {
+            from_version,
+            to_version,
+            migrate,
+        });
+        self
+    }
+
+    /// Ensures each step upgrades to a newer version and that steps are ordered without overlapping
+    pub fn validate(&self) -> Result<(), ContractError> {
+        let mut previous: Option<Version> = None;
+        for step in self.steps.iter() {
+            let from: Version = step.from_version.parse().map_err(from_semver)?;
+            let to: Version = step.to_version.parse().map_err(from_semver)?;
+            let is_ordered = previous.map_or(true, |previous| previous <= from);
+            ensure!(
+                from < to && is_ordered,
+                ContractError::InvalidMigrationStep {
+                    from_version: step.from_version.to_string(),
+                    to_version: step.to_version.to_string(),
+                }
+            );
+            previous = Some(to);
+        }
+        Ok(())
+    }
+
+    /// The steps that are run when migrating from `from_version` to `to_version`, in order
+    pub fn steps_between(
+        &self,
+        from_version: &str,
+        to_version: &str,
+    ) -> Result<Vec<&MigrationStep>, ContractError> {
+        self.validate()?;
+        let from: Version = from_version.parse().map_err(from_semver)?;
+        let to: Version = to_version.parse().map_err(from_semver)?;
+        let mut steps = vec![];
+        for step in self.steps.iter() {
+            if step.is_between(&from, &to)? {
+                steps.push(step);
+            }
+        }
+        Ok(steps)
+    }
+
+    /// Runs every step between `from_version` and `to_version`, returning the steps that were run
+    pub fn run(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        from_version: &str,
+        to_version: &str,
+    ) -> Result<Vec<MigrationStepInfo>, ContractError> {
+        let steps = self.steps_between(from_version, to_version)?;
+        for step in steps.iter() {
+            (step.migrate)(deps.branch(), env)?;
+        }
+        Ok(steps.into_iter().map(MigrationStep::info).collect())
+    }
+
+    /// The steps that would be run when migrating from `from_version` to `to_version`
+    pub fn plan(
+        &self,
+        from_version: &str,
+        to_version: &str,
+    ) -> Result<MigrationPlanResponse, ContractError> {
+        let steps = self.steps_between(from_version, to_version)?;
+        Ok(MigrationPlanResponse {
+            from_version: from_version.to_string(),
+            to_version: to_version.to_string(),
+            steps: steps.into_iter().map(MigrationStep::info).collect(),
+        })
+    }
+}
+
+#[cw_serde]
+pub struct MigrationStepInfo {
+    pub from_version: String,
+    pub to_version: String,
+}
+
+impl std::fmt::Display for MigrationStepInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}->{}", self.from_version, self.to_version)
+    }
+}
+
+#[cw_serde]
+pub struct MigrationPlanResponse {
+    pub from_version: String,
+    pub to_version: String,
+    pub steps: Vec<MigrationStepInfo>,
+}
+
 /// Ensure compatibility when migrating from the previous version.
 ///
 /// min_version specifies the oldest version that is still compatible.
@@ -33,3 +178,62 @@ fn test_ensure_compatibility() {
         }
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cw_storage_plus::Item;
+
+    const STEPS: Item<Vec<String>> = Item::new("steps");
+
+    fn record(deps: DepsMut, name: &str) -> Result<(), ContractError> {
+        let mut steps = STEPS.may_load(deps.storage)?.unwrap_or_default();
+        steps.push(name.to_string());
+        STEPS.save(deps.storage, &steps)?;
+        Ok(())
+    }
+
+    fn first(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+        record(deps, "first")
+    }
+
+    fn second(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+        record(deps, "second")
+    }
+
+    #[test]
+    fn test_migration_registry() {
+        let registry = MigrationRegistry::new()
+            .add_step("1.0.0", "1.1.0", first)
+            .add_step("1.1.0", "2.0.0", second);
+
+        let plan = registry.plan("1.0.0", "1.1.0").unwrap();
+        assert_eq!(plan.steps.len(), 1);
+        assert_eq!(plan.steps[0].to_string(), "1.0.0->1.1.0");
+        assert_eq!(registry.plan("1.1.0", "1.2.0").unwrap().steps, vec![]);
+        // Contracts stored at a patch release after the step's origin still run it
+        assert_eq!(registry.plan("1.0.5", "1.1.0").unwrap().steps.len(), 1);
+
+        let mut deps = mock_dependencies();
+        let steps = registry
+            .run(deps.as_mut(), &mock_env(), "0.9.0", "2.0.1")
+            .unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            STEPS.load(deps.as_ref().storage).unwrap(),
+            vec!["first".to_string(), "second".to_string()]
+        );
+
+        let unordered = MigrationRegistry::new()
+            .add_step("1.1.0", "2.0.0", second)
+            .add_step("1.0.0", "1.1.0", first);
+        assert_eq!(
+            unordered.validate().unwrap_err(),
+            ContractError::InvalidMigrationStep {
+                from_version: "1.0.0".to_string(),
+                to_version: "1.1.0".to_string(),
+            }
+        );
+    }
+}
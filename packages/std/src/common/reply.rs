@@ -9,6 +9,8 @@ pub enum ReplyId {
     IBCHooksPacketSend = 103,
     Recovery = 104,
     RegisterUsername = 105,
+    AMPMsgExitAtError = 106,
+    CrossChainCreateCallback = 107,
     // App
     ClaimOwnership = 200,
     AssignApp = 201,
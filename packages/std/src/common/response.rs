@@ -1,8 +1,170 @@
 use crate::error::ContractError;
-use cosmwasm_std::Reply;
+use cosmwasm_std::{Binary, Coin, Event, Reply, Uint128};
 use cw_utils::parse_reply_instantiate_data;
 
 pub fn get_reply_address(msg: Reply) -> Result<String, ContractError> {
     let res = parse_reply_instantiate_data(msg)?;
     Ok(res.contract_address)
 }
+
+/// Gets the address and the data, if any, returned by an instantiation reply
+pub fn get_reply_instantiate_data(msg: Reply) -> Result<(String, Option<Binary>), ContractError> {
+    let res = parse_reply_instantiate_data(msg)?;
+    let data = res.data.filter(|data| !data.is_empty());
+    Ok((res.contract_address, data))
+}
+
+/// The version of the standard ADO event schema, included in the type of every `AndrEvent`
+pub const ANDR_EVENT_VERSION: &str = "v1";
+
+/// A standard event for a core ADO action.
+///
+/// Events are emitted with the type `andr_{version}_{name}`, e.g. `andr_v1_fee_paid`, and stable attribute keys
+/// so that indexers can handle the actions of every ADO in the same way.
+/// Assets are formatted as `native:{denom}` or `cw20:{address}`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AndrEvent {
+    /// A fee was paid for an action
+    FeePaid {
+        action: String,
+        payee: String,
+        recipient: String,
+        asset: String,
+        amount: Uint128,
+    },
+    /// A rate was applied to a payment
+    RateApplied {
+        rate_type: String,
+        recipient: String,
+        asset: String,
+        amount: Uint128,
+        deducted: bool,
+    },
+    /// The permission of an actor for an action was set, or removed if no permission is provided
+    PermissionChanged {
+        action: String,
+        actor: String,
+        permission: Option<String>,
+    },
+    /// The contract owner changed, no new owner is provided if the contract was disowned
+    OwnershipChanged {
+        previous_owner: String,
+        new_owner: Option<String>,
+    },
+    /// Funds were sent to a recipient
+    FundsSent { recipient: String, funds: Vec<Coin> },
+}
+
+impl AndrEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AndrEvent::FeePaid { .. } => "fee_paid",
+            AndrEvent::RateApplied { .. } => "rate_applied",
+            AndrEvent::PermissionChanged { .. } => "permission_changed",
+            AndrEvent::OwnershipChanged { .. } => "ownership_changed",
+            AndrEvent::FundsSent { .. } => "funds_sent",
+        }
+    }
+
+    /// The type of the emitted event, e.g. `andr_v1_fee_paid`
+    pub fn event_type(&self) -> String {
+        format!("andr_{ANDR_EVENT_VERSION}_{}", self.name())
+    }
+
+    /// The attributes of the emitted event in a fixed order
+    pub fn attributes(&self) -> Vec<(&'static str, String)> {
+        match self {
+            AndrEvent::FeePaid {
+                action,
+                payee,
+                recipient,
+                asset,
+                amount,
+            } => vec![
+                ("action", action.clone()),
+                ("payee", payee.clone()),
+                ("recipient", recipient.clone()),
+                ("asset", asset.clone()),
+                ("amount", amount.to_string()),
+            ],
+            AndrEvent::RateApplied {
+                rate_type,
+                recipient,
+                asset,
+                amount,
+                deducted,
+            } => vec![
+                ("rate_type", rate_type.clone()),
+                ("recipient", recipient.clone()),
+                ("asset", asset.clone()),
+                ("amount", amount.to_string()),
+                ("deducted", deducted.to_string()),
+            ],
+            AndrEvent::PermissionChanged {
+                action,
+                actor,
+                permission,
+            } => vec![
+                ("action", action.clone()),
+                ("actor", actor.clone()),
+                ("permission", permission.clone().unwrap_or_default()),
+            ],
+            AndrEvent::OwnershipChanged {
+                previous_owner,
+                new_owner,
+            } => vec![
+                ("previous_owner", previous_owner.clone()),
+                ("new_owner", new_owner.clone().unwrap_or_default()),
+            ],
+            AndrEvent::FundsSent { recipient, funds } => vec![
+                ("recipient", recipient.clone()),
+                (
+                    "funds",
+                    funds
+                        .iter()
+                        .map(|coin| coin.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ),
+            ],
+        }
+    }
+}
+
+impl From<AndrEvent> for Event {
+    fn from(andr_event: AndrEvent) -> Self {
+        Event::new(andr_event.event_type())
+            .add_attribute("version", ANDR_EVENT_VERSION)
+            .add_attributes(andr_event.attributes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::coin;
+
+    #[test]
+    fn test_andr_event() {
+        let event: Event = AndrEvent::FundsSent {
+            recipient: "recipient".to_string(),
+            funds: vec![coin(100, "uandr"), coin(50, "uusd")],
+        }
+        .into();
+        assert_eq!(
+            event,
+            Event::new("andr_v1_funds_sent")
+                .add_attribute("version", "v1")
+                .add_attribute("recipient", "recipient")
+                .add_attribute("funds", "100uandr,50uusd")
+        );
+
+        let event: Event = AndrEvent::OwnershipChanged {
+            previous_owner: "owner".to_string(),
+            new_owner: None,
+        }
+        .into();
+        assert_eq!(event.ty, "andr_v1_ownership_changed");
+        assert_eq!(event.attributes[2].value, "");
+    }
+}
@@ -123,6 +123,15 @@ pub enum ContractError {
     #[error("Paused")]
     Paused {},
 
+    #[error("Admin action {id} not found")]
+    AdminActionNotFound { id: u64 },
+
+    #[error("Admin action {id} is timelocked until {executable_at}")]
+    AdminActionTimelocked {
+        id: u64,
+        executable_at: Milliseconds,
+    },
+
     #[error("EmptyOptional")]
     EmptyOptional {},
 
@@ -285,6 +294,15 @@ pub enum ContractError {
     #[error("ActorNotFound")]
     ActorNotFound {},
 
+    #[error("Role not found: {role}")]
+    RoleNotFound { role: String },
+
+    #[error("Role already exists: {role}")]
+    RoleAlreadyExists { role: String },
+
+    #[error("Membership of role {role} is managed by an address list")]
+    RoleMembershipDelegated { role: String },
+
     #[error("ModuleDiscriptionTooLong: {msg}")]
     ModuleDiscriptionTooLong { msg: String },
 
@@ -399,6 +417,9 @@ pub enum ContractError {
     #[error("InvalidADOVersion: {msg:?}")]
     InvalidADOVersion { msg: Option<String> },
 
+    #[error("DeprecatedADOVersion: {ado_version} (code ID {code_id}) is deprecated")]
+    DeprecatedADOVersion { ado_version: String, code_id: u64 },
+
     #[error("InvalidMinBid: {msg:?}")]
     InvalidMinBid { msg: Option<String> },
 
@@ -487,6 +508,12 @@ pub enum ContractError {
     #[error("Invalid migration. Unable to migrate from version {prev}")]
     InvalidMigration { prev: String },
 
+    #[error("Invalid migration step from {from_version} to {to_version}")]
+    InvalidMigrationStep {
+        from_version: String,
+        to_version: String,
+    },
+
     #[error("Invalid xml preamble for SVG")]
     InvalidXmlPreamble {},
 
@@ -557,6 +584,12 @@ pub enum ContractError {
     #[error("TooManyAppComponents")]
     TooManyAppComponents {},
 
+    #[error("Invalid template: {msg}")]
+    InvalidTemplate { msg: String },
+
+    #[error("Missing template parameter {param}")]
+    MissingTemplateParam { param: String },
+
     #[error("InvalidLtvRatio: {msg}")]
     InvalidLtvRatio { msg: String },
 
@@ -620,6 +653,9 @@ pub enum ContractError {
     #[error("Invalid Asset: {asset}")]
     InvalidAsset { asset: String },
 
+    #[error("Rate asset {rate_asset} does not match any payment asset")]
+    RateAssetMismatch { rate_asset: String },
+
     #[error("Asset Error")]
     AssetError {},
 
@@ -692,9 +728,30 @@ pub enum ContractError {
     #[error("Invalid component: {name}")]
     InvalidComponent { name: String },
 
+    #[error("The app must be the admin of component {name} to migrate it")]
+    AppNotComponentAdmin { name: String },
+
+    #[error("Base ADO messages cannot be proxied to component {name}")]
+    ProxiedBaseMessage { name: String },
+
+    #[error("Component {name} depends on invalid component {dependency}")]
+    InvalidComponentDependency { name: String, dependency: String },
+
+    #[error("Cyclic dependencies between components: {components}")]
+    CyclicComponentDependencies { components: String },
+
+    #[error("Cross-chain component {name} can only be declared when the app is instantiated")]
+    CrossChainComponentAfterInstantiation { name: String },
+
     #[error("Multi-batch not supported")]
     MultiBatchNotSupported {},
 
+    #[error("Batch message {index} failed: {error}")]
+    BatchMessageFailed { index: u32, error: String },
+
+    #[error("Invalid batch message {index}: {msg}")]
+    InvalidBatchMessage { index: u32, msg: String },
+
     #[error("Unexpected number of bytes. Expected: {expected}, actual: {actual}")]
     UnexpectedNumberOfBytes { expected: u8, actual: usize },
 
@@ -707,6 +764,12 @@ pub enum ContractError {
     #[error("Invalid Pathname, {error:?}")]
     InvalidPathname { error: Option<String> },
 
+    #[error("Symlink cycle detected at {path}")]
+    SymlinkCycle { path: String },
+
+    #[error("Symlink resolution exceeded the maximum depth of {max_depth}")]
+    SymlinkDepthExceeded { max_depth: u8 },
+
     #[error("Invalid Username, {error:?}")]
     InvalidUsername { error: Option<String> },
 
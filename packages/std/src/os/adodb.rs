@@ -1,12 +1,13 @@
 use std::str::FromStr;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{ensure, Addr, Api, Uint128};
+use cosmwasm_std::{ensure, to_json_string, Addr, Api, Decimal, Uint128};
 use schemars::JsonSchema;
 use semver::Version;
 use serde::{Deserialize, Serialize};
+use strum_macros::AsRefStr;
 
-use crate::{ado_base::ownership::OwnershipMessage, error::ContractError};
+use crate::{ado_base::ownership::OwnershipMessage, common::Funds, error::ContractError};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -15,6 +16,7 @@ pub struct InstantiateMsg {
 }
 
 #[cw_serde]
+#[derive(AsRefStr)]
 pub enum ExecuteMsg {
     Publish {
         code_id: u64,
@@ -39,8 +41,26 @@ pub enum ExecuteMsg {
         ado_type: String,
         publisher: String,
     },
+    /// Flags a published version as deprecated, it remains resolvable but is refused by the kernel and apps unless forced
+    Deprecate {
+        ado_type: String,
+        version: String,
+        reason: Option<String>,
+    },
+    /// Publishes a reusable set of app components that apps can be instantiated from
+    PublishTemplate {
+        name: String,
+        version: String,
+        components: Vec<TemplateComponent>,
+        params_schema: Vec<TemplateParam>,
+    },
     // Base message
     Ownership(OwnershipMessage),
+    Pause {
+        actions: Option<Vec<String>>,
+        until: Option<crate::common::expiration::Expiry>,
+    },
+    Unpause {},
 }
 
 #[cw_serde]
@@ -49,6 +69,82 @@ pub struct ActionFee {
     pub asset: String,
     pub amount: Uint128,
     pub receiver: Option<Addr>,
+    /// Additional assets the fee can be paid in, tried in order after `asset`
+    pub alternative_assets: Option<Vec<FeeAsset>>,
+    /// Percentage of the funds attached to the action charged on top of the flat amount.
+    /// Only funds matching the asset used to pay the fee are considered.
+    pub percentage: Option<Decimal>,
+}
+
+/// An asset and flat amount that an action fee may be paid in
+#[cw_serde]
+pub struct FeeAsset {
+    pub asset: String,
+    pub amount: Uint128,
+}
+
+impl FeeAsset {
+    pub fn new(asset: impl Into<String>, amount: Uint128) -> Self {
+        Self {
+            asset: asset.into(),
+            amount,
+        }
+    }
+
+    /// Valiades the provided asset for an action fee
+    /// An asset is valid if it fits the format "cw20:address" or "native:denom"
+    /// If the asset type is cw20 the address is also validated
+    /// TODO: Add denom validation in future cosmwasm version
+    pub fn validate_asset(&self, api: &dyn Api) -> Result<(), ContractError> {
+        validate_fee_asset(api, &self.asset)
+    }
+
+    /// Gets the asset string without the asset type
+    ///
+    /// i.e. **cw20:address** would return **"address"** or native:denom would return **"denom"**
+    pub fn get_asset_string(&self) -> Result<&str, ContractError> {
+        fee_asset_string(&self.asset)
+    }
+}
+
+fn validate_fee_asset(api: &dyn Api, asset: &str) -> Result<(), ContractError> {
+    let asset_split = asset.split(':').collect::<Vec<&str>>();
+    // Ensure asset is in the format "cw20:address" or "native:denom"
+    // This is double validated as the asset type in the ADODB contract for fees is validated as cw20:* or native:*
+    ensure!(
+        asset_split.len() == 2 && !asset_split.is_empty(),
+        ContractError::InvalidAsset {
+            asset: asset.to_string()
+        }
+    );
+    let asset_type = asset_split[0];
+    ensure!(
+        asset_type == "cw20" || asset_type == "native",
+        ContractError::InvalidAsset {
+            asset: asset.to_string()
+        }
+    );
+
+    if asset_type == "cw20" {
+        api.addr_validate(asset_split[1])?;
+    }
+
+    Ok(())
+}
+
+fn fee_asset_string(asset: &str) -> Result<&str, ContractError> {
+    ensure!(
+        asset.contains(':'),
+        ContractError::InvalidAsset {
+            asset: asset.to_string()
+        }
+    );
+    match asset.split(':').next_back() {
+        Some(asset_string) => Ok(asset_string),
+        None => Err(ContractError::InvalidAsset {
+            asset: asset.to_string(),
+        }),
+    }
 }
 
 impl ActionFee {
@@ -58,42 +154,46 @@ impl ActionFee {
             asset,
             amount,
             receiver: None,
+            alternative_assets: None,
+            percentage: None,
         }
     }
 
     pub fn with_receive(&self, receiver: Addr) -> Self {
         Self {
-            action: self.action.clone(),
-            asset: self.asset.clone(),
-            amount: self.amount,
             receiver: Some(receiver),
+            ..self.clone()
         }
     }
 
-    /// Valiades the provided asset for an action fee
-    /// An asset is valid if it fits the format "cw20:address" or "native:denom"
-    /// If the asset type is cw20 the address is also validated
-    /// TODO: Add denom validation in future cosmwasm version
-    pub fn validate_asset(&self, api: &dyn Api) -> Result<(), ContractError> {
-        let asset_split = self.asset.split(':').collect::<Vec<&str>>();
-        // Ensure asset is in the format "cw20:address" or "native:denom"
-        // This is double validated as the asset type in the ADODB contract for fees is validated as cw20:* or native:*
-        ensure!(
-            asset_split.len() == 2 && !asset_split.is_empty(),
-            ContractError::InvalidAsset {
-                asset: self.asset.clone()
-            }
-        );
-        let asset_type = asset_split[0];
-        ensure!(
-            asset_type == "cw20" || asset_type == "native",
-            ContractError::InvalidAsset {
-                asset: self.asset.clone()
-            }
-        );
+    pub fn with_alternative_assets(&self, alternative_assets: Vec<FeeAsset>) -> Self {
+        Self {
+            alternative_assets: Some(alternative_assets),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_percentage(&self, percentage: Decimal) -> Self {
+        Self {
+            percentage: Some(percentage),
+            ..self.clone()
+        }
+    }
+
+    /// Returns every asset the fee can be paid in, in order of preference
+    pub fn assets(&self) -> Vec<FeeAsset> {
+        let mut assets = vec![FeeAsset::new(self.asset.clone(), self.amount)];
+        assets.extend(self.alternative_assets.clone().unwrap_or_default());
+        assets
+    }
 
-        if asset_type == "cw20" {
-            api.addr_validate(asset_split[1])?;
+    /// Validates every asset the fee can be paid in and ensures the percentage does not exceed 100%
+    pub fn validate_asset(&self, api: &dyn Api) -> Result<(), ContractError> {
+        for asset in self.assets() {
+            asset.validate_asset(api)?;
+        }
+        if let Some(percentage) = self.percentage {
+            ensure!(percentage <= Decimal::one(), ContractError::InvalidRate {});
         }
 
         Ok(())
@@ -103,19 +203,174 @@ impl ActionFee {
     ///
     /// i.e. **cw20:address** would return **"address"** or native:denom would return **"denom"**
     pub fn get_asset_string(&self) -> Result<&str, ContractError> {
+        fee_asset_string(&self.asset)
+    }
+
+    /// Calculates the fee owed when paying in the given asset.
+    ///
+    /// This is the flat amount for the asset plus the fee percentage of any attached native or CW20 funds of the same asset.
+    pub fn amount_for(
+        &self,
+        fee_asset: &FeeAsset,
+        funds: &[Funds],
+    ) -> Result<Uint128, ContractError> {
+        let mut amount = fee_asset.amount;
+        if let Some(percentage) = self.percentage {
+            let asset = fee_asset.get_asset_string()?;
+            let is_cw20 = fee_asset.asset.starts_with("cw20:");
+            let attached = funds
+                .iter()
+                .filter_map(|funds| match funds {
+                    Funds::Native(coin) if !is_cw20 && coin.denom == asset => Some(coin.amount),
+                    Funds::Cw20(coin) if is_cw20 && coin.address == asset => Some(coin.amount),
+                    _ => None,
+                })
+                .try_fold(Uint128::zero(), |acc, amount| acc.checked_add(amount))?;
+            amount = amount.checked_add(attached.mul_floor(percentage))?;
+        }
+
+        Ok(amount)
+    }
+}
+
+/// The parameter substituted with the owner of the app created from a template, unless provided
+pub const TEMPLATE_OWNER_PARAM: &str = "owner";
+
+/// A component of an app template
+#[cw_serde]
+pub struct TemplateComponent {
+    pub name: String,
+    pub ado_type: String,
+    /// The JSON instantiate message of the component, `{{param}}` placeholders are substituted with the template parameters
+    pub instantiate_msg: String,
+}
+
+/// A parameter of an app template
+#[cw_serde]
+pub struct TemplateParam {
+    pub name: String,
+    pub description: Option<String>,
+    /// Used when the parameter is not provided
+    pub default: Option<String>,
+}
+
+#[cw_serde]
+pub struct AppTemplate {
+    pub name: String,
+    pub version: String,
+    pub components: Vec<TemplateComponent>,
+    pub params_schema: Vec<TemplateParam>,
+    pub publisher: String,
+}
+
+impl AppTemplate {
+    /// Ensures the template has components, that its parameters are unique and that every placeholder is a declared parameter or `owner`
+    pub fn validate(&self) -> Result<(), ContractError> {
         ensure!(
-            self.asset.contains(':'),
-            ContractError::InvalidAsset {
-                asset: self.asset.clone()
+            !self.components.is_empty(),
+            ContractError::InvalidTemplate {
+                msg: "Templates must have at least one component".to_string()
             }
         );
-        match self.asset.split(':').last() {
-            Some(asset) => Ok(asset),
-            None => Err(ContractError::InvalidAsset {
-                asset: self.asset.clone(),
-            }),
+        for (idx, param) in self.params_schema.iter().enumerate() {
+            ensure!(
+                !self.params_schema[..idx]
+                    .iter()
+                    .any(|other| other.name == param.name),
+                ContractError::InvalidTemplate {
+                    msg: format!("Duplicate parameter {}", param.name)
+                }
+            );
+        }
+        for component in self.components.iter() {
+            substitute_placeholders(&component.instantiate_msg, |placeholder| {
+                ensure!(
+                    placeholder == TEMPLATE_OWNER_PARAM
+                        || self
+                            .params_schema
+                            .iter()
+                            .any(|param| param.name == placeholder),
+                    ContractError::InvalidTemplate {
+                        msg: format!(
+                            "Component {} uses undeclared parameter {placeholder}",
+                            component.name
+                        )
+                    }
+                );
+                Ok(String::new())
+            })?;
         }
+        Ok(())
+    }
+
+    /// Substitutes the given parameters, or their defaults, into the instantiate message of each component.
+    ///
+    /// Values are escaped as JSON string contents so they cannot change the structure of the message.
+    pub fn render(
+        &self,
+        params: &[(String, String)],
+    ) -> Result<Vec<TemplateComponent>, ContractError> {
+        self.components
+            .iter()
+            .map(|component| {
+                let instantiate_msg =
+                    substitute_placeholders(&component.instantiate_msg, |placeholder| {
+                        let value = params
+                            .iter()
+                            .find(|(name, _)| name == placeholder)
+                            .map(|(_, value)| value.clone())
+                            .or_else(|| {
+                                self.params_schema
+                                    .iter()
+                                    .find(|param| param.name == placeholder)
+                                    .and_then(|param| param.default.clone())
+                            })
+                            .ok_or(ContractError::MissingTemplateParam {
+                                param: placeholder.to_string(),
+                            })?;
+                        escape_json_string(&value)
+                    })?;
+                Ok(TemplateComponent {
+                    name: component.name.clone(),
+                    ado_type: component.ado_type.clone(),
+                    instantiate_msg,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Escapes the given value as the contents of a JSON string
+pub fn escape_json_string(value: &str) -> Result<String, ContractError> {
+    let quoted = to_json_string(value)?;
+    Ok(quoted[1..quoted.len() - 1].to_string())
+}
+
+/// Replaces each `{{param}}` placeholder in the given string with the value returned for its name
+fn substitute_placeholders(
+    template: &str,
+    mut value_of: impl FnMut(&str) -> Result<String, ContractError>,
+) -> Result<String, ContractError> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or(ContractError::InvalidTemplate {
+            msg: "Unclosed placeholder".to_string(),
+        })?;
+        let name = after[..end].trim();
+        ensure!(
+            !name.is_empty(),
+            ContractError::InvalidTemplate {
+                msg: "Empty placeholder".to_string()
+            }
+        );
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&value_of(name)?);
+        rest = &after[end + 2..];
     }
+    rendered.push_str(rest);
+    Ok(rendered)
 }
 
 #[cw_serde]
@@ -124,9 +379,23 @@ pub struct ADOMetadata {
     pub latest_version: String,
 }
 
+#[cw_serde]
+pub struct DeprecationInfo {
+    pub reason: Option<String>,
+}
+
+#[cw_serde]
+pub struct ADOVersionResponse {
+    /// The full `ado_type@version` key
+    pub version: String,
+    pub code_id: u64,
+    pub deprecated: Option<DeprecationInfo>,
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
+    /// Resolves a code ID from either an exact version (`ado_type@1.0.0`), a semver requirement (`ado_type@^1.0`) or the latest version (`ado_type`)
     #[returns(u64)]
     CodeId { key: String },
     // #[returns(Vec<u64>)]
@@ -142,7 +411,8 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
-    #[returns(Vec<String>)]
+    /// Lists the published versions of an ADO type, latest first, along with their code IDs and deprecation status
+    #[returns(Vec<ADOVersionResponse>)]
     #[serde(rename = "ado_versions")]
     ADOVersions {
         ado_type: String,
@@ -159,6 +429,12 @@ pub enum QueryMsg {
     ActionFee { ado_type: String, action: String },
     #[returns(Option<ActionFee>)]
     ActionFeeByCodeId { code_id: u64, action: String },
+    /// Gets an app template, defaults to the latest version
+    #[returns(AppTemplate)]
+    Template {
+        name: String,
+        version: Option<String>,
+    },
     // Base queries
     #[returns(crate::ado_base::version::VersionResponse)]
     Version {},
@@ -166,6 +442,8 @@ pub enum QueryMsg {
     Type {},
     #[returns(crate::ado_base::ownership::ContractOwnerResponse)]
     Owner {},
+    #[returns(crate::ado_base::pause::PauseStatusResponse)]
+    PauseStatus {},
     #[returns(crate::ado_base::kernel_address::KernelAddressResponse)]
     KernelAddress {},
 }
@@ -262,7 +540,7 @@ impl ADOVersion {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{testing::mock_dependencies, Coin};
 
     use super::*;
 
@@ -330,4 +608,118 @@ mod tests {
             ActionFee::new("action".to_string(), "invalid".to_string(), Uint128::zero());
         assert!(action_fee.validate_asset(deps.as_ref().api).is_err());
     }
+
+    #[test]
+    fn test_action_fee_amount_for() {
+        let action_fee = ActionFee::new(
+            "action".to_string(),
+            "cw20:token".to_string(),
+            Uint128::from(10u128),
+        )
+        .with_alternative_assets(vec![FeeAsset::new("native:token", Uint128::from(20u128))])
+        .with_percentage(Decimal::percent(10));
+        let funds = vec![
+            Funds::Cw20(cw20::Cw20Coin {
+                address: "token".to_string(),
+                amount: Uint128::from(100u128),
+            }),
+            Funds::Native(Coin::new(50, "token")),
+        ];
+
+        let assets = action_fee.assets();
+        // Percentages are only charged on funds of the same asset type
+        assert_eq!(
+            action_fee.amount_for(&assets[0], &funds).unwrap(),
+            Uint128::from(20u128)
+        );
+        assert_eq!(
+            action_fee.amount_for(&assets[1], &funds).unwrap(),
+            Uint128::from(25u128)
+        );
+    }
+
+    #[test]
+    fn test_template_render() {
+        let template = AppTemplate {
+            name: "marketplace".to_string(),
+            version: "1.0.0".to_string(),
+            components: vec![TemplateComponent {
+                name: "token".to_string(),
+                ado_type: "cw721".to_string(),
+                instantiate_msg:
+                    r#"{"name":"{{name}}","minter":"{{owner}}","symbol":"{{ symbol }}"}"#
+                        .to_string(),
+            }],
+            params_schema: vec![
+                TemplateParam {
+                    name: "name".to_string(),
+                    description: None,
+                    default: None,
+                },
+                TemplateParam {
+                    name: "symbol".to_string(),
+                    description: None,
+                    default: Some("TT".to_string()),
+                },
+            ],
+            publisher: "publisher".to_string(),
+        };
+        template.validate().unwrap();
+
+        let components = template
+            .render(&[
+                ("name".to_string(), "Token".to_string()),
+                ("owner".to_string(), "owner".to_string()),
+            ])
+            .unwrap();
+        assert_eq!(
+            components[0].instantiate_msg,
+            r#"{"name":"Token","minter":"owner","symbol":"TT"}"#
+        );
+
+        // Values cannot inject fields into the instantiate message
+        let components = template
+            .render(&[
+                (
+                    "name".to_string(),
+                    r#"Token","admin":"attacker"#.to_string(),
+                ),
+                ("owner".to_string(), "owner".to_string()),
+            ])
+            .unwrap();
+        assert_eq!(
+            components[0].instantiate_msg,
+            r#"{"name":"Token\",\"admin\":\"attacker","minter":"owner","symbol":"TT"}"#
+        );
+
+        let err = template
+            .render(&[("owner".to_string(), "owner".to_string())])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MissingTemplateParam {
+                param: "name".to_string()
+            }
+        );
+
+        let mut duplicate = template.clone();
+        duplicate
+            .params_schema
+            .push(duplicate.params_schema[0].clone());
+        assert_eq!(
+            duplicate.validate().unwrap_err(),
+            ContractError::InvalidTemplate {
+                msg: "Duplicate parameter name".to_string()
+            }
+        );
+
+        let mut invalid = template;
+        invalid.params_schema.pop();
+        assert_eq!(
+            invalid.validate().unwrap_err(),
+            ContractError::InvalidTemplate {
+                msg: "Component token uses undeclared parameter symbol".to_string()
+            }
+        );
+    }
 }
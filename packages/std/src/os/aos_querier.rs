@@ -2,7 +2,7 @@ use crate::ado_base::permissioning::LocalPermission;
 use crate::amp::{ADO_DB_KEY, VFS_KEY};
 use crate::error::ContractError;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{from_json, Addr, QuerierWrapper};
+use cosmwasm_std::{ensure, from_json, Addr, QuerierWrapper};
 use cw_storage_plus::Path;
 use lazy_static::__Deref;
 use serde::de::DeserializeOwned;
@@ -11,7 +11,9 @@ use std::str::from_utf8;
 #[cfg(feature = "rates")]
 use crate::ado_base::rates::LocalRate;
 
-use super::adodb::{ADOVersion, ActionFee, QueryMsg as ADODBQueryMsg};
+use super::adodb::{
+    ADOVersion, ActionFee, AppTemplate, DeprecationInfo, QueryMsg as ADODBQueryMsg,
+};
 use super::kernel::ChannelInfo;
 
 #[cw_serde]
@@ -58,9 +60,18 @@ impl AOSQuerier {
         adodb_addr: &Addr,
         code_id: u64,
     ) -> Result<Option<String>, ContractError> {
+        let ado_version = AOSQuerier::ado_version_getter(querier, adodb_addr, code_id)?;
+        Ok(ado_version.map(|v| v.get_type()))
+    }
+
+    /// Queries the ADODB's raw storage for the full `ado_type@version` published under the given code ID
+    pub fn ado_version_getter(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        code_id: u64,
+    ) -> Result<Option<ADOVersion>, ContractError> {
         let key = AOSQuerier::get_map_storage_key("ado_type", &[code_id.to_string().as_bytes()])?;
-        let ado_type: Option<ADOVersion> = AOSQuerier::query_storage(querier, adodb_addr, &key)?;
-        Ok(ado_type.map(|v| v.get_type()))
+        AOSQuerier::query_storage(querier, adodb_addr, &key)
     }
 
     pub fn ado_type_getter_smart(
@@ -129,6 +140,60 @@ impl AOSQuerier {
         Ok(code_id)
     }
 
+    /// Queries the ADODB for an app template, defaulting to its latest version
+    pub fn template_getter(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        name: &str,
+        version: Option<String>,
+    ) -> Result<AppTemplate, ContractError> {
+        let query = ADODBQueryMsg::Template {
+            name: name.to_string(),
+            version,
+        };
+        let template: AppTemplate = querier.query_wasm_smart(adodb_addr, &query)?;
+        Ok(template)
+    }
+
+    /// Queries the ADODB's raw storage for the deprecation status of the given `ado_type@version`
+    pub fn deprecation_getter(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        ado_version: &ADOVersion,
+    ) -> Result<Option<DeprecationInfo>, ContractError> {
+        let key = AOSQuerier::get_map_storage_key(
+            "deprecated_versions",
+            &[ado_version.as_str().as_bytes()],
+        )?;
+        AOSQuerier::query_storage(querier, adodb_addr, &key)
+    }
+
+    /// Resolves the code ID for the given ADO type, erroring if the resolved version is deprecated unless `allow_deprecated` is set
+    pub fn non_deprecated_code_id_getter(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        ado_type: &str,
+        allow_deprecated: bool,
+    ) -> Result<u64, ContractError> {
+        let code_id = AOSQuerier::code_id_getter(querier, adodb_addr, ado_type)?;
+        if !allow_deprecated {
+            let deprecation = match AOSQuerier::ado_version_getter(querier, adodb_addr, code_id)? {
+                Some(ado_version) => {
+                    AOSQuerier::deprecation_getter(querier, adodb_addr, &ado_version)?
+                }
+                None => None,
+            };
+            ensure!(
+                deprecation.is_none(),
+                ContractError::DeprecatedADOVersion {
+                    ado_version: ado_type.to_string(),
+                    code_id
+                }
+            );
+        }
+        Ok(code_id)
+    }
+
     /// Queries the kernel's raw storage for the VFS's address
     pub fn vfs_address_getter(
         querier: &QuerierWrapper,
@@ -1,8 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Uint128};
 use cw20::Cw20ReceiveMsg;
+use strum_macros::AsRefStr;
 
-use crate::{ado_base::ownership::OwnershipMessage, amp::AndrAddr};
+use crate::{ado_base::ownership::OwnershipMessage, amp::AndrAddr, common::Funds};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -12,6 +13,7 @@ pub struct InstantiateMsg {
 }
 
 #[cw_serde]
+#[derive(AsRefStr)]
 pub enum ExecuteMsg {
     /// Deposit funds to be used by the Andromeda economics module to pay for ADO fees.
     ///
@@ -22,12 +24,23 @@ pub enum ExecuteMsg {
     /// Pay a fee for the given action. The sender must be a valid ADO contract.
     ///
     /// Fees are paid in the following fallthrough priority:
-    /// 1. The balance of the ADO contract itself
-    /// 2. The balance of the App contract for the ADO
+    /// 1. The balance of the ADO contract itself, if it has opted in via `SetFeeSponsorship`
+    /// 2. The balance of the App contract for the ADO, if it has opted in via `SetFeeSponsorship`
     /// 3. The provided payee address
+    ///
+    /// The fee is paid in the first of the fee's accepted assets that can be covered in full.
+    /// `funds` are the funds attached to the action, used to calculate percentage based fees.
     PayFee {
         payee: Addr,
         action: String,
+        funds: Option<Vec<Funds>>,
+    },
+    /// Opts an ADO or App contract in or out of paying fees from its deposited balance.
+    ///
+    /// Can only be called by the contract itself or its owner, contracts are opted out by default.
+    SetFeeSponsorship {
+        address: AndrAddr,
+        enabled: bool,
     },
     /// Withdraw native funds from the Andromeda economics module.
     ///
@@ -47,6 +60,11 @@ pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
     // Base message
     Ownership(OwnershipMessage),
+    Pause {
+        actions: Option<Vec<String>>,
+        until: Option<crate::common::expiration::Expiry>,
+    },
+    Unpause {},
 }
 
 #[cw_serde]
@@ -65,6 +83,19 @@ pub enum QueryMsg {
     /// Returns a `Uint128` representing the current balance
     #[returns(BalanceResponse)]
     Balance { asset: String, address: AndrAddr },
+    /// Queries whether the given ADO or App contract pays fees from its deposited balance
+    #[returns(bool)]
+    FeeSponsorship { address: AndrAddr },
+    /// Quotes the fee the given payee would pay for performing an action on an ADO type with the given funds attached.
+    ///
+    /// Only the payee's balance is considered when choosing the fee asset, returns `None` if the action has no fee
+    #[returns(Option<FeeQuoteResponse>)]
+    FeeQuote {
+        ado_type: String,
+        action: String,
+        payee: AndrAddr,
+        funds: Option<Vec<Funds>>,
+    },
     // Base queries
     #[returns(crate::ado_base::version::VersionResponse)]
     Version {},
@@ -72,6 +103,8 @@ pub enum QueryMsg {
     Type {},
     #[returns(crate::ado_base::ownership::ContractOwnerResponse)]
     Owner {},
+    #[returns(crate::ado_base::pause::PauseStatusResponse)]
+    PauseStatus {},
     #[returns(crate::ado_base::kernel_address::KernelAddressResponse)]
     KernelAddress {},
 }
@@ -80,3 +113,12 @@ pub enum QueryMsg {
 pub struct BalanceResponse {
     pub balance: Uint128,
 }
+
+#[cw_serde]
+pub struct FeeQuoteResponse {
+    /// The asset the fee would be paid in, i.e. "native:denom" or "cw20:address"
+    pub asset: String,
+    pub amount: Uint128,
+    /// Whether the payee's current balance covers the fee
+    pub payable: bool,
+}
@@ -5,6 +5,8 @@ use crate::amp::AndrAddr;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
 use cosmwasm_std::Binary;
+use cosmwasm_std::Timestamp;
+use strum_macros::AsRefStr;
 
 #[cw_serde]
 pub struct ChannelInfo {
@@ -25,6 +27,21 @@ impl Default for ChannelInfo {
     }
 }
 
+/// Describes one leg of a multi-coin transfer sent over IBC hooks, one ICS20 transfer is sent per coin
+#[cw_serde]
+pub struct IbcHooksTransferLeg {
+    /// Identifier of the transfer, unique to the sending kernel
+    pub transfer_id: u64,
+    /// The number of legs making up the transfer
+    pub legs: u64,
+    /// The chain the transfer was sent from
+    pub source_chain: String,
+    /// The address on the source chain that received funds are returned to should the transfer not complete
+    pub recovery_addr: String,
+    /// The time after which an incomplete transfer can be refunded
+    pub expiry: Timestamp,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub owner: Option<String>,
@@ -32,6 +49,7 @@ pub struct InstantiateMsg {
 }
 
 #[cw_serde]
+#[derive(AsRefStr)]
 pub enum ExecuteMsg {
     /// Receives an AMP Packet for relaying
     #[serde(rename = "amp_receive")]
@@ -51,6 +69,8 @@ pub enum ExecuteMsg {
         msg: Binary,
         owner: Option<AndrAddr>,
         chain: Option<String>,
+        /// Allows creating deprecated ADO versions
+        force: Option<bool>,
     },
     /// Assigns a given channel to the given chain
     AssignChannels {
@@ -59,6 +79,18 @@ pub enum ExecuteMsg {
         chain: String,
         kernel_address: String,
     },
+    /// Receives one leg of a multi-coin transfer sent over IBC hooks
+    ///
+    /// The message is relayed once every leg of the transfer has been received
+    ReceiveTransferLeg {
+        message: AMPMsg,
+        leg: IbcHooksTransferLeg,
+    },
+    /// Returns the funds of an expired, incomplete multi-coin transfer to its recovery address on the source chain
+    RefundTransfer {
+        sender: String,
+        transfer_id: u64,
+    },
     /// Recovers funds from failed IBC messages
     Recover {},
     /// Update Current Chain
@@ -69,6 +101,11 @@ pub enum ExecuteMsg {
     Internal(InternalMsg),
     // Base message
     Ownership(OwnershipMessage),
+    Pause {
+        actions: Option<Vec<String>>,
+        until: Option<crate::common::expiration::Expiry>,
+    },
+    Unpause {},
 }
 
 #[cw_serde]
@@ -81,6 +118,30 @@ pub enum InternalMsg {
     },
 }
 
+/// Sent by the kernel to a contract that created an ADO on another chain once the creation is acknowledged or times out
+#[cw_serde]
+pub enum CreateCallbackMsg {
+    CrossChainCreateAck {
+        chain: String,
+        ado_type: String,
+        /// The address of the new ADO on the receiving chain, if it was created
+        address: Option<String>,
+        /// The name and address of each component on the receiving chain, when the new ADO is an app
+        #[serde(default)]
+        components: Vec<(String, String)>,
+        error: Option<String>,
+    },
+}
+
+/// The acknowledgement of a successful cross-chain ADO creation
+#[cw_serde]
+pub struct CreateADOAck {
+    pub address: String,
+    /// The name and address of each component of the new ADO, when it is an app
+    #[serde(default)]
+    pub components: Vec<(String, String)>,
+}
+
 #[cw_serde]
 pub struct ChannelInfoResponse {
     pub ics20: Option<String>,
@@ -107,6 +168,16 @@ pub enum QueryMsg {
     Recoveries { addr: Addr },
     #[returns(ChainNameResponse)]
     ChainName {},
+    /// The logged details of the relayed packet with the given identifier
+    #[returns(PacketInfo)]
+    PacketStatus { id: u64 },
+    /// The logged packets originating from the given address, ordered by identifier
+    #[returns(Vec<PacketInfo>)]
+    PacketsByOrigin {
+        origin: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     // Base queries
     #[returns(crate::ado_base::version::VersionResponse)]
     Version {},
@@ -114,6 +185,60 @@ pub enum QueryMsg {
     Type {},
     #[returns(crate::ado_base::ownership::ContractOwnerResponse)]
     Owner {},
+    #[returns(crate::ado_base::pause::PauseStatusResponse)]
+    PauseStatus {},
+}
+
+/// The outcome of a message relayed by the kernel
+#[cw_serde]
+pub struct AMPMsgResult {
+    /// The index of the message within its packet
+    pub index: u64,
+    pub recipient: AndrAddr,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Set as the response data when the kernel relays messages, summarising the outcome of each message
+#[cw_serde]
+pub struct AMPPktResponse {
+    /// The identifier assigned to the packet by the kernel
+    pub packet_id: u64,
+    pub results: Vec<AMPMsgResult>,
+}
+
+#[cw_serde]
+pub enum PacketStatus {
+    /// Awaiting the acknowledgement of a cross-chain hop
+    Pending,
+    Succeeded,
+    Failed,
+    /// A cross-chain hop timed out
+    TimedOut,
+}
+
+/// A cross-chain hop made by a relayed packet
+#[cw_serde]
+pub struct PacketHop {
+    pub channel: String,
+    /// The sequence of the IBC packet, once known
+    pub sequence: Option<u64>,
+    pub status: PacketStatus,
+}
+
+/// The kernel's record of a relayed packet
+#[cw_serde]
+pub struct PacketInfo {
+    pub id: u64,
+    pub origin: String,
+    pub previous_sender: String,
+    pub recipients: Vec<AndrAddr>,
+    pub status: PacketStatus,
+    pub hops: Vec<PacketHop>,
+    /// The most recent error encountered by the packet, if any
+    pub error: Option<String>,
+    /// The height of the block the packet was relayed in
+    pub block_height: u64,
 }
 
 #[cw_serde]
@@ -131,6 +256,7 @@ pub enum IbcExecuteMsg {
         instantiation_msg: Binary,
         owner: AndrAddr,
         ado_type: String,
+        force: Option<bool>,
     },
     RegisterUsername {
         username: String,
@@ -2,6 +2,7 @@ use crate::{ado_base::ownership::OwnershipMessage, amp::AndrAddr, error::Contrac
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{ensure, Addr, Api, QuerierWrapper};
 use regex::Regex;
+use strum_macros::AsRefStr;
 
 pub const COMPONENT_NAME_REGEX: &str = r"^[A-Za-z0-9.\-_]{2,80}$";
 pub const USERNAME_REGEX: &str = r"^[a-z0-9]{2,30}$";
@@ -149,6 +150,7 @@ impl PathDetails {
 }
 
 #[cw_serde]
+#[derive(AsRefStr)]
 pub enum ExecuteMsg {
     AddPath {
         #[schemars(regex = "COMPONENT_NAME_REGEX")]
@@ -182,8 +184,47 @@ pub enum ExecuteMsg {
         chain: String,
         address: String,
     },
+    /// Removes a path, subject to the same ownership checks as `AddPath`
+    RemovePath {
+        name: String,
+        parent_address: Option<AndrAddr>,
+    },
+    /// Renames a path within its parent directory
+    RenamePath {
+        name: String,
+        #[schemars(regex = "COMPONENT_NAME_REGEX")]
+        new_name: String,
+        parent_address: Option<AndrAddr>,
+    },
+    /// Moves a path to a new parent directory, both directories are subject to the same ownership checks as `AddPath`
+    MovePath {
+        name: String,
+        parent_address: Option<AndrAddr>,
+        new_parent_address: Option<AndrAddr>,
+    },
+    /// Proposes transferring the sender's username, along with the paths registered directly under it, to the given address
+    ///
+    /// The transfer completes once the recipient accepts it with `AcceptUsername`
+    TransferUsername {
+        address: Addr,
+    },
+    /// Accepts a proposed username transfer, restricted to the recipient of the transfer
+    AcceptUsername {
+        username: String,
+    },
+    /// Releases the sender's username
+    UnregisterUser {},
+    /// Sets the maximum number of symlinks followed when resolving a path, restricted to the VFS owner/Kernel
+    UpdateSymlinkMaxDepth {
+        max_depth: u8,
+    },
     // Base message
     Ownership(OwnershipMessage),
+    Pause {
+        actions: Option<Vec<String>>,
+        until: Option<crate::common::expiration::Expiry>,
+    },
+    Unpause {},
 }
 
 #[cw_serde]
@@ -217,6 +258,8 @@ pub enum QueryMsg {
     GetLibrary { address: Addr },
     #[returns(AndrAddr)]
     ResolveSymlink { path: AndrAddr },
+    #[returns(u8)]
+    SymlinkMaxDepth {},
     // Base queries
     #[returns(crate::ado_base::version::VersionResponse)]
     Version {},
@@ -224,6 +267,8 @@ pub enum QueryMsg {
     Type {},
     #[returns(crate::ado_base::ownership::ContractOwnerResponse)]
     Owner {},
+    #[returns(crate::ado_base::pause::PauseStatusResponse)]
+    PauseStatus {},
     #[returns(crate::ado_base::kernel_address::KernelAddressResponse)]
     KernelAddress {},
 }
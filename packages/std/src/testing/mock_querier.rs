@@ -1,25 +1,24 @@
 use crate::{
-    ado_base::AndromedaQuery,
+    ado_base::{permissioning::LocalPermission, AndromedaQuery},
     ado_contract::ADOContract,
     amp::{ADO_DB_KEY, ECONOMICS_KEY, OSMOSIS_ROUTER_KEY, VFS_KEY},
     os::kernel::QueryMsg as KernelQueryMsg,
     os::vfs::QueryMsg as VFSQueryMsg,
     os::{
-        adodb::{ActionFee, QueryMsg as ADODBQueryMsg},
-        kernel::ChannelInfo,
+        adodb::{ActionFee, DeprecationInfo, FeeAsset, QueryMsg as ADODBQueryMsg},
+        kernel::{ChannelInfo, VerifyAddressResponse},
     },
 };
 
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::SubMsg;
 use cosmwasm_std::{
     from_json,
     testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR},
     to_json_binary, Addr, Binary, CodeInfoResponse, Coin, ContractInfoResponse, ContractResult,
-    HexBinary, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128,
-    WasmQuery,
+    Decimal, HexBinary, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult,
+    Uint128, WasmQuery,
 };
-#[cfg(feature = "primitive")]
-use cosmwasm_std::{Decimal, Uint128};
 use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
 /// Mock CW20 Contract Address
@@ -28,6 +27,8 @@ pub const MOCK_CW20_CONTRACT: &str = "cw20_contract";
 pub const MOCK_ANCHOR_CONTRACT: &str = "anchor_contract";
 /// Mock App Contract Address
 pub const MOCK_APP_CONTRACT: &str = "app_contract";
+/// Mock address of the only component listed by the mock App Contract
+pub const MOCK_APP_COMPONENT: &str = "app_component";
 /// Mock Primitive Contract Address
 pub const MOCK_PRIMITIVE_CONTRACT: &str = "primitive_contract";
 /// Mock Kernel Contract Address
@@ -56,9 +57,15 @@ pub const INVALID_CONTRACT: &str = "invalid_contract";
 pub const FAKE_VFS_PATH: &str = "/f";
 /// An invalid ADODB Key
 pub const FAKE_ADODB_KEY: &str = "fake_adodb_key";
+/// An ADO type whose resolved version (code ID 4) is flagged as deprecated
+pub const MOCK_DEPRECATED_ADO_TYPE: &str = "deprecated_ado_type";
 /// A valid action
 pub const MOCK_ACTION: &str = "action";
+/// An action with a fee payable in uusd or uandr, plus 10% of the attached funds
+pub const MOCK_MULTI_ASSET_ACTION: &str = "multi_asset_action";
 pub const UNWHITELISTED_ADDRESS: &str = "unwhitelisted_address";
+/// An address whitelisted in `MOCK_ADDRESS_LIST_CONTRACT`
+pub const MOCK_ADDRESS_LIST_MEMBER: &str = "address_list_member";
 pub const RATES_EXCLUDED_ADDRESS: &str = "rates_excluded_address";
 
 pub const MOCK_CHECKSUM: &str = "9af782a3a1bcbcd22dbb6a45c751551d9af782a3a1bcbcd22dbb6a45c751551d";
@@ -127,6 +134,17 @@ impl WasmMockQuerier {
     }
 }
 
+#[cw_serde]
+enum MockAppQueryMsg {
+    GetAddressesWithNames {},
+}
+
+#[cw_serde]
+struct MockComponentAddress {
+    name: String,
+    address: String,
+}
+
 #[derive(Default)]
 pub struct MockAndromedaQuerier {}
 
@@ -165,6 +183,7 @@ impl MockAndromedaQuerier {
                     MOCK_ADODB_CONTRACT => self.handle_adodb_raw_query(key),
                     MOCK_CW20_CONTRACT => self.handle_cw20_owner_query(key),
                     MOCK_ANCHOR_CONTRACT => self.handle_anchor_owner_query(key),
+                    MOCK_ADDRESS_LIST_CONTRACT => self.handle_address_list_raw_query(key),
 
                     _ => self.handle_ado_raw_query(key, &Addr::unchecked(contract_addr)),
                 }
@@ -212,7 +231,7 @@ impl MockAndromedaQuerier {
     ///
     /// Returns the appropriate `MOCK_CONTRACT_*` address for the given key in the case of a `KeyAddress` query.
     ///
-    /// Returns `true` for `VerifyAddress` for any address excluding `INVALID_CONTRACT`.
+    /// Verifies any address for `VerifyAddress` excluding `INVALID_CONTRACT`.
     fn handle_kernel_query(&self, msg: &Binary) -> QuerierResult {
         match from_json(msg).unwrap() {
             KernelQueryMsg::KeyAddress { key } => match key.as_str() {
@@ -228,7 +247,12 @@ impl MockAndromedaQuerier {
                 INVALID_CONTRACT => {
                     SystemResult::Ok(ContractResult::Err("Invalid Address".to_string()))
                 }
-                _ => SystemResult::Ok(ContractResult::Ok(to_json_binary(&true).unwrap())),
+                _ => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&VerifyAddressResponse {
+                        verify_address: true,
+                    })
+                    .unwrap(),
+                )),
             },
             _ => SystemResult::Ok(ContractResult::Err("Not implemented".to_string())),
         }
@@ -266,13 +290,18 @@ impl MockAndromedaQuerier {
 
     /// Handles all App queries.
     ///
-    /// Returns `"actual_address"` for `Get` queries.
-    fn handle_app_query(&self, _msg: &Binary) -> QuerierResult {
-        // match from_json(msg).unwrap() {
-        // match from_json(msg).unwrap() {
-        //     _ => SystemResult::Ok(ContractResult::Err("Error".to_string())),
-        // }
-        todo!()
+    /// Returns `MOCK_APP_COMPONENT` as the only component for `GetAddressesWithNames` queries.
+    fn handle_app_query(&self, msg: &Binary) -> QuerierResult {
+        match from_json(msg) {
+            Ok(MockAppQueryMsg::GetAddressesWithNames {}) => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&vec![MockComponentAddress {
+                    name: "component".to_string(),
+                    address: MOCK_APP_COMPONENT.to_string(),
+                }])
+                .unwrap(),
+            )),
+            Err(_) => SystemResult::Ok(ContractResult::Err("Not implemented".to_string())),
+        }
     }
 
     /// Handles all ADODB queries.
@@ -289,6 +318,9 @@ impl MockAndromedaQuerier {
             },
             ADODBQueryMsg::CodeId { key } => match key.as_str() {
                 FAKE_ADODB_KEY => SystemResult::Ok(ContractResult::Err("Invalid Key".to_string())),
+                MOCK_DEPRECATED_ADO_TYPE => {
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&4).unwrap()))
+                }
                 _ => SystemResult::Ok(ContractResult::Ok(to_json_binary(&1).unwrap())),
             },
             _ => SystemResult::Ok(ContractResult::Err("Not implemented".to_string())),
@@ -391,6 +423,19 @@ impl MockAndromedaQuerier {
         panic!("Unsupported query for contract: {contract_addr}")
     }
 
+    /// Handles address list permission lookups, only `MOCK_ADDRESS_LIST_MEMBER` is whitelisted
+    pub fn handle_address_list_raw_query(&self, key: &Binary) -> QuerierResult {
+        let key_vec = key.as_slice();
+        let key_str = String::from_utf8(key_vec.to_vec()).unwrap();
+
+        if key_str.contains("permissioning") && key_str.ends_with(MOCK_ADDRESS_LIST_MEMBER) {
+            return SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&LocalPermission::Whitelisted(None)).unwrap(),
+            ));
+        }
+        SystemResult::Ok(ContractResult::Ok(Binary::default()))
+    }
+
     pub fn handle_kernel_raw_query(&self, key: &Binary, fake: bool) -> QuerierResult {
         let key_vec = key.as_slice();
         let key_str = String::from_utf8(key_vec.to_vec()).unwrap();
@@ -443,7 +488,20 @@ impl MockAndromedaQuerier {
         let key_vec = key.as_slice();
         let key_str = String::from_utf8(key_vec.to_vec()).unwrap();
 
-        if key_str.contains("code_id") {
+        if key_str.contains("deprecated_versions") {
+            let split = key_str.split("deprecated_versions");
+            match split.last() {
+                Some(key) if key == format!("{MOCK_DEPRECATED_ADO_TYPE}@1.0.0") => {
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&DeprecationInfo {
+                            reason: Some("Deprecated".to_string()),
+                        })
+                        .unwrap(),
+                    ))
+                }
+                _ => SystemResult::Ok(ContractResult::Ok(Binary::default())),
+            }
+        } else if key_str.contains("code_id") {
             let split = key_str.split("code_id");
             let key = split.last();
             if let Some(key) = key {
@@ -451,6 +509,9 @@ impl MockAndromedaQuerier {
                     FAKE_ADODB_KEY => {
                         SystemResult::Ok(ContractResult::Err("Invalid Key".to_string()))
                     }
+                    MOCK_DEPRECATED_ADO_TYPE => {
+                        SystemResult::Ok(ContractResult::Ok(to_json_binary(&4).unwrap()))
+                    }
                     _ => SystemResult::Ok(ContractResult::Ok(to_json_binary(&1).unwrap())),
                 }
             } else {
@@ -461,7 +522,23 @@ impl MockAndromedaQuerier {
             let key = split.last();
             match key {
                 Some(key) => {
-                    if key.contains("ADOTypeaction") {
+                    if key.contains("ADOTypemulti_asset_action") {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary(
+                                &ActionFee::new(
+                                    MOCK_MULTI_ASSET_ACTION.to_string(),
+                                    "native:uusd".to_string(),
+                                    Uint128::from(10u128),
+                                )
+                                .with_alternative_assets(vec![FeeAsset::new(
+                                    "native:uandr",
+                                    Uint128::from(20u128),
+                                )])
+                                .with_percentage(Decimal::percent(10)),
+                            )
+                            .unwrap(),
+                        ))
+                    } else if key.contains("ADOTypeaction") {
                         SystemResult::Ok(ContractResult::Ok(
                             to_json_binary(&ActionFee::new(
                                 MOCK_ACTION.to_string(),
@@ -484,6 +561,10 @@ impl MockAndromedaQuerier {
             if let Some(key) = key {
                 if key == "3" {
                     SystemResult::Ok(ContractResult::Ok(to_json_binary("app-contract").unwrap()))
+                } else if key == "4" {
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&format!("{MOCK_DEPRECATED_ADO_TYPE}@1.0.0")).unwrap(),
+                    ))
                 } else if key == "1" {
                     SystemResult::Ok(ContractResult::Ok(to_json_binary("ADOType").unwrap()))
                 } else {
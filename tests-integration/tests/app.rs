@@ -1,11 +1,12 @@
 #![cfg(not(target_arch = "wasm32"))]
 
-use andromeda_app::app::AppComponent;
-use andromeda_app_contract::mock::{mock_andromeda_app, MockAppContract};
+use andromeda_app::app::{AppComponent, ExecuteMsg, InstantiateMsg};
+use andromeda_app_contract::mock::{mock_andromeda_app, mock_app_instantiate_msg, MockAppContract};
 use andromeda_cw721::mock::{mock_andromeda_cw721, mock_cw721_instantiate_msg};
-use andromeda_std::os::vfs::convert_component_name;
+use andromeda_std::{ado_base::MigrateMsg, error::ContractError, os::vfs::convert_component_name};
 use andromeda_testing::{mock::mock_app, mock_builder::MockAndromedaBuilder, MockContract};
-use cosmwasm_std::{coin, to_json_binary};
+use cosmwasm_std::{coin, to_json_binary, DepsMut, Env, Response};
+use cw_multi_test::{ContractWrapper, Executor};
 
 #[test]
 fn test_app() {
@@ -82,3 +83,73 @@ fn test_app() {
     let component_addresses = app.query_components(&router);
     assert_eq!(component_addresses.len(), components.len() + 2);
 }
+
+fn mock_migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    Ok(Response::default())
+}
+
+#[test]
+fn test_app_migrate_components() {
+    let mut router = mock_app(None);
+    let migratable_cw721 = ContractWrapper::new_with_empty(
+        andromeda_cw721::contract::execute,
+        andromeda_cw721::contract::instantiate,
+        andromeda_cw721::contract::query,
+    )
+    .with_migrate(mock_migrate);
+    let andr = MockAndromedaBuilder::new(&mut router, "admin")
+        .with_wallets(vec![("owner", vec![])])
+        .with_contracts(vec![
+            ("cw721@0.1.0", mock_andromeda_cw721()),
+            ("cw721@0.2.0", Box::new(migratable_cw721)),
+            ("app-contract", mock_andromeda_app()),
+        ])
+        .build(&mut router);
+    let owner = andr.get_wallet("owner");
+
+    let cw721_init_msg = mock_cw721_instantiate_msg(
+        "Test Tokens".to_string(),
+        "TT".to_string(),
+        owner.to_string(),
+        andr.kernel.addr().to_string(),
+        None,
+    );
+    let msg = InstantiateMsg {
+        app_is_component_admin: Some(true),
+        ..mock_app_instantiate_msg(
+            "Migrating App",
+            vec![AppComponent::new(
+                "cw721",
+                "cw721@0.1.0",
+                to_json_binary(&cw721_init_msg).unwrap(),
+            )],
+            andr.kernel.addr(),
+            None,
+        )
+    };
+    let app_code_id = andr.get_code_id(&mut router, "app-contract");
+    let app_addr = router
+        .instantiate_contract(app_code_id, owner.clone(), &msg, &[], "App Contract", None)
+        .unwrap();
+    let app = MockAppContract::from(app_addr.clone());
+
+    // The app is the admin of the components it instantiates and can migrate them without further transactions
+    let cw721_addr = app.query_component_addr(&router, "cw721");
+    let contract_info = router.wrap().query_wasm_contract_info(&cw721_addr).unwrap();
+    assert_eq!(contract_info.admin, Some(app_addr.to_string()));
+
+    app.execute(
+        &mut router,
+        &ExecuteMsg::MigrateComponents {
+            targets: vec![("cw721".to_string(), "0.2.0".to_string())],
+        },
+        owner.clone(),
+        &[],
+    )
+    .unwrap();
+    let contract_info = router.wrap().query_wasm_contract_info(&cw721_addr).unwrap();
+    assert_eq!(
+        contract_info.code_id,
+        andr.get_code_id(&mut router, "cw721@0.2.0")
+    );
+}
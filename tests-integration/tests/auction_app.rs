@@ -127,6 +127,7 @@ fn test_auction_app_modules() {
                     percent: Decimal::percent(25),
                 }),
                 description: None,
+                weights: None,
             }),
         )
         .unwrap();
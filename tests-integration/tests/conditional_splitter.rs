@@ -82,6 +82,7 @@ fn test_conditional_splitter() {
         name: "conditional-splitter".to_string(),
         component_type: ComponentType::new(splitter_init_msg),
         ado_type: "conditional-splitter".to_string(),
+        depends_on: None,
     };
 
     let app_components = vec![splitter_app_component.clone()];
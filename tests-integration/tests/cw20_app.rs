@@ -109,6 +109,7 @@ fn test_cw20_with_rates() {
                 percent: Decimal::percent(10),
             }),
             description: None,
+            weights: None,
         }),
     )
     .unwrap();
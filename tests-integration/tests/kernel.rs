@@ -2,14 +2,17 @@ use andromeda_finance::splitter::AddressPercent;
 use andromeda_splitter::mock::{
     mock_andromeda_splitter, mock_splitter_instantiate_msg, mock_splitter_send_msg, MockSplitter,
 };
-use andromeda_std::amp::{AndrAddr, Recipient};
+use andromeda_std::{
+    amp::{AndrAddr, Recipient},
+    os::kernel::AMPPktResponse,
+};
 use andromeda_testing::{
     mock::mock_app,
     mock_builder::MockAndromedaBuilder,
     mock_contract::{MockADO, MockContract},
 };
 
-use cosmwasm_std::{coin, Addr, Decimal};
+use cosmwasm_std::{coin, from_json, Addr, Decimal};
 
 #[test]
 fn kernel() {
@@ -99,5 +102,10 @@ fn kernel() {
     assert_eq!(owner_balance, coin(900, "uandr"));
     assert_eq!(owner_balance, coin(900, "uandr"));
 
-    assert!(res.data.is_none());
+    // The kernel summarises the outcome of each relayed message in the response data
+    let pkt_res: AMPPktResponse = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(pkt_res.results.len(), 1);
+    assert_eq!(pkt_res.results[0].index, 0);
+    assert!(pkt_res.results[0].success);
+    assert!(pkt_res.results[0].error.is_none());
 }
@@ -22,7 +22,9 @@ use andromeda_std::ado_base::rates::{LocalRateType, LocalRateValue, PercentRate,
 use andromeda_std::amp::messages::{AMPMsg, AMPPkt};
 use andromeda_std::amp::{AndrAddr, Recipient};
 use andromeda_std::common::denom::Asset;
+use andromeda_std::common::response::AndrEvent;
 use andromeda_std::error::ContractError;
+use andromeda_testing::events::assert_andr_event;
 use andromeda_testing::mock::mock_app;
 use andromeda_testing::mock_builder::MockAndromedaBuilder;
 use andromeda_testing::MockADO;
@@ -72,6 +74,7 @@ fn test_marketplace_app() {
         recipients: vec![Recipient::from_string(rates_receiver.to_string())],
         value: LocalRateValue::Flat(coin(100, "uandr")),
         description: None,
+        weights: None,
     };
 
     let rates_init_msg = mock_rates_instantiate_msg(
@@ -142,7 +145,10 @@ fn test_marketplace_app() {
         .query_rates(&mut router, "Buy".to_string())
         .unwrap();
 
-    assert_eq!(rate, Rate::Contract(AndrAddr::from_string(rates.addr())));
+    assert_eq!(
+        rate,
+        vec![Rate::Contract(AndrAddr::from_string(rates.addr()))]
+    );
 
     let rates: AllRatesResponse = marketplace.query_all_rates(&mut router).unwrap();
 
@@ -151,7 +157,7 @@ fn test_marketplace_app() {
         AllRatesResponse {
             all_rates: vec![(
                 "Buy".to_string(),
-                Rate::Contract(AndrAddr::from_string(rates.addr()))
+                vec![Rate::Contract(AndrAddr::from_string(rates.addr()))]
             )]
         }
     );
@@ -227,7 +233,7 @@ fn test_marketplace_app() {
         .unwrap();
 
     // Blacklist buyer using contract permission
-    marketplace
+    let res = marketplace
         .execute_set_permissions(
             &mut router,
             owner.clone(),
@@ -236,6 +242,14 @@ fn test_marketplace_app() {
             Permission::Contract(AndrAddr::from_string(address_list.addr())),
         )
         .unwrap();
+    assert_andr_event(
+        &res.events,
+        AndrEvent::PermissionChanged {
+            action: "Buy".to_string(),
+            actor: buyer.to_string(),
+            permission: Some(address_list.addr().to_string()),
+        },
+    );
 
     // Should return Unauthorized error
     let err: ContractError = router
@@ -511,6 +525,7 @@ fn test_marketplace_app_cw20_restricted() {
             "andr1f5m2mm5gms637c06t0er56g454j5hznlefzavxm5cr7ex8xc5r0s4sfhu4",
         )),
         description: None,
+        weights: None,
     };
 
     let rates_init_msg = mock_rates_instantiate_msg(
@@ -812,6 +827,7 @@ fn test_marketplace_app_cw20_unrestricted() {
             percent: Decimal::percent(20),
         }),
         description: None,
+        weights: None,
     };
 
     let rates_init_msg = mock_rates_instantiate_msg(
@@ -965,6 +981,7 @@ fn test_marketplace_app_cw20_unrestricted() {
             "andr1ywhkkafy0jgr3etypp40v6ct9ffmvakrsruwvp595pd9juv5tafqqzph5h",
         )),
         description: None,
+        weights: None,
     };
 
     // Try updating denom to another unpermissioned cw20, should work since this an unrestricted cw20 sale
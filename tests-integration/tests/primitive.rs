@@ -2,18 +2,31 @@
 
 use andromeda_app::app::AppComponent;
 use andromeda_app_contract::mock::{mock_andromeda_app, MockAppContract};
-use andromeda_data_storage::primitive::{GetTypeResponse, GetValueResponse, Primitive};
+use andromeda_data_storage::primitive::{
+    GetTypeResponse, GetValueResponse, Primitive, PrimitiveRestriction, QueryMsg,
+};
 
 use andromeda_primitive::mock::{
     mock_andromeda_primitive, mock_primitive_instantiate_msg, MockPrimitive,
 };
 use andromeda_std::{
-    ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate},
+    ado_base::{
+        rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate},
+        version::VersionResponse,
+        MigrateMsg,
+    },
     amp::Recipient,
+    common::migration::MigrationPlanResponse,
     error::ContractError,
 };
-use andromeda_testing::{mock::mock_app, mock_builder::MockAndromedaBuilder, MockContract};
+use andromeda_testing::{
+    migration::{assert_migration_preserves_state, set_stored_version},
+    mock::mock_app,
+    mock_builder::MockAndromedaBuilder,
+    MockContract,
+};
 use cosmwasm_std::{coin, to_json_binary, Decimal, Uint128};
+use cw_storage_plus::Map;
 
 #[test]
 fn test_primitive() {
@@ -91,6 +104,7 @@ fn test_primitive() {
                     percent: Decimal::percent(25),
                 }),
                 description: None,
+                weights: None,
             }),
         )
         .unwrap_err()
@@ -109,6 +123,7 @@ fn test_primitive() {
                 recipients: vec![Recipient::new(recipient_one, None)],
                 value: LocalRateValue::Flat(coin(10_u128, "uandr")),
                 description: None,
+                weights: None,
             }),
         )
         .unwrap();
@@ -190,3 +205,86 @@ fn test_primitive() {
     let recipient_balance = router.wrap().query_balance(recipient_one, "uandr").unwrap();
     assert_eq!(recipient_balance.amount, Uint128::new(20));
 }
+
+#[test]
+fn test_primitive_migration() {
+    let mut router = mock_app(None);
+    let andr = MockAndromedaBuilder::new(&mut router, "admin")
+        .with_wallets(vec![("owner", vec![]), ("recipient_one", vec![])])
+        .with_contracts(vec![("primitive", mock_andromeda_primitive())])
+        .build(&mut router);
+    let owner = andr.get_wallet("owner");
+    let recipient_one = andr.get_wallet("recipient_one");
+    let code_id = andr.get_code_id(&mut router, "primitive");
+
+    let primitive = MockPrimitive::instantiate(
+        code_id,
+        owner.clone(),
+        &mut router,
+        andr.kernel.addr().to_string(),
+        None,
+        PrimitiveRestriction::Private,
+    );
+    primitive
+        .execute_set_value(
+            &mut router,
+            owner.clone(),
+            Some("bool".to_string()),
+            Primitive::Bool(true),
+            None,
+        )
+        .unwrap();
+
+    // Simulate a contract deployed at 2.0.3, which stored a single rate per action
+    let rate = Rate::Local(LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipients: vec![Recipient::new(recipient_one, None)],
+        value: LocalRateValue::Flat(coin(10_u128, "uandr")),
+        description: None,
+        weights: None,
+    });
+    set_stored_version(&mut router, primitive.addr(), "primitive", "2.0.3");
+    Map::<&str, Rate>::new("rates")
+        .save(
+            router.contract_storage_mut(primitive.addr()).as_mut(),
+            "SetValue",
+            &rate,
+        )
+        .unwrap();
+
+    let plan: MigrationPlanResponse =
+        primitive.query(&router, QueryMsg::MigrationPlan { from_version: None });
+    assert_eq!(plan.from_version, "2.0.3");
+    assert_eq!(plan.steps.len(), 1);
+
+    let res = assert_migration_preserves_state(
+        &mut router,
+        owner.clone(),
+        primitive.addr(),
+        code_id,
+        &MigrateMsg {},
+        |app| {
+            primitive
+                .query::<GetValueResponse>(
+                    app,
+                    QueryMsg::GetValue {
+                        key: Some("bool".to_string()),
+                    },
+                )
+                .value
+        },
+    );
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm").add_attribute("migration_step", "2.0.3->2.0.4")
+    ));
+
+    let version: VersionResponse = primitive.query(&router, QueryMsg::Version {});
+    assert_eq!(version.version, "2.0.4");
+    let rates: Option<Vec<Rate>> = primitive.query(
+        &router,
+        QueryMsg::Rates {
+            action: "SetValue".to_string(),
+        },
+    );
+    assert_eq!(rates, Some(vec![rate]));
+}
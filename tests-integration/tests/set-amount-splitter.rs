@@ -53,6 +53,7 @@ fn test_splitter() {
         name: "splitter".to_string(),
         component_type: ComponentType::new(splitter_init_msg),
         ado_type: "splitter".to_string(),
+        depends_on: None,
     };
 
     let app_components = vec![splitter_app_component.clone()];